@@ -8,5 +8,7 @@
 mod psyche;
 mod strata;
 
-pub use psyche::{Lineage, LineageFlags, LineageId, PsycheArena};
+pub use psyche::{
+    key_hash, Lineage, LineageFlags, LineageId, Protection, PsycheArena, Temperature,
+};
 pub use strata::{Engram, StrataArena};
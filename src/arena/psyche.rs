@@ -3,9 +3,16 @@
 //! High-performance arena for storing active lineages with O(1) access.
 //! Uses contiguous memory layout for cache efficiency.
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::clock::{self, elapsed_seconds};
+
+/// Resolution of [`Lineage::decay_lag`]
+const NANOS_PER_MIN: u64 = 60 * clock::NANOS_PER_SEC;
 
 /// Unique identifier for a lineage within the arena
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -29,6 +36,13 @@ impl LineageId {
     }
 }
 
+/// Hash of a lineage's string key, as used by [`PsycheArena::lookup`]
+pub fn key_hash(key: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
 impl From<u32> for LineageId {
     fn from(v: u32) -> Self {
         Self(v)
@@ -44,17 +58,182 @@ impl From<usize> for LineageId {
 bitflags! {
     /// Flags for lineage state
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-    pub struct LineageFlags: u32 {
+    pub struct LineageFlags: u16 {
         /// Lineage is active (not deleted)
         const ACTIVE = 1 << 0;
         /// Lineage is conscious (energy >= threshold)
         const CONSCIOUS = 1 << 1;
-        /// Lineage is protected from decay (trauma)
+        /// Absolute protection: immune to decay, never modified or deleted
         const PROTECTED = 1 << 2;
         /// Lineage has been modified since last persist
         const DIRTY = 1 << 3;
-        /// Lineage is pinned in memory
+        /// Pinned protection: kept in memory, deletion needs confirmation
         const PINNED = 1 << 4;
+        /// Temperature tier: Hot (see [`Temperature`])
+        const TIER_HOT = 1 << 5;
+        /// Temperature tier: Cold
+        const TIER_COLD = 1 << 6;
+        /// Temperature tier: Frozen (sticky until unlocked)
+        const TIER_FROZEN = 1 << 7;
+        /// Hardened protection: modification and deletion need an override
+        const HARDENED = 1 << 8;
+    }
+}
+
+impl LineageFlags {
+    /// All temperature tier bits (none set = Warm)
+    pub const TIERS: Self = Self::TIER_HOT
+        .union(Self::TIER_COLD)
+        .union(Self::TIER_FROZEN);
+
+    /// All protection level bits (none set = Normal)
+    pub const PROTECTION: Self = Self::PROTECTED.union(Self::HARDENED).union(Self::PINNED);
+}
+
+/// Protection level of a lineage
+///
+/// | Level    | Decay resistance | Modification      | Deletion              |
+/// |----------|------------------|-------------------|-----------------------|
+/// | Absolute | immune           | blocked           | blocked               |
+/// | Hardened | 95%              | requires override | requires override     |
+/// | Pinned   | 80%              | allowed           | requires confirmation |
+/// | Normal   | 0%               | allowed           | allowed               |
+///
+/// Hardened and Absolute lineages are also skipped by propagation
+/// cascades; every level above Normal is exempt from GC and eviction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Protection {
+    /// No protection
+    Normal = 0,
+    /// Slow decay, deletion needs confirmation
+    Pinned = 1,
+    /// Near-immune, changes need an override
+    Hardened = 2,
+    /// Immune and immutable
+    Absolute = 3,
+}
+
+impl Protection {
+    /// Parse a protection level from its wire byte
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::Normal),
+            1 => Some(Self::Pinned),
+            2 => Some(Self::Hardened),
+            3 => Some(Self::Absolute),
+            _ => None,
+        }
+    }
+
+    /// Fraction of decay this level resists (1.0 = immune)
+    #[inline]
+    pub fn decay_resistance(self) -> f32 {
+        match self {
+            Self::Normal => 0.0,
+            Self::Pinned => 0.8,
+            Self::Hardened => 0.95,
+            Self::Absolute => 1.0,
+        }
+    }
+
+    /// Whether energy may be changed (`overridden` = caller asked to override)
+    pub fn allows_modification(self, overridden: bool) -> bool {
+        match self {
+            Self::Normal | Self::Pinned => true,
+            Self::Hardened => overridden,
+            Self::Absolute => false,
+        }
+    }
+
+    /// Whether the lineage may be forgotten
+    pub fn allows_deletion(self, confirmed: bool, overridden: bool) -> bool {
+        match self {
+            Self::Normal => true,
+            Self::Pinned => confirmed || overridden,
+            Self::Hardened => overridden,
+            Self::Absolute => false,
+        }
+    }
+
+    /// Whether the level may be changed to `to`
+    ///
+    /// Raising is always allowed; lowering Hardened needs an override and
+    /// Absolute never changes.
+    pub fn allows_change(self, to: Self, overridden: bool) -> bool {
+        match self {
+            Self::Normal | Self::Pinned => true,
+            Self::Hardened => to >= self || overridden,
+            Self::Absolute => to == self,
+        }
+    }
+
+    /// Whether propagation cascades skip this lineage
+    #[inline]
+    pub fn exempt_from_cascades(self) -> bool {
+        self >= Self::Hardened
+    }
+
+    fn flag(self) -> LineageFlags {
+        match self {
+            Self::Normal => LineageFlags::empty(),
+            Self::Pinned => LineageFlags::PINNED,
+            Self::Hardened => LineageFlags::HARDENED,
+            Self::Absolute => LineageFlags::PROTECTED,
+        }
+    }
+}
+
+/// Temperature tier of a lineage
+///
+/// Derived from access recency and frequency by the decay tick. The tier
+/// scales the lineage's decay rate; Frozen lineages are hidden from reads
+/// until explicitly unlocked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Temperature {
+    /// Recently accessed, minimal decay
+    Hot = 0,
+    /// Moderate access, normal decay
+    Warm = 1,
+    /// Rarely accessed, accelerated decay
+    Cold = 2,
+    /// Archived, suspended decay, requires unlock
+    Frozen = 3,
+}
+
+impl Temperature {
+    /// All tiers, hottest first
+    pub const ALL: [Self; 4] = [Self::Hot, Self::Warm, Self::Cold, Self::Frozen];
+
+    /// Multiplier applied to the lineage's decay rate
+    #[inline]
+    pub fn decay_multiplier(self) -> f32 {
+        match self {
+            Self::Hot => 0.25,
+            Self::Warm => 1.0,
+            Self::Cold => 2.0,
+            Self::Frozen => 0.0,
+        }
+    }
+
+    /// Lowercase tier name
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Hot => "hot",
+            Self::Warm => "warm",
+            Self::Cold => "cold",
+            Self::Frozen => "frozen",
+        }
+    }
+
+    fn flag(self) -> LineageFlags {
+        match self {
+            Self::Hot => LineageFlags::TIER_HOT,
+            Self::Warm => LineageFlags::empty(),
+            Self::Cold => LineageFlags::TIER_COLD,
+            Self::Frozen => LineageFlags::TIER_FROZEN,
+        }
     }
 }
 
@@ -93,6 +272,10 @@ pub struct Lineage {
     /// State flags
     pub flags: LineageFlags,
 
+    /// Minutes after `last_access` up to which decay is folded into `energy`
+    /// Tier and protection changes move it; accesses reset it to 0
+    pub decay_lag: u16,
+
     /// Index of the head engram in strata arena
     pub head_index: u32,
 }
@@ -104,8 +287,9 @@ impl Default for Lineage {
             threshold: 0.5,
             decay_rate: 0.001,
             rigidity: 0.5,
-            last_access: now_nanos(),
+            last_access: clock::now_nanos(),
             flags: LineageFlags::ACTIVE,
+            decay_lag: 0,
             head_index: u32::MAX,
         }
     }
@@ -116,22 +300,38 @@ impl Lineage {
     pub fn new(energy: f32) -> Self {
         Self {
             energy,
-            last_access: now_nanos(),
+            last_access: clock::now_nanos(),
             ..Default::default()
         }
     }
 
+    /// Create a new lineage last accessed at `now` (nanoseconds since epoch)
+    pub fn new_at(energy: f32, now: u64) -> Self {
+        Self {
+            last_access: now,
+            ..Self::new(energy)
+        }
+    }
+
     /// Create a new lineage with full configuration
     pub fn with_config(energy: f32, threshold: f32, decay_rate: f32) -> Self {
         Self {
             energy,
             threshold,
             decay_rate,
-            last_access: now_nanos(),
+            last_access: clock::now_nanos(),
             ..Default::default()
         }
     }
 
+    /// [`Self::with_config`] last accessed at `now` (nanoseconds since epoch)
+    pub fn with_config_at(energy: f32, threshold: f32, decay_rate: f32, now: u64) -> Self {
+        Self {
+            last_access: now,
+            ..Self::with_config(energy, threshold, decay_rate)
+        }
+    }
+
     /// Check if lineage is conscious (energy >= threshold)
     #[inline]
     pub fn is_conscious(&self) -> bool {
@@ -144,26 +344,117 @@ impl Lineage {
         self.flags.contains(LineageFlags::ACTIVE)
     }
 
-    /// Check if lineage is protected from decay
+    /// Check if lineage is protected from decay (Absolute protection)
     #[inline]
     pub fn is_protected(&self) -> bool {
         self.flags.contains(LineageFlags::PROTECTED)
     }
 
+    /// Get the protection level (strongest bit wins)
+    #[inline]
+    pub fn protection(&self) -> Protection {
+        if self.flags.contains(LineageFlags::PROTECTED) {
+            Protection::Absolute
+        } else if self.flags.contains(LineageFlags::HARDENED) {
+            Protection::Hardened
+        } else if self.flags.contains(LineageFlags::PINNED) {
+            Protection::Pinned
+        } else {
+            Protection::Normal
+        }
+    }
+
+    /// Set the protection level as of `now` (nanoseconds since epoch)
+    ///
+    /// Decay under the old level is folded into `energy` first. Not an
+    /// access: the idle clock keeps running.
+    pub fn set_protection_at(&mut self, level: Protection, now: u64) {
+        self.fold_decay_at(now);
+        self.flags.remove(LineageFlags::PROTECTION);
+        self.flags.insert(level.flag() | LineageFlags::DIRTY);
+    }
+
+    /// Get the temperature tier (Warm unless a tier bit is set)
+    #[inline]
+    pub fn temperature(&self) -> Temperature {
+        if self.flags.contains(LineageFlags::TIER_FROZEN) {
+            Temperature::Frozen
+        } else if self.flags.contains(LineageFlags::TIER_COLD) {
+            Temperature::Cold
+        } else if self.flags.contains(LineageFlags::TIER_HOT) {
+            Temperature::Hot
+        } else {
+            Temperature::Warm
+        }
+    }
+
+    /// Check if lineage is frozen (hidden from reads until unlocked)
+    #[inline]
+    pub fn is_frozen(&self) -> bool {
+        self.flags.contains(LineageFlags::TIER_FROZEN)
+    }
+
+    /// Move to another tier as of `now` (nanoseconds since epoch)
+    ///
+    /// Decay under the old tier is folded into `energy` first, so the new
+    /// multiplier only applies from `now` on. Not an access: the idle
+    /// clock keeps running.
+    pub fn set_temperature_at(&mut self, tier: Temperature, now: u64) {
+        self.fold_decay_at(now);
+        self.flags.remove(LineageFlags::TIERS);
+        self.flags.insert(tier.flag() | LineageFlags::DIRTY);
+    }
+
     /// Compute current energy with decay applied
     pub fn current_energy(&self) -> f32 {
+        self.current_energy_at(clock::now_nanos())
+    }
+
+    /// Compute energy with decay applied up to `now` (nanoseconds since epoch)
+    pub fn current_energy_at(&self, now: u64) -> f32 {
         if self.is_protected() {
             return self.energy;
         }
 
-        let elapsed_secs = elapsed_seconds(self.last_access);
-        self.energy * (-self.decay_rate * elapsed_secs).exp()
+        let rate = self.decay_rate
+            * self.temperature().decay_multiplier()
+            * (1.0 - self.protection().decay_resistance());
+        if rate == 0.0 {
+            return self.energy;
+        }
+
+        let elapsed_secs = elapsed_seconds(self.decay_anchor(), now);
+        self.energy * (-rate * elapsed_secs).exp()
+    }
+
+    /// When decay was last folded into `energy` (nanoseconds since epoch)
+    #[inline]
+    pub fn decay_anchor(&self) -> u64 {
+        self.last_access + self.decay_lag as u64 * NANOS_PER_MIN
+    }
+
+    /// Fold decay into `energy` without counting as an access
+    ///
+    /// The anchor is kept in whole minutes after `last_access`, so it lands
+    /// up to a minute before `now` (and stops ~45 days after the access).
+    fn fold_decay_at(&mut self, now: u64) {
+        let lag =
+            (now.saturating_sub(self.last_access) / NANOS_PER_MIN).min(u16::MAX as u64) as u16;
+        let lag = lag.max(self.decay_lag);
+        self.energy = self.current_energy_at(self.last_access + lag as u64 * NANOS_PER_MIN);
+        self.decay_lag = lag;
     }
 
     /// Stimulate the lineage with energy delta
     pub fn stimulate(&mut self, delta: f32) {
-        self.energy = (self.current_energy() + delta).clamp(0.0, 1.0);
-        self.last_access = now_nanos();
+        self.stimulate_at(delta, clock::now_nanos());
+    }
+
+    /// Stimulate the lineage as of `now` (nanoseconds since epoch)
+    pub fn stimulate_at(&mut self, delta: f32, now: u64) {
+        self.energy = (self.current_energy_at(now) + delta).clamp(0.0, 1.0);
+        self.last_access = now;
+        self.decay_lag = 0;
         self.flags.insert(LineageFlags::DIRTY);
 
         // Update consciousness flag
@@ -176,9 +467,16 @@ impl Lineage {
 
     /// Touch the lineage (update last access without changing energy)
     pub fn touch(&mut self) {
+        self.touch_at(clock::now_nanos());
+    }
+
+    /// Touch the lineage as of `now` (nanoseconds since epoch)
+    pub fn touch_at(&mut self, now: u64) {
         // First apply decay, then reset timer
-        self.energy = self.current_energy();
-        self.last_access = now_nanos();
+        self.energy = self.current_energy_at(now);
+        self.last_access = now;
+        self.decay_lag = 0;
+        self.flags.insert(LineageFlags::DIRTY);
     }
 }
 
@@ -192,6 +490,8 @@ pub struct PsycheArena {
     free_list: Vec<LineageId>,
     /// String ID to LineageId mapping
     id_map: rustc_hash::FxHashMap<u64, LineageId>,
+    /// String keys of lineages allocated with [`Self::alloc_named`]
+    keys: rustc_hash::FxHashMap<LineageId, String>,
 }
 
 impl PsycheArena {
@@ -202,6 +502,7 @@ impl PsycheArena {
             count: 0,
             free_list: Vec::new(),
             id_map: rustc_hash::FxHashMap::default(),
+            keys: rustc_hash::FxHashMap::default(),
         }
     }
 
@@ -223,8 +524,9 @@ impl PsycheArena {
         self.data.capacity()
     }
 
-    /// Allocate a new lineage
-    pub fn alloc(&mut self, lineage: Lineage) -> LineageId {
+    /// Allocate a new lineage (marked dirty)
+    pub fn alloc(&mut self, mut lineage: Lineage) -> LineageId {
+        lineage.flags.insert(LineageFlags::DIRTY);
         let id = if let Some(recycled) = self.free_list.pop() {
             // Reuse a recycled slot
             self.data[recycled.index()] = lineage;
@@ -247,6 +549,29 @@ impl PsycheArena {
         id
     }
 
+    /// Allocate with a string key, remembering the key itself
+    pub fn alloc_named(&mut self, key: &str, lineage: Lineage) -> LineageId {
+        let id = self.alloc(lineage);
+        self.set_key(id, key);
+        id
+    }
+
+    /// Attach a string key to an existing lineage
+    pub fn set_key(&mut self, id: LineageId, key: &str) {
+        self.id_map.insert(key_hash(key), id);
+        self.keys.insert(id, key.to_string());
+    }
+
+    /// String key of a lineage allocated with [`Self::alloc_named`]
+    pub fn key_of(&self, id: LineageId) -> Option<&str> {
+        self.keys.get(&id).map(String::as_str)
+    }
+
+    /// All known string keys
+    pub fn keys(&self) -> impl Iterator<Item = (LineageId, &str)> {
+        self.keys.iter().map(|(id, key)| (*id, key.as_str()))
+    }
+
     /// Get a lineage by ID
     #[inline]
     pub fn get(&self, id: LineageId) -> Option<&Lineage> {
@@ -264,13 +589,18 @@ impl PsycheArena {
         self.id_map.get(&key).copied()
     }
 
-    /// Delete a lineage
+    /// Delete a lineage (the freed slot stays dirty until persisted)
     pub fn free(&mut self, id: LineageId) -> bool {
         if let Some(lineage) = self.data.get_mut(id.index()) {
             if lineage.is_active() {
                 lineage.flags.remove(LineageFlags::ACTIVE);
+                lineage.flags.insert(LineageFlags::DIRTY);
                 self.free_list.push(id);
                 self.count -= 1;
+                // A recycled slot must not answer to the old key
+                if let Some(key) = self.keys.remove(&id) {
+                    self.id_map.remove(&key_hash(&key));
+                }
                 return true;
             }
         }
@@ -299,29 +629,103 @@ impl PsycheArena {
     pub fn as_slice(&self) -> &[Lineage] {
         &self.data
     }
-}
 
-// ═══════════════════════════════════════════════════════════════
-// TIME UTILITIES
-// ═══════════════════════════════════════════════════════════════
+    /// Rebuild from persisted `(slot, lineage)` pairs, keeping every slot ID
+    ///
+    /// Gaps become free slots, so bonds and engram windows (which are
+    /// addressed by slot) stay valid. The result starts clean.
+    pub fn from_slots(capacity: usize, slots: impl IntoIterator<Item = (u32, Lineage)>) -> Self {
+        let mut slots: Vec<(u32, Lineage)> = slots.into_iter().collect();
+        slots.sort_unstable_by_key(|(id, _)| *id);
+
+        let mut arena = Self::with_capacity(capacity);
+        for (id, mut lineage) in slots {
+            while arena.data.len() < id as usize {
+                arena.free_list.push(LineageId(arena.data.len() as u32));
+                arena.data.push(Lineage {
+                    flags: LineageFlags::empty(),
+                    ..Default::default()
+                });
+            }
+            lineage.flags.insert(LineageFlags::ACTIVE);
+            lineage.flags.remove(LineageFlags::DIRTY);
+            if arena.data.len() == id as usize {
+                arena.data.push(lineage);
+                arena.count += 1;
+            }
+        }
+        // Recycle low slots first
+        arena.free_list.reverse();
+        arena
+    }
+
+    /// Reserve slot IDs below `span` for [`Self::restore_slot`]
+    ///
+    /// Reserved slots stay off the free list, so allocations made while a
+    /// restore is in progress land above them.
+    pub(crate) fn reserve_slots(&mut self, span: usize) {
+        while self.data.len() < span {
+            self.data.push(Lineage {
+                flags: LineageFlags::empty(),
+                ..Default::default()
+            });
+        }
+    }
 
-/// Get current time in nanoseconds since epoch
-#[inline]
-fn now_nanos() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_nanos() as u64)
-        .unwrap_or(0)
-}
+    /// Place a persisted lineage into its original (vacant) slot
+    pub(crate) fn restore_slot(&mut self, id: u32, mut lineage: Lineage) {
+        self.reserve_slots(id as usize + 1);
+        let slot = &mut self.data[id as usize];
+        if slot.is_active() {
+            return;
+        }
+        lineage.flags.insert(LineageFlags::ACTIVE);
+        lineage.flags.remove(LineageFlags::DIRTY);
+        *slot = lineage;
+        self.count += 1;
+    }
+
+    /// Recompute the free list from vacant slots once a restore completes
+    pub(crate) fn rebuild_free_list(&mut self) {
+        self.free_list = self
+            .data
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, l)| !l.is_active())
+            .map(|(i, _)| LineageId(i as u32))
+            .collect();
+    }
+
+    /// Flag a lineage as modified outside `stimulate` (e.g. its bonds changed)
+    pub fn mark_dirty(&mut self, id: LineageId) {
+        if let Some(lineage) = self.get_mut(id) {
+            lineage.flags.insert(LineageFlags::DIRTY);
+        }
+    }
+
+    /// Number of slots (live or freed) modified since the last persist
+    pub fn dirty_count(&self) -> usize {
+        self.data
+            .iter()
+            .filter(|l| l.flags.contains(LineageFlags::DIRTY))
+            .count()
+    }
+
+    /// Slots (live or freed) modified since the last persist
+    pub fn dirty_ids(&self) -> impl Iterator<Item = LineageId> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.flags.contains(LineageFlags::DIRTY))
+            .map(|(i, _)| LineageId(i as u32))
+    }
 
-/// Calculate elapsed seconds since a timestamp
-#[inline]
-fn elapsed_seconds(timestamp: u64) -> f32 {
-    let now = now_nanos();
-    if now > timestamp {
-        ((now - timestamp) as f64 / 1_000_000_000.0) as f32
-    } else {
-        0.0
+    /// Mark every slot as persisted
+    pub fn clear_dirty(&mut self) {
+        for lineage in &mut self.data {
+            lineage.flags.remove(LineageFlags::DIRTY);
+        }
     }
 }
 
@@ -352,6 +756,102 @@ mod tests {
         assert!(l.is_conscious());
     }
 
+    #[test]
+    fn test_lineage_decay_is_deterministic() {
+        let start = 1_000 * crate::clock::NANOS_PER_SEC;
+        let mut l = Lineage::with_config(0.0, 0.5, 0.1);
+        l.stimulate_at(0.8, start);
+
+        assert_eq!(l.current_energy_at(start), 0.8);
+        // One second later: 0.8 * e^(-0.1)
+        let later = l.current_energy_at(start + crate::clock::NANOS_PER_SEC);
+        assert!((later - 0.8 * (-0.1f32).exp()).abs() < 1e-6);
+        // Time never runs backwards
+        assert_eq!(l.current_energy_at(start - 1), 0.8);
+
+        l.touch_at(start + crate::clock::NANOS_PER_SEC);
+        assert!((l.energy - later).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_lineage_temperature_scales_decay() {
+        let min = NANOS_PER_MIN;
+        let mut l = Lineage::with_config(0.8, 0.5, 0.001);
+        l.last_access = 0;
+        assert_eq!(l.temperature(), Temperature::Warm);
+
+        l.set_temperature_at(Temperature::Hot, 0);
+        assert_eq!(l.temperature(), Temperature::Hot);
+        assert!((l.current_energy_at(min) - 0.8 * (-0.015f32).exp()).abs() < 1e-6);
+
+        // Decay under Hot is folded in before Cold takes over
+        l.set_temperature_at(Temperature::Cold, min);
+        let folded = l.energy;
+        assert!((folded - 0.8 * (-0.015f32).exp()).abs() < 1e-6);
+        assert!((l.current_energy_at(2 * min) - folded * (-0.12f32).exp()).abs() < 1e-6);
+
+        // The anchor lands on the last whole minute; the idle clock keeps running
+        l.set_temperature_at(Temperature::Frozen, 2 * min + min / 2);
+        assert!(l.is_frozen());
+        assert_eq!((l.last_access, l.decay_anchor()), (0, 2 * min));
+        assert_eq!(l.current_energy_at(60 * min), l.energy);
+        assert!(l.flags.contains(LineageFlags::ACTIVE | LineageFlags::DIRTY));
+    }
+
+    #[test]
+    fn test_lineage_protection_resists_decay() {
+        let sec = crate::clock::NANOS_PER_SEC;
+        let mut l = Lineage::with_config(0.8, 0.5, 0.1);
+        l.last_access = 0;
+
+        l.set_protection_at(Protection::Pinned, 0);
+        assert_eq!(l.protection(), Protection::Pinned);
+        assert!((l.current_energy_at(sec) - 0.8 * (-0.02f32).exp()).abs() < 1e-6);
+
+        l.set_protection_at(Protection::Absolute, 0);
+        assert!(l.is_protected());
+        assert_eq!(l.current_energy_at(3600 * sec), 0.8);
+        assert!(!l.flags.contains(LineageFlags::PINNED));
+
+        assert!(Protection::Hardened.allows_modification(true));
+        assert!(!Protection::Hardened.allows_deletion(true, false));
+        assert!(Protection::Pinned.allows_deletion(true, false));
+        assert!(!Protection::Absolute.allows_deletion(true, true));
+
+        assert!(Protection::Pinned.allows_change(Protection::Normal, false));
+        assert!(Protection::Hardened.allows_change(Protection::Absolute, false));
+        assert!(!Protection::Hardened.allows_change(Protection::Pinned, false));
+        assert!(Protection::Hardened.allows_change(Protection::Pinned, true));
+        assert!(!Protection::Absolute.allows_change(Protection::Hardened, true));
+
+        // Changing protection folds decay but is not an access
+        let min = NANOS_PER_MIN;
+        let mut l = Lineage::with_config(0.8, 0.5, 0.001);
+        l.last_access = 0;
+        l.set_protection_at(Protection::Hardened, min);
+        assert_eq!((l.last_access, l.decay_anchor()), (0, min));
+        assert!((l.energy - 0.8 * (-0.06f32).exp()).abs() < 1e-6);
+
+        // An access moves both back together
+        l.stimulate_at(0.0, 2 * min);
+        assert_eq!((l.last_access, l.decay_lag), (2 * min, 0));
+    }
+
+    #[test]
+    fn test_lineage_layout() {
+        // Two lineages per 64-byte cache line
+        assert_eq!(std::mem::size_of::<Lineage>(), 32);
+        assert_eq!(std::mem::align_of::<Lineage>(), 32);
+
+        // `flags` + `decay_lag` encode like the former u32 flags
+        let before = (0.6f32, 0.5f32, 0.001f32, 0.5f32, 42u64, 0x109u32, 7u32);
+        let lineage: Lineage = bincode::deserialize(&bincode::serialize(&before).unwrap()).unwrap();
+        assert_eq!(lineage.flags.bits(), 0x109);
+        assert_eq!((lineage.last_access, lineage.decay_lag), (42, 0));
+        assert_eq!(lineage.head_index, 7);
+        assert_eq!(bincode::serialize(&lineage).unwrap().len(), 32);
+    }
+
     #[test]
     fn test_psyche_arena_alloc() {
         let mut arena = PsycheArena::with_capacity(100);
@@ -381,6 +881,82 @@ mod tests {
         assert_eq!(arena.len(), 2);
     }
 
+    #[test]
+    fn test_psyche_dirty_tracking() {
+        let mut arena = PsycheArena::with_capacity(10);
+        let a = arena.alloc(Lineage::new(0.5));
+        let b = arena.alloc(Lineage::new(0.5));
+        assert_eq!(arena.dirty_count(), 2);
+
+        arena.clear_dirty();
+        assert_eq!(arena.dirty_count(), 0);
+
+        arena.get_mut(a).unwrap().stimulate(0.1);
+        arena.mark_dirty(b);
+        assert_eq!(arena.dirty_count(), 2);
+
+        // Forgetting is a change too
+        arena.clear_dirty();
+        arena.free(a);
+        assert_eq!(arena.dirty_count(), 1);
+
+        // Touching folds decay into energy, so the next delta must carry it
+        arena.clear_dirty();
+        arena.get_mut(b).unwrap().touch();
+        assert_eq!(arena.dirty_count(), 1);
+    }
+
+    #[test]
+    fn test_psyche_named_keys() {
+        let mut arena = PsycheArena::with_capacity(10);
+        let a = arena.alloc_named("alpha", Lineage::new(0.5));
+        arena.alloc(Lineage::new(0.5));
+
+        assert_eq!(arena.lookup(key_hash("alpha")), Some(a));
+        assert_eq!(arena.key_of(a), Some("alpha"));
+        assert_eq!(arena.keys().count(), 1);
+
+        // Forgetting releases the key before the slot is recycled
+        arena.free(a);
+        let b = arena.alloc_named("beta", Lineage::new(0.5));
+        assert_eq!(b, a);
+        assert_eq!(arena.lookup(key_hash("alpha")), None);
+        assert_eq!(arena.key_of(b), Some("beta"));
+    }
+
+    #[test]
+    fn test_psyche_from_slots_keeps_ids() {
+        let arena =
+            PsycheArena::from_slots(10, vec![(4, Lineage::new(0.4)), (1, Lineage::new(0.1))]);
+        assert_eq!(arena.len(), 2);
+        assert_eq!(arena.get(LineageId(1)).unwrap().energy, 0.1);
+        assert_eq!(arena.get(LineageId(4)).unwrap().energy, 0.4);
+        assert!(arena.get(LineageId(0)).is_none());
+        assert_eq!(arena.dirty_count(), 0);
+
+        // Gaps are recycled before the arena grows
+        let mut arena = arena;
+        assert_eq!(arena.alloc(Lineage::new(0.5)), LineageId(0));
+    }
+
+    #[test]
+    fn test_psyche_restore_slot_reserves_ids() {
+        let mut arena = PsycheArena::with_capacity(10);
+        arena.reserve_slots(4);
+        arena.restore_slot(3, Lineage::new(0.3));
+        assert_eq!(arena.len(), 1);
+        assert_eq!(arena.dirty_count(), 0);
+
+        // Reserved slots are not handed out while the restore runs
+        assert_eq!(arena.alloc(Lineage::new(0.5)), LineageId(4));
+        arena.restore_slot(1, Lineage::new(0.1));
+        assert_eq!(arena.get(LineageId(1)).unwrap().energy, 0.1);
+
+        arena.rebuild_free_list();
+        assert_eq!(arena.alloc(Lineage::new(0.5)), LineageId(0));
+        assert_eq!(arena.alloc(Lineage::new(0.5)), LineageId(2));
+    }
+
     #[test]
     fn test_lineage_id_null() {
         assert!(!LineageId::NULL.is_valid());
@@ -190,6 +190,37 @@ impl StrataArena {
         }
     }
 
+    /// Empty one lineage's engram window (its slot is being vacated)
+    pub(crate) fn clear_window(&mut self, lineage: LineageId) {
+        let base = self.base_index(lineage);
+        if let Some(window) = self.data.get_mut(base..base + self.depth) {
+            window.fill(Engram::default());
+        }
+    }
+
+    /// Non-empty engrams as (global_index, engram) pairs
+    pub fn to_sparse(&self) -> Vec<(u32, Engram)> {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.timestamp != 0)
+            .map(|(i, e)| (i as u32, *e))
+            .collect()
+    }
+
+    /// Non-empty engrams in one lineage's window, with global indices
+    pub fn window_sparse(&self, lineage: LineageId) -> Vec<(u32, Engram)> {
+        let base = self.base_index(lineage);
+        self.data
+            .get(base..base + self.depth)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.timestamp != 0)
+            .map(|(i, e)| ((base + i) as u32, *e))
+            .collect()
+    }
+
     /// Restore from sparse representation
     ///
     /// # Arguments
@@ -0,0 +1,309 @@
+//! MindFry Admin - Offline store inspection and repair
+//!
+//! Opens an Akashic data directory without a running server. Inspection
+//! commands open the store read-only; `reindex` and `extract` are the only
+//! commands that write, and `extract` only writes to a fresh destination.
+//!
+//! The server holds an exclusive lock on its data directory, so stop it first.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! cargo run --bin mfadmin -- list
+//! cargo run --bin mfadmin -- --data-dir /var/lib/mindfry stats
+//! cargo run --bin mfadmin -- verify
+//! cargo run --bin mfadmin -- extract 1737800000000000000 ./rescued
+//! ```
+
+use mindfry::arena::LineageId;
+use mindfry::persistence::{
+    AkashicConfig, AkashicStore, SnapshotContents, SnapshotHealth, SnapshotMeta,
+};
+use mindfry::setun::Trit;
+
+/// Histogram resolution for values in [0, 1]
+const BINS: usize = 10;
+
+/// Width of the longest histogram bar
+const BAR_WIDTH: usize = 40;
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("❌ {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let mut data_dir =
+        std::env::var("MINDFRY_DATA_DIR").unwrap_or_else(|_| AkashicConfig::default().path);
+    let mut args: Vec<String> = Vec::new();
+    let mut raw = std::env::args().skip(1);
+    while let Some(arg) = raw.next() {
+        match arg.as_str() {
+            "--data-dir" => data_dir = raw.next().ok_or("Missing value for --data-dir")?,
+            _ => args.push(arg),
+        }
+    }
+
+    let Some(command) = args.first() else {
+        print_usage();
+        return Ok(());
+    };
+
+    match command.as_str() {
+        "list" => list(&open(&data_dir, true)?),
+        "stats" => stats(&open(&data_dir, true)?, parse_id(args.get(1))?),
+        "verify" => verify(&open(&data_dir, true)?, parse_id(args.get(1))?),
+        "marker" => marker(&open(&data_dir, true)?),
+        "reindex" => reindex(&open(&data_dir, false)?, parse_id(args.get(1))?),
+        "extract" => match (args.get(1), args.get(2)) {
+            (Some(id), Some(dest)) => extract(&open(&data_dir, true)?, id.parse()?, dest),
+            _ => Err("Usage: mfadmin extract <id> <dest-dir>".into()),
+        },
+        _ => {
+            eprintln!("Unknown command: {}", command);
+            print_usage();
+            Ok(())
+        }
+    }
+}
+
+fn open(path: &str, read_only: bool) -> Result<AkashicStore, Box<dyn std::error::Error>> {
+    Ok(AkashicStore::open(AkashicConfig {
+        path: path.to_string(),
+        read_only,
+        ..Default::default()
+    })?)
+}
+
+fn parse_id(arg: Option<&String>) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+    Ok(match arg {
+        Some(id) => Some(
+            id.parse()
+                .map_err(|_| format!("Invalid snapshot id: {}", id))?,
+        ),
+        None => None,
+    })
+}
+
+/// Explicit snapshot, or the newest one
+fn pick(store: &AkashicStore, id: Option<u64>) -> Result<SnapshotMeta, Box<dyn std::error::Error>> {
+    let snapshots = store.list_snapshots()?;
+    let meta = match id {
+        Some(id) => snapshots.into_iter().find(|m| m.id == id),
+        None => snapshots.into_iter().next(),
+    };
+    meta.ok_or_else(|| "No such snapshot".into())
+}
+
+// ═══════════════════════════════════════════════════════════════
+// COMMANDS
+// ═══════════════════════════════════════════════════════════════
+
+fn list(store: &AkashicStore) -> Result<(), Box<dyn std::error::Error>> {
+    let snapshots = store.list_snapshots()?;
+    println!(
+        "{:<20} {:<16} {:>12} {:>9} {:>9} {:>11}  {:<8} kind",
+        "id", "name", "created_at", "lineages", "bonds", "bytes", "version"
+    );
+    for meta in &snapshots {
+        let kind = match store.parent_of(meta.id)? {
+            Some(link) => format!("delta of {}", link.parent),
+            None => "full".to_string(),
+        };
+        println!(
+            "{:<20} {:<16} {:>12} {:>9} {:>9} {:>11}  {:<8} {}",
+            meta.id,
+            meta.name.as_deref().unwrap_or("-"),
+            meta.created_at,
+            meta.lineage_count,
+            meta.bond_count,
+            meta.size_bytes,
+            meta.version,
+            kind
+        );
+    }
+    println!(
+        "\n{} snapshot(s), {} bytes on disk",
+        snapshots.len(),
+        store.disk_size()?
+    );
+    Ok(())
+}
+
+fn stats(store: &AkashicStore, id: Option<u64>) -> Result<(), Box<dyn std::error::Error>> {
+    let meta = pick(store, id)?;
+    let contents = store.contents(meta.id)?;
+
+    println!(
+        "Snapshot {} ({}), chain depth {}",
+        meta.id,
+        meta.name.as_deref().unwrap_or("unnamed"),
+        store.chain_depth(meta.id)?
+    );
+    print_contents(&contents);
+    Ok(())
+}
+
+fn print_contents(contents: &SnapshotContents) {
+    let lineages = &contents.lineages;
+    let conscious = lineages
+        .iter()
+        .filter(|(_, l)| l.energy >= l.threshold)
+        .count();
+    println!("\n🧠 Lineages: {}", lineages.len());
+    println!("   Keyed: {}", contents.keys.len());
+    println!("   Conscious (stored energy): {}", conscious);
+    print_histogram("Energy", lineages.iter().map(|(_, l)| l.energy));
+    print_histogram("Rigidity", lineages.iter().map(|(_, l)| l.rigidity));
+
+    println!("\n📜 Engrams: {}", contents.engrams.len());
+
+    let bonds = &contents.bonds;
+    let count = |polarity: Trit| bonds.iter().filter(|b| b.polarity == polarity).count();
+    println!("\n🔗 Bonds: {}", bonds.len());
+    println!("   Synergy (+1): {}", count(Trit::True));
+    println!("   Neutral (0): {}", count(Trit::Unknown));
+    println!("   Antagonism (-1): {}", count(Trit::False));
+    print_histogram("Strength", bonds.iter().map(|b| b.strength));
+}
+
+fn print_histogram(title: &str, values: impl Iterator<Item = f32>) {
+    let mut bins = [0usize; BINS];
+    for value in values {
+        let bin = (value.clamp(0.0, 1.0) * BINS as f32) as usize;
+        bins[bin.min(BINS - 1)] += 1;
+    }
+
+    println!("   {}:", title);
+    let peak = bins.iter().copied().max().unwrap_or(0).max(1);
+    for (i, &n) in bins.iter().enumerate() {
+        let lo = i as f32 / BINS as f32;
+        let bar = "█".repeat(n * BAR_WIDTH / peak);
+        println!(
+            "     [{:.1}, {:.1}{} {:>8} {}",
+            lo,
+            lo + 1.0 / BINS as f32,
+            if i == BINS - 1 { "]" } else { ")" },
+            n,
+            bar
+        );
+    }
+}
+
+fn verify(store: &AkashicStore, id: Option<u64>) -> Result<(), Box<dyn std::error::Error>> {
+    let reports: Vec<(u64, Option<String>, SnapshotHealth)> = match id {
+        Some(id) => {
+            let meta = pick(store, Some(id))?;
+            vec![(id, meta.name, store.verify_snapshot(id)?)]
+        }
+        None => store
+            .verify()?
+            .into_iter()
+            .map(|r| (r.id, r.name, r.health))
+            .collect(),
+    };
+
+    let mut broken = 0;
+    for (id, name, health) in &reports {
+        let icon = match health {
+            SnapshotHealth::Intact => "✅",
+            SnapshotHealth::Unverified => "➖",
+            _ => "❌",
+        };
+        if !health.is_restorable() {
+            broken += 1;
+        }
+        println!(
+            "{} {:<20} {:<16} {}",
+            icon,
+            id,
+            name.as_deref().unwrap_or("-"),
+            health
+        );
+    }
+
+    println!("\n{} checked, {} not restorable", reports.len(), broken);
+    if broken > 0 {
+        return Err(format!("{} snapshot(s) failed verification", broken).into());
+    }
+    Ok(())
+}
+
+fn marker(store: &AkashicStore) -> Result<(), Box<dyn std::error::Error>> {
+    match store.peek_shutdown_marker()? {
+        Some(marker) => println!(
+            "🏁 Shutdown marker: {} at {} (v{})",
+            if marker.graceful {
+                "graceful"
+            } else {
+                "ungraceful"
+            },
+            marker.timestamp,
+            marker.version
+        ),
+        // The server clears the marker on startup, so none means it is
+        // running or did not shut down cleanly
+        None => println!("⚠️ No shutdown marker: the last run did not shut down cleanly"),
+    }
+
+    let tracker = store.read_shutdown_tracker()?;
+    match (tracker.last_reason, tracker.last_timestamp) {
+        (Some(reason), Some(at)) => println!("📖 Last shutdown: {:?} at {}", reason, at),
+        _ => println!("📖 No shutdown history"),
+    }
+    Ok(())
+}
+
+fn reindex(store: &AkashicStore, id: Option<u64>) -> Result<(), Box<dyn std::error::Error>> {
+    let meta = pick(store, id)?;
+    let contents = store.contents(meta.id)?;
+    if contents.keys.is_empty() && !contents.lineages.is_empty() {
+        return Err(format!(
+            "Snapshot {} predates key tables; nothing to rebuild from",
+            meta.id
+        )
+        .into());
+    }
+
+    let count = store.indexer().rebuild(
+        contents
+            .keys
+            .into_iter()
+            .map(|(id, key)| (key, LineageId(id))),
+    )?;
+    println!(
+        "🔑 Rebuilt lineage_index with {} key(s) from snapshot {}",
+        count, meta.id
+    );
+    Ok(())
+}
+
+fn extract(store: &AkashicStore, id: u64, dest: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let target = open(dest, false)?;
+    if !target.list_snapshots()?.is_empty() {
+        return Err(format!("{} already holds snapshots", dest).into());
+    }
+
+    let meta = store.extract_to(id, &target)?;
+    println!(
+        "📦 Extracted snapshot {} ({} lineages, {} bonds) into {}",
+        meta.id, meta.lineage_count, meta.bond_count, dest
+    );
+    Ok(())
+}
+
+fn print_usage() {
+    println!("MindFry Admin - offline store inspection");
+    println!();
+    println!("Usage: mfadmin [--data-dir <path>] <command> [args...]");
+    println!();
+    println!("Commands:");
+    println!("  list                          List snapshots with metadata");
+    println!("  stats [id]                    Histograms of a snapshot (default: newest)");
+    println!("  verify [id]                   Check snapshot integrity (default: all)");
+    println!("  marker                        Show the shutdown marker and history");
+    println!("  reindex [id]                  Rebuild lineage_index from a snapshot's keys");
+    println!("  extract <id> <dest-dir>       Copy one snapshot into a fresh store");
+}
@@ -7,14 +7,17 @@
 //! ```bash
 //! cargo run --bin mfcli -- ping
 //! cargo run --bin mfcli -- stats
+//! cargo run --bin mfcli -- health
 //! cargo run --bin mfcli -- create fire 0.8
 //! cargo run --bin mfcli -- get fire
+//! cargo run --bin mfcli -- export backup.ndjson
 //! ```
 
 use std::io::{Read, Write};
 use std::net::TcpStream;
 
-use mindfry::protocol::{MfbpCodec, Request};
+use mindfry::dynamics::{CascadeBounds, CascadeMode};
+use mindfry::protocol::{MfbpCodec, Request, Response, ResponseData};
 
 const DEFAULT_HOST: &str = "127.0.0.1:9527";
 
@@ -30,6 +33,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let request = match command.as_str() {
         "ping" => Request::Ping,
         "stats" => Request::Stats,
+        "health" => Request::Health,
         "create" => {
             if args.len() < 4 {
                 eprintln!("Usage: mfcli create <id> <energy>");
@@ -75,11 +79,81 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         "forget" => {
             if args.len() < 3 {
-                eprintln!("Usage: mfcli forget <id>");
+                eprintln!("Usage: mfcli forget <id> [flags: 1=confirm 2=override]");
                 return Ok(());
             }
+            let flags: u8 = if args.len() > 3 {
+                args[3].parse().unwrap_or(0)
+            } else {
+                0
+            };
             Request::LineageForget {
                 id: args[2].clone(),
+                flags,
+            }
+        }
+        "protect" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "Usage: mfcli protect <id> <normal|pinned|hardened|absolute> [flags: 2=override]"
+                );
+                return Ok(());
+            }
+            let level = match args[3].as_str() {
+                "normal" => 0,
+                "pinned" => 1,
+                "hardened" => 2,
+                "absolute" => 3,
+                other => other.parse()?,
+            };
+            let flags: u8 = if args.len() > 4 {
+                args[4].parse().unwrap_or(0)
+            } else {
+                0
+            };
+            Request::LineageProtect {
+                id: args[2].clone(),
+                level,
+                flags,
+            }
+        }
+        "cascade" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "Usage: mfcli cascade <recall|forget|lock|unlock> <id> [strength] [depth] [min_bond]"
+                );
+                return Ok(());
+            }
+            let mode = match args[2].as_str() {
+                "recall" => CascadeMode::Recall,
+                "forget" => CascadeMode::Forget,
+                "lock" => CascadeMode::Lock,
+                "unlock" => CascadeMode::Unlock,
+                other => {
+                    eprintln!("Unknown cascade mode: {}", other);
+                    return Ok(());
+                }
+            };
+            let bounds = CascadeBounds::default();
+            Request::Cascade {
+                id: args[3].clone(),
+                mode,
+                strength: args.get(4).map_or(Ok(bounds.strength), |s| s.parse())?,
+                max_depth: args
+                    .get(5)
+                    .map_or(Ok(bounds.max_depth as u8), |s| s.parse())?,
+                min_bond_strength: args
+                    .get(6)
+                    .map_or(Ok(bounds.min_bond_strength), |s| s.parse())?,
+            }
+        }
+        "unlock" => {
+            if args.len() < 3 {
+                eprintln!("Usage: mfcli unlock <id>");
+                return Ok(());
+            }
+            Request::LineageUnlock {
+                id: args[2].clone(),
             }
         }
         "connect" => {
@@ -101,11 +175,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         "neighbors" => {
             if args.len() < 3 {
-                eprintln!("Usage: mfcli neighbors <id>");
+                eprintln!("Usage: mfcli neighbors <id> [flags]");
                 return Ok(());
             }
             Request::BondNeighbors {
                 id: args[2].clone(),
+                flags: args.get(3).and_then(|f| f.parse().ok()).unwrap_or(0),
             }
         }
         "conscious" => {
@@ -114,11 +189,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             } else {
                 0.5
             };
-            Request::QueryConscious { min_energy }
+            let flags: u8 = if args.len() > 3 {
+                args[3].parse().unwrap_or(0)
+            } else {
+                0
+            };
+            Request::QueryConscious { min_energy, flags }
         }
         "topk" => {
             let k = if args.len() > 2 { args[2].parse()? } else { 10 };
-            Request::QueryTopK { k }
+            let flags: u8 = if args.len() > 3 {
+                args[3].parse().unwrap_or(0)
+            } else {
+                0
+            };
+            Request::QueryTopK { k, flags }
         }
         "trauma" => {
             let min_rigidity = if args.len() > 2 {
@@ -126,7 +211,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             } else {
                 0.8
             };
-            Request::QueryTrauma { min_rigidity }
+            let flags: u8 = if args.len() > 3 {
+                args[3].parse().unwrap_or(0)
+            } else {
+                0
+            };
+            Request::QueryTrauma {
+                min_rigidity,
+                flags,
+            }
         }
         "freeze" => Request::Freeze { frozen: true },
         "thaw" => Request::Freeze { frozen: false },
@@ -138,6 +231,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             };
             Request::Snapshot { name }
         }
+        "snapshots" => Request::SnapshotList,
+        "snapshot-delete" => {
+            if args.len() < 3 {
+                eprintln!("Usage: mfcli snapshot-delete <id>");
+                return Ok(());
+            }
+            Request::SnapshotDelete {
+                id: args[2].parse()?,
+            }
+        }
+        "export" => Request::Export,
+        "import" => {
+            if args.len() < 3 {
+                eprintln!("Usage: mfcli import <file>");
+                return Ok(());
+            }
+            Request::Import {
+                ndjson: std::fs::read_to_string(&args[2])?,
+            }
+        }
         _ => {
             eprintln!("Unknown command: {}", command);
             print_usage();
@@ -147,21 +260,38 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Connect to server
     let mut stream = TcpStream::connect(DEFAULT_HOST)?;
-    println!("Connected to {}", DEFAULT_HOST);
+    eprintln!("Connected to {}", DEFAULT_HOST);
 
     // Send request
     let request_bytes = MfbpCodec::encode_request(&request);
     stream.write_all(&request_bytes)?;
-    println!("Sent: {:?}", request.opcode());
+    eprintln!("Sent: {:?}", request.opcode());
 
-    // Read response
-    let mut response_buf = vec![0u8; 65536];
-    let n = stream.read(&mut response_buf)?;
-
-    if n == 0 {
+    // Read one whole frame (exports can be far larger than a single read)
+    let mut len_buf = [0u8; 4];
+    if stream.read_exact(&mut len_buf).is_err() {
         eprintln!("Server closed connection");
         return Ok(());
     }
+    let frame_len = u32::from_le_bytes(len_buf) as usize;
+    let mut response_buf = vec![0u8; 4 + frame_len];
+    response_buf[..4].copy_from_slice(&len_buf);
+    stream.read_exact(&mut response_buf[4..])?;
+    let n = response_buf.len();
+
+    // Exports go to a file or stdout, not the summary printer
+    if let (Request::Export, Ok(Response::Ok(ResponseData::Export(ndjson)))) =
+        (&request, MfbpCodec::decode_response(&response_buf))
+    {
+        match args.get(2) {
+            Some(path) => {
+                std::fs::write(path, &ndjson)?;
+                println!("Exported {} records to {}", ndjson.lines().count(), path);
+            }
+            None => print!("{}", ndjson),
+        }
+        return Ok(());
+    }
 
     // Decode and display response
     // Note: We're reading raw response bytes, need to parse
@@ -178,9 +308,33 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             0xF1 => {
                 println!("❌ ERROR");
-                // Error code is at byte 5
-                if n > 5 {
-                    println!("   Code: 0x{:02X}", response_buf[5]);
+                match MfbpCodec::decode_response(&response_buf[..n]) {
+                    Ok(Response::Error { code, message }) => {
+                        println!("   Code: {:?} (0x{:02X})", code, code as u8);
+                        println!("   Message: {}", message);
+                    }
+                    Ok(Response::Rejected {
+                        code,
+                        message,
+                        level,
+                        retry_after_ms,
+                    }) => {
+                        println!("   Code: {:?} (0x{:02X})", code, code as u8);
+                        println!("   Message: {}", message);
+                        println!("   Exhaustion: {:?}", level);
+                        if retry_after_ms > 0 {
+                            println!("   Retry after: {} ms", retry_after_ms);
+                        }
+                    }
+                    _ => println!("   Code: 0x{:02X}", response_buf[5]),
+                }
+            }
+            0xF3 => {
+                println!("👋 GOING AWAY (server shutting down)");
+                if let Ok(Response::GoingAway { drain_deadline_ms }) =
+                    MfbpCodec::decode_response(&response_buf[..n])
+                {
+                    println!("   Drain deadline: {} ms", drain_deadline_ms);
                 }
             }
             _ => {
@@ -221,8 +375,145 @@ fn parse_response_data(data: &[u8]) {
                 println!("   Bonds: {}", bond_count);
                 println!("   Conscious: {}", conscious_count);
             }
+            // Tuner stats follow uptime: [mean:f32, std_dev:f32, samples:u32, warmed_up:u8]
+            if data.len() >= 44 {
+                let mean = f32::from_le_bytes([data[31], data[32], data[33], data[34]]);
+                let std_dev = f32::from_le_bytes([data[35], data[36], data[37], data[38]]);
+                let samples = u32::from_le_bytes([data[39], data[40], data[41], data[42]]);
+                println!(
+                    "   Tuner: μ={:.3} σ={:.3} ({} samples, {})",
+                    mean,
+                    std_dev,
+                    samples,
+                    if data[43] != 0 {
+                        "adaptive"
+                    } else {
+                        "warming up"
+                    }
+                );
+            }
+            // Cold tier follows the tuner: [evicted:u32]
+            if data.len() >= 48 {
+                let evicted = u32::from_le_bytes([data[44], data[45], data[46], data[47]]);
+                println!("   Evicted (cold tier): {}", evicted);
+            }
+            // Temperature tiers follow: [hot, warm, cold, frozen: u32]
+            if data.len() >= 64 {
+                let tier = |i: usize| {
+                    let at = 48 + i * 4;
+                    u32::from_le_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]])
+                };
+                println!(
+                    "   Tiers: {} hot, {} warm, {} cold, {} frozen",
+                    tier(0),
+                    tier(1),
+                    tier(2),
+                    tier(3)
+                );
+            }
+            // Protection follows: [violations:u64]
+            if data.len() >= 72 {
+                let mut violations = [0u8; 8];
+                violations.copy_from_slice(&data[64..72]);
+                println!(
+                    "   Protection violations: {}",
+                    u64::from_le_bytes(violations)
+                );
+            }
         }
         0x06 => println!("   Type: SnapshotCreated"),
+        0x07 => {
+            println!("   Type: Health");
+            // [status:u8, energy:f32, warmup:u8, exhaustion:u8, recovery:u8, flags:u8]
+            if data.len() >= 15 {
+                let status = ["Healthy", "Degraded", "Unhealthy"];
+                let warmup = ["Cold", "Resurrecting", "Ready", "Partial"];
+                let exhaustion = ["Normal", "Elevated", "Exhausted", "Emergency"];
+                let recovery = ["Normal", "Shock", "Coma"];
+                let name =
+                    |table: &[&'static str], i: u8| table.get(i as usize).copied().unwrap_or("?");
+                let energy = f32::from_le_bytes([data[7], data[8], data[9], data[10]]);
+                let flags = data[14];
+                println!("   Status: {} ({:.2})", name(&status, data[6]), energy);
+                println!("   Warmup: {}", name(&warmup, data[11]));
+                println!("   Exhaustion: {}", name(&exhaustion, data[12]));
+                println!("   Recovery: {}", name(&recovery, data[13]));
+                println!("   Live: {}", flags & 0x01 != 0);
+                println!("   Ready: {}", flags & 0x02 != 0);
+                if flags & 0x04 != 0 {
+                    println!("   Store reachable: {}", flags & 0x08 != 0);
+                }
+            }
+        }
+        0x08 => {
+            println!("   Type: Snapshots[]");
+            if let Ok(Response::Ok(ResponseData::Snapshots(list))) =
+                MfbpCodec::decode_response(data)
+            {
+                for s in list {
+                    let name = if s.name.is_empty() { "-" } else { &s.name };
+                    let kind = match s.parent {
+                        Some(parent) => format!("delta of {}", parent),
+                        None => "full".to_string(),
+                    };
+                    println!(
+                        "   {}  {:<16} {:>6} lineages {:>6} bonds {:>10} bytes  @{}  ({})",
+                        s.id, name, s.lineage_count, s.bond_count, s.size_bytes, s.created_at, kind
+                    );
+                }
+            }
+        }
+        0x0A => {
+            println!("   Type: Imported");
+            if let Ok(Response::Ok(ResponseData::Imported {
+                lineages,
+                bonds,
+                engrams,
+            })) = MfbpCodec::decode_response(data)
+            {
+                println!("   Lineages: {}", lineages);
+                println!("   Bonds: {}", bonds);
+                println!("   Engrams: {}", engrams);
+            }
+        }
+        0x0B => {
+            println!("   Type: Cascade[]");
+            if let Ok(Response::Ok(ResponseData::Cascade(list))) = MfbpCodec::decode_response(data)
+            {
+                for entry in list {
+                    println!(
+                        "   [{}] {:<24} signal {:.3}  energy {:.3}",
+                        entry.depth, entry.id, entry.signal, entry.energy
+                    );
+                }
+            }
+        }
+        0x0C => {
+            println!("   Type: Forensic[]");
+            if let Ok(Response::Ok(ResponseData::Forensic(list))) = MfbpCodec::decode_response(data)
+            {
+                for entry in list {
+                    println!(
+                        "   {:<24} {:?}  energy {:.3} (raw {:.3})  flags 0x{:04X}  head {}",
+                        entry.info.id,
+                        entry.status,
+                        entry.info.energy,
+                        entry.raw_energy,
+                        entry.flags,
+                        entry.head_index
+                    );
+                    if !entry.suppressors.is_empty() {
+                        println!("      pressure {:.3} from:", entry.pressure);
+                    }
+                    for suppressor in entry.suppressors {
+                        println!(
+                            "      ⊖ {:<22} bond {:.3}  energy {:.3}",
+                            suppressor.id, suppressor.bond_strength, suppressor.energy
+                        );
+                    }
+                }
+            }
+        }
         _ => println!("   Unknown data type: 0x{:02X}", data_type),
     }
 }
@@ -235,16 +526,28 @@ fn print_usage() {
     println!("Commands:");
     println!("  ping                          Test connection");
     println!("  stats                         Get database statistics");
+    println!("  health                        Liveness/readiness report");
     println!("  create <id> <energy>          Create a lineage");
-    println!("  get <id>                      Get lineage info");
+    println!(
+        "  get <id> [flags]              Get lineage info (flags: 1=bypass 2=repressed 7=forensic)"
+    );
     println!("  stimulate <id> <delta>        Stimulate a lineage");
     println!("  forget <id>                   Forget (soft-delete) a lineage");
+    println!("  unlock <id>                   Unlock a frozen lineage");
+    println!("  protect <id> <level>          Set protection (normal|pinned|hardened|absolute)");
+    println!("  cascade <mode> <id> [str] [depth] [min_bond]  Recall/forget/lock/unlock a cluster");
     println!("  connect <src> <tgt> <str>     Create a bond");
-    println!("  neighbors <id>                Get neighbors of a lineage");
-    println!("  conscious [min_energy]        Query conscious lineages");
-    println!("  topk [k]                      Get top K lineages");
-    println!("  trauma [min_rigidity]         Query traumatized lineages");
+    println!("  neighbors <id> [flags]        Get neighbors of a lineage");
+    println!("  conscious [min_energy] [flags] Query conscious lineages");
+    println!(
+        "  topk [k] [flags]              Get top K lineages (flags: 4=no side effects 8=reinforce)"
+    );
+    println!("  trauma [min_rigidity] [flags] Query traumatized lineages");
     println!("  freeze                        Freeze decay engine");
     println!("  thaw                          Unfreeze decay engine");
     println!("  snapshot [name]               Take a snapshot");
+    println!("  snapshots                     List stored snapshots");
+    println!("  snapshot-delete <id>          Delete a snapshot by ID");
+    println!("  export [file]                 Export state as NDJSON (stdout if no file)");
+    println!("  import <file>                 Replace state from an NDJSON export");
 }
@@ -7,19 +7,54 @@
 //!
 //! ```bash
 //! cargo run --bin mindfry-server -- --port 9527
+//! cargo run --bin mindfry-server -- --port 9527 --http-port 9528 --data-dir ./mindfry_data
+//! cargo run --bin mindfry-server -- --tick-ms 500 --gc
+//! cargo run --bin mindfry-server -- --drain-timeout-ms 10000
+//! cargo run --bin mindfry-server -- --time-scale 60   # soak: 1h wall = 60h decay
+//! cargo run --bin mindfry-server -- --keep-last 5 --keep-daily 7 --max-snapshot-bytes 1073741824
+//! cargo run --bin mindfry-server -- --snapshot-interval-secs 60 --snapshot-dirty 5000
+//! cargo run --bin mindfry-server -- --delta-chain 20
+//! cargo run --bin mindfry-server -- --warmup-hot-set 50000
+//! cargo run --bin mindfry-server -- --evict-below 0.05 --evict-idle-secs 3600
 //! ```
+//!
+//! Resurrection restores the `--warmup-hot-set` most energetic lineages
+//! first, then serves reads (`Partial` warmup) while the rest streams in.
+//!
+//! With `--evict-below` set, maintenance moves idle lineages at or below
+//! that energy to the cold tier on disk; an access faults them back in.
+//!
+//! On SIGINT/SIGTERM the server stops accepting, sends `GoingAway` to
+//! connected clients, waits up to `--drain-timeout-ms` for in-flight
+//! requests, then snapshots under an exclusive lock.
+//!
+//! With `--http-port` set, Prometheus metrics are served at `GET /metrics`
+//! and orchestrator probes at `GET /livez` and `GET /readyz`.
 
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::{TcpListener, TcpStream};
 use tracing::{error, info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 
-use mindfry::persistence::{AkashicConfig, AkashicStore};
-use mindfry::protocol::{CommandHandler, MfbpCodec, Request};
+use mindfry::clock::{self, AcceleratedClock};
+use mindfry::metrics::{Metrics, Transport};
+use mindfry::persistence::{
+    AkashicConfig, AkashicStore, EvictionPolicy, PhysicsSnapshot, RetentionPolicy, SnapshotSchedule,
+};
+use mindfry::protocol::{
+    CommandHandler, HttpGateway, MfbpCodec, Request, Response, ShutdownCoordinator,
+};
+use mindfry::stability::{
+    lineages, ExhaustionMonitor, RecoveryState, SelfDiagnostic, ShutdownReason, TunerConfig,
+};
 use mindfry::{MindFry, MindFryConfig};
 
+/// Lineages installed per lock acquisition while the cold remainder streams in
+const RESURRECTION_BATCH: usize = 4096;
+
 /// Default server port (MFBP)
 const DEFAULT_PORT: u16 = 9527;
 
@@ -30,7 +65,29 @@ const MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
 struct ServerConfig {
     host: String,
     port: u16,
+    /// Optional HTTP/JSON gateway port (disabled when None)
+    http_port: Option<u16>,
+    /// Akashic Records directory
+    data_dir: String,
     max_connections: usize,
+    /// Background maintenance interval
+    tick_ms: u64,
+    /// Run lineage GC during maintenance
+    gc: bool,
+    /// Adaptive exhaustion threshold tuning
+    tuner: TunerConfig,
+    /// How long shutdown waits for in-flight requests
+    drain_timeout_ms: u64,
+    /// Clock speed-up factor (1.0 = wall time)
+    time_scale: f64,
+    /// Which automatic snapshots survive garbage collection
+    retention: RetentionPolicy,
+    /// When automatic snapshots are taken
+    schedule: SnapshotSchedule,
+    /// Lineages restored before reads are served during resurrection
+    warmup_hot_set: usize,
+    /// Cold tier eviction (disabled when None)
+    eviction: Option<EvictionPolicy>,
 }
 
 impl Default for ServerConfig {
@@ -38,11 +95,121 @@ impl Default for ServerConfig {
         Self {
             host: "0.0.0.0".into(),
             port: DEFAULT_PORT,
+            http_port: None,
+            data_dir: AkashicConfig::default().path,
             max_connections: 1024,
+            tick_ms: 1000,
+            gc: false,
+            tuner: TunerConfig::default(),
+            drain_timeout_ms: 5000,
+            time_scale: 1.0,
+            retention: RetentionPolicy::default(),
+            schedule: SnapshotSchedule::default(),
+            warmup_hot_set: 10_000,
+            eviction: None,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Build configuration from `MINDFRY_*` environment and CLI flags
+    ///
+    /// Flags win over environment variables.
+    fn from_args() -> Result<Self, String> {
+        let mut config = Self::default();
+
+        if let Ok(dir) = std::env::var("MINDFRY_DATA_DIR") {
+            config.data_dir = dir;
+        }
+        if let Ok(port) = std::env::var("MINDFRY_HTTP_PORT") {
+            config.http_port = Some(parse_port(&port)?);
+        }
+
+        let mut args = std::env::args().skip(1);
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {}", flag))
+            };
+            match flag.as_str() {
+                "--host" => config.host = value()?,
+                "--port" => config.port = parse_port(&value()?)?,
+                "--http-port" => config.http_port = Some(parse_port(&value()?)?),
+                "--data-dir" => config.data_dir = value()?,
+                "--max-connections" => {
+                    config.max_connections = value()?
+                        .parse()
+                        .map_err(|_| "Invalid --max-connections".to_string())?
+                }
+                "--tick-ms" => {
+                    config.tick_ms = value()?
+                        .parse()
+                        .ok()
+                        .filter(|&ms| ms > 0)
+                        .ok_or_else(|| "Invalid --tick-ms".to_string())?
+                }
+                "--gc" => config.gc = true,
+                "--time-scale" => {
+                    config.time_scale = value()?
+                        .parse()
+                        .ok()
+                        .filter(|&scale: &f64| scale > 0.0)
+                        .ok_or_else(|| "Invalid --time-scale".to_string())?
+                }
+                "--keep-last" => config.retention.keep_last = parse_flag(&flag, &value()?)?,
+                "--keep-daily" => config.retention.keep_daily = parse_flag(&flag, &value()?)?,
+                "--keep-weekly" => config.retention.keep_weekly = parse_flag(&flag, &value()?)?,
+                "--max-snapshot-bytes" => {
+                    config.retention.max_total_bytes = Some(parse_flag(&flag, &value()?)?)
+                }
+                "--snapshot-interval-secs" => {
+                    let secs: u64 = parse_flag(&flag, &value()?)?;
+                    config.schedule.interval = (secs > 0).then(|| Duration::from_secs(secs));
+                }
+                "--snapshot-dirty" => {
+                    let n: usize = parse_flag(&flag, &value()?)?;
+                    config.schedule.dirty_threshold = (n > 0).then_some(n);
+                }
+                "--delta-chain" => config.schedule.max_delta_chain = parse_flag(&flag, &value()?)?,
+                "--drain-timeout-ms" => config.drain_timeout_ms = parse_flag(&flag, &value()?)?,
+                "--warmup-hot-set" => config.warmup_hot_set = parse_flag(&flag, &value()?)?,
+                "--evict-below" => {
+                    config
+                        .eviction
+                        .get_or_insert_with(EvictionPolicy::default)
+                        .max_energy = parse_flag(&flag, &value()?)?
+                }
+                "--evict-idle-secs" => {
+                    config
+                        .eviction
+                        .get_or_insert_with(EvictionPolicy::default)
+                        .min_idle_secs = parse_flag(&flag, &value()?)?
+                }
+                "--tuner-window" => config.tuner.window_size = parse_flag(&flag, &value()?)?,
+                "--tuner-sensitivity" => config.tuner.sensitivity = parse_flag(&flag, &value()?)?,
+                "--tuner-floor" => config.tuner.min_floor = parse_flag(&flag, &value()?)?,
+                "--tuner-ceiling" => config.tuner.hard_ceiling = parse_flag(&flag, &value()?)?,
+                "--tuner-warmup" => config.tuner.warmup_ticks = parse_flag(&flag, &value()?)?,
+                "--tuner-min-std-dev" => config.tuner.min_std_dev = parse_flag(&flag, &value()?)?,
+                "--tuner-min-scale" => config.tuner.min_scale = parse_flag(&flag, &value()?)?,
+                "--tuner-max-scale" => config.tuner.max_scale = parse_flag(&flag, &value()?)?,
+                other => return Err(format!("Unknown argument: {}", other)),
+            }
         }
+
+        Ok(config)
     }
 }
 
+fn parse_port(raw: &str) -> Result<u16, String> {
+    raw.parse().map_err(|_| format!("Invalid port: {}", raw))
+}
+
+fn parse_flag<T: std::str::FromStr>(flag: &str, raw: &str) -> Result<T, String> {
+    raw.parse()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, raw))
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize logging
@@ -59,8 +226,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  ╚═══════════════════════════════════════════════════════════╝");
     println!();
 
-    // Parse args (simple for now)
-    let server_config = ServerConfig::default();
+    let server_config = ServerConfig::from_args()?;
+
+    // Accelerated time must be installed before anything reads the clock
+    if server_config.time_scale != 1.0 {
+        let clock = Arc::new(AcceleratedClock::new(server_config.time_scale));
+        if clock::install(clock).is_err() {
+            warn!("⚠️ Clock already in use; running at wall speed");
+        } else {
+            warn!(
+                "⏩ Accelerated clock: {}× wall speed (decay, recovery and markers use virtual time)",
+                server_config.time_scale
+            );
+        }
+    }
 
     // ═══════════════════════════════════════════════════════════════
     // INITIALIZATION SEQUENCE (Network-first for zero delay)
@@ -71,7 +250,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Step 1: Mount Storage
     print!("  │ 📁 Mounting Akashic Records...");
     std::io::Write::flush(&mut std::io::stdout())?;
-    let store_config = AkashicConfig::default();
+    let store_config = AkashicConfig {
+        path: server_config.data_dir.clone(),
+        retention: server_config.retention.clone(),
+        ..Default::default()
+    };
     let store = match AkashicStore::open(store_config) {
         Ok(s) => {
             println!(" ✓");
@@ -88,7 +271,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     print!("  │ 🔍 Checking recovery state...");
     std::io::Write::flush(&mut std::io::stdout())?;
     let last_marker = store.read_shutdown_marker().ok().flatten();
-    let recovery_analyzer = mindfry::stability::RecoveryAnalyzer::new(last_marker);
+    let shutdown_history = store.read_shutdown_tracker().unwrap_or_default();
+    let recovery_analyzer =
+        mindfry::stability::RecoveryAnalyzer::new(last_marker).with_history(&shutdown_history);
     let recovery_state = recovery_analyzer.analyze();
     match recovery_state {
         mindfry::stability::RecoveryState::Normal => println!(" ✓ (clean)"),
@@ -108,7 +293,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Step 2: Initialize Psyche Arena (empty)
     print!("  │ 🧠 Initializing Psyche Arena...");
     std::io::Write::flush(&mut std::io::stdout())?;
-    let db_config = MindFryConfig::default();
+    let db_config = MindFryConfig {
+        tuner: server_config.tuner.clone(),
+        eviction: server_config.eviction,
+        ..Default::default()
+    };
     let db = MindFry::with_config(db_config).with_store(Arc::clone(&store));
     println!(" ✓");
 
@@ -119,6 +308,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let listener = TcpListener::bind(&addr).await?;
     println!(" ✓ ({})", addr);
 
+    // Optional HTTP/JSON gateway
+    let http_listener = match server_config.http_port {
+        Some(port) => {
+            print!("  │ 🌍 Binding HTTP gateway...");
+            std::io::Write::flush(&mut std::io::stdout())?;
+            let http_addr = format!("{}:{}", server_config.host, port);
+            let http_listener = TcpListener::bind(&http_addr).await?;
+            println!(" ✓ ({})", http_addr);
+            Some(http_listener)
+        }
+        None => None,
+    };
+
     // Wrap DB in Arc<RwLock> for sharing
     let db = Arc::new(RwLock::new(db));
 
@@ -140,29 +342,64 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Spawn async resurrection
         let db_clone = Arc::clone(&db);
         let warmup_clone = warmup.clone();
+        let store_clone = Arc::clone(&store);
+        let hot_set = server_config.warmup_hot_set;
         tokio::spawn(async move {
             let start = std::time::Instant::now();
 
-            // Perform resurrection
-            let result = {
-                let mut db = db_clone.write().unwrap();
-                db.resurrect()
+            // Decode outside the lock; only installing lineages needs it
+            let (depth, now) = {
+                let db = db_clone.read().unwrap();
+                (db.strata.depth(), db.clock().now_nanos())
             };
+            let stager = Arc::clone(&store_clone);
+            let result = tokio::task::spawn_blocking(move || {
+                mindfry::persistence::Resurrection::stage(&stager, depth, now)
+            })
+            .await
+            .expect("resurrection staging panicked");
 
             match result {
-                Ok(true) => {
+                Ok(Some(staged)) => {
+                    db_clone
+                        .write()
+                        .unwrap()
+                        .begin_resurrection(staged, hot_set);
+                    warmup_clone.mark_partial();
+                    info!("🔥 Serving reads after {:?}", start.elapsed());
+
+                    // Short lock per batch so reads interleave
+                    while !db_clone
+                        .write()
+                        .unwrap()
+                        .resume_resurrection(RESURRECTION_BATCH)
+                    {
+                        tokio::task::yield_now().await;
+                    }
+
                     // Bootstrap system lineages after resurrection
                     {
                         let mut db = db_clone.write().unwrap();
                         db.bootstrap_system_lineages();
+                        db.apply_recovery(recovery_state);
                     }
                     info!("✅ Resurrection complete in {:?}", start.elapsed());
                 }
-                Ok(false) => {
+                Ok(None) => {
                     info!("🌱 No snapshot found, genesis mode");
+                    let mut db = db_clone.write().unwrap();
+                    db.bootstrap_system_lineages();
+                    db.apply_recovery(recovery_state);
                 }
                 Err(e) => {
-                    warn!("⚠️ Resurrection failed: {}", e);
+                    // Serve from an empty arena, but never let automatic
+                    // snapshots of it push the real ones out
+                    error!("❌ Resurrection failed: {}", e);
+                    warn!("⏸️ Automatic snapshots and retention held until a restore");
+                    store_clone.hold();
+                    let mut db = db_clone.write().unwrap();
+                    db.bootstrap_system_lineages();
+                    db.apply_recovery(recovery_state);
                 }
             }
 
@@ -175,6 +412,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         {
             let mut db = db.write().unwrap();
             db.bootstrap_system_lineages();
+            db.apply_recovery(recovery_state);
         }
         println!(" ✓");
     }
@@ -191,82 +429,345 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         warmup.state()
     );
 
+    let metrics = Arc::new(Metrics::new());
+    let tick_interval = Duration::from_millis(server_config.tick_ms);
+    let shutdown = ShutdownCoordinator::new();
+    let drain_timeout = Duration::from_millis(server_config.drain_timeout_ms);
+
+    tokio::spawn(maintenance_loop(
+        Arc::clone(&db),
+        Arc::clone(&metrics),
+        tick_interval,
+        server_config.gc,
+    ));
+
+    if server_config.schedule.is_enabled() {
+        tokio::spawn(snapshot_loop(
+            Arc::clone(&db),
+            Arc::clone(&store),
+            Arc::clone(&metrics),
+            server_config.schedule.clone(),
+            warmup.clone(),
+            shutdown.clone(),
+        ));
+    }
+
+    if let Some(http_listener) = http_listener {
+        let gateway = HttpGateway::new(Arc::clone(&db), warmup.clone())
+            .with_store(Arc::clone(&store))
+            .with_metrics(Arc::clone(&metrics))
+            .with_recovery(recovery_state)
+            .with_tick_interval(tick_interval)
+            .with_shutdown(shutdown.clone());
+        tokio::spawn(async move {
+            if let Err(e) = gateway.serve(http_listener).await {
+                error!("HTTP gateway error: {}", e);
+            }
+        });
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // MAIN LOOP WITH GRACEFUL SHUTDOWN
     // ═══════════════════════════════════════════════════════════════
 
     let shutdown_result = tokio::select! {
-        result = accept_loop(listener, Arc::clone(&db), warmup.clone()) => {
+        result = accept_loop(listener, ConnectionContext {
+            db: Arc::clone(&db),
+            store: Arc::clone(&store),
+            warmup: warmup.clone(),
+            metrics: Arc::clone(&metrics),
+            recovery: recovery_state,
+            tick_interval,
+            shutdown: shutdown.clone(),
+            drain_timeout,
+        }) => {
             // Accept loop returned (error or explicit stop)
             result
         }
-        _ = tokio::signal::ctrl_c() => {
-            info!("🛑 Shutdown signal received (Ctrl+C)");
-            Ok(mindfry::stability::ShutdownReason::Signal { signal: 2 }) // SIGINT
-        }
+        reason = shutdown_signal() => Ok(reason),
     };
 
     // ═══════════════════════════════════════════════════════════════
     // GRACEFUL SHUTDOWN SEQUENCE
     // ═══════════════════════════════════════════════════════════════
 
-    match shutdown_result {
-        Ok(reason) => {
-            info!("📝 Recording shutdown experience: {}", reason.description());
-
-            // Take final snapshot before shutdown
-            {
-                let db_guard = db.read().unwrap();
-                if let Some(ref store) = db_guard.store {
-                    match store.take_snapshot(
-                        Some("pre-shutdown"),
-                        &db_guard.psyche,
-                        &db_guard.strata,
-                        &db_guard.bonds,
-                        Some(&db_guard.cortex),
-                        mindfry::persistence::PhysicsSnapshot::default(),
-                    ) {
-                        Ok(meta) => info!("💾 Pre-shutdown snapshot saved: {}", meta.id),
-                        Err(e) => warn!("⚠️ Failed to save shutdown snapshot: {}", e),
+    // A failed accept loop is still a shutdown we live through: record
+    // it as a crash so the next startup treats it as trauma
+    let reason = shutdown_result.unwrap_or_else(|e| {
+        error!("💔 Server error: {}", e);
+        ShutdownReason::Crash {
+            message: e.to_string(),
+        }
+    });
+
+    // The MFBP listener was dropped with the accept loop; the HTTP
+    // gateway stops accepting once draining starts
+    info!("🚰 Draining connections (up to {:?})...", drain_timeout);
+    if shutdown.drain(drain_timeout).await {
+        info!("✅ All in-flight requests completed");
+    } else {
+        warn!(
+            "⚠️ Drain deadline passed with {} request(s) in flight",
+            shutdown.in_flight()
+        );
+    }
+
+    info!("📝 Recording shutdown experience: {}", reason.description());
+
+    // Quiesced: hold the write lock so no straggler or maintenance tick
+    // can mutate state while the final snapshot is taken
+    {
+        let mut db_guard = db.write().unwrap();
+        // A partial arena would shadow the cold lineages on the next start
+        db_guard.resume_resurrection(usize::MAX);
+        db_guard.record_shutdown(&reason);
+
+        if let Some(ref store) = db_guard.store {
+            let start = Instant::now();
+            // After a failed resurrection the arena is not the real state
+            if store.is_held() {
+                warn!("⏸️ Skipping pre-shutdown snapshot: store held since a failed resurrection");
+            } else {
+                match store.take_snapshot(
+                    Some(mindfry::persistence::snapshot::PRE_SHUTDOWN),
+                    &db_guard.psyche,
+                    &db_guard.strata,
+                    &db_guard.bonds,
+                    Some(&db_guard.cortex),
+                    PhysicsSnapshot::default(),
+                ) {
+                    Ok(meta) => {
+                        metrics.record_snapshot(start.elapsed(), meta.size_bytes);
+                        info!("💾 Pre-shutdown snapshot saved: {}", meta.id)
                     }
+                    Err(e) => warn!("⚠️ Failed to save shutdown snapshot: {}", e),
+                }
+            }
+
+            let marker = mindfry::stability::ShutdownMarker::graceful();
+
+            // Shutdown history feeds the next startup's recovery analysis
+            let mut tracker = shutdown_history;
+            tracker.record(reason, marker.timestamp);
+            if let Err(e) = store.write_shutdown_tracker(&tracker) {
+                warn!("⚠️ Failed to record shutdown history: {}", e);
+            }
+
+            // Write graceful shutdown marker for crash recovery
+            match store.write_shutdown_marker(&marker) {
+                Ok(_) => info!("✅ Graceful shutdown marker written"),
+                Err(e) => warn!("⚠️ Failed to write shutdown marker: {}", e),
+            }
+        }
+    }
+
+    info!("😴 MindFry going to sleep... Goodbye!");
 
-                    // Write graceful shutdown marker for crash recovery
-                    let marker = mindfry::stability::ShutdownMarker::graceful();
-                    match store.write_shutdown_marker(&marker) {
-                        Ok(_) => info!("✅ Graceful shutdown marker written"),
-                        Err(e) => warn!("⚠️ Failed to write shutdown marker: {}", e),
+    Ok(())
+}
+
+/// Wait for SIGINT (Ctrl+C) or, on Unix, SIGTERM
+async fn shutdown_signal() -> ShutdownReason {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = sigterm.recv() => {
+                        info!("🛑 Shutdown signal received (SIGTERM)");
+                        return ShutdownReason::Signal { signal: 15 };
                     }
                 }
             }
+            Err(e) => {
+                warn!("⚠️ Failed to install SIGTERM handler: {}", e);
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = tokio::signal::ctrl_c().await;
+
+    info!("🛑 Shutdown signal received (Ctrl+C)");
+    ShutdownReason::Signal { signal: 2 } // SIGINT
+}
+
+/// Background maintenance - decay, bond pruning, optional GC, health pulse
+/// and exhaustion recovery
+async fn maintenance_loop(
+    db: Arc<RwLock<MindFry>>,
+    metrics: Arc<Metrics>,
+    interval: Duration,
+    run_gc: bool,
+) {
+    let mut diagnostic = SelfDiagnostic::default();
+    let exhaustion = ExhaustionMonitor::default();
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        let report = {
+            let mut db = db.write().unwrap();
+            if diagnostic.should_pulse() {
+                db.stimulate_system(lineages::HEALTH, diagnostic.pulse_delta());
+            }
+            // Drain queued request costs and learn from the drained level,
+            // then rest restores what they took from `_system.state`
+            db.settle_charges();
+            db.observe_exhaustion();
+            db.stimulate_system(lineages::STATE, exhaustion.recovery_rate);
+            db.maintenance_tick(run_gc)
+        };
+        metrics.record_maintenance(&report);
+
+        if let Some(gc) = report.gc {
+            if gc.pruned > 0 {
+                info!("🧹 GC pruned {} lineages", gc.pruned);
+            }
+        }
+    }
+}
 
-            info!("😴 MindFry going to sleep... Goodbye!");
+/// Automatic snapshots driven by `SnapshotSchedule` and dirty lineages
+///
+/// The write lock is held only while the checkpoint is captured;
+/// serialization and the disk write run on the blocking pool.
+async fn snapshot_loop(
+    db: Arc<RwLock<MindFry>>,
+    store: Arc<AkashicStore>,
+    metrics: Arc<Metrics>,
+    schedule: SnapshotSchedule,
+    warmup: mindfry::stability::WarmupTracker,
+    shutdown: ShutdownCoordinator,
+) {
+    let mut ticker = tokio::time::interval(Duration::from_secs(1));
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut last = Instant::now();
+    let mut retry = false;
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            // Shutdown takes its own snapshot under a quiesced lock
+            _ = shutdown.draining() => return,
         }
-        Err(e) => {
-            error!("💔 Server error: {}", e);
+
+        // A snapshot before resurrection finishes (or after it failed)
+        // would shadow the real state
+        if !warmup.is_ready() || store.is_held() {
+            continue;
+        }
+
+        // The maintenance pulse dirties `_system.*` every tick; only
+        // client changes make a snapshot due
+        let dirty = db.read().unwrap().client_dirty_count();
+        // A failed write already cleared the flags; keep it due
+        let pending = if retry { dirty.max(1) } else { dirty };
+        if !schedule.is_due(last.elapsed(), pending) {
+            continue;
+        }
+
+        // A retry starts a fresh base: the failed delta's parent may be gone
+        let checkpoint = {
+            let mut db = db.write().unwrap();
+            if schedule.uses_deltas() && !retry {
+                db.checkpoint_delta()
+            } else {
+                db.checkpoint()
+            }
+        };
+        let name = SnapshotSchedule::snapshot_name(clock::now_secs());
+        let start = Instant::now();
+        let writer = Arc::clone(&store);
+        let policy = schedule.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let meta =
+                writer.write_checkpoint(Some(&name), &checkpoint, PhysicsSnapshot::default())?;
+            if !policy.needs_compaction(writer.chain_depth(meta.id)?) {
+                return Ok(meta);
+            }
+            // Fold the chain; its old links become ordinary retention candidates
+            let compacted = writer.compact(meta.id)?.unwrap_or(meta);
+            if let Err(e) = writer.apply_retention() {
+                warn!("⚠️ Retention after compaction failed: {}", e);
+            }
+            Ok::<_, mindfry::persistence::AkashicError>(compacted)
+        })
+        .await;
+        last = Instant::now();
+
+        match result {
+            Ok(Ok(meta)) => {
+                retry = false;
+                metrics.record_snapshot(start.elapsed(), meta.size_bytes);
+                info!(
+                    "📸 Auto snapshot {} ({} dirty, {} lineages, {} bonds)",
+                    meta.name.as_deref().unwrap_or_default(),
+                    dirty,
+                    meta.lineage_count,
+                    meta.bond_count
+                );
+            }
+            Ok(Err(e)) => {
+                retry = true;
+                db.write().unwrap().invalidate_checkpoint_chain();
+                metrics.record_snapshot_failure();
+                warn!("⚠️ Auto snapshot failed: {}", e);
+            }
+            Err(e) => {
+                retry = true;
+                db.write().unwrap().invalidate_checkpoint_chain();
+                metrics.record_snapshot_failure();
+                warn!("⚠️ Auto snapshot task failed: {}", e);
+            }
         }
     }
+}
 
-    Ok(())
+/// Shared state every MFBP connection handler is built from
+#[derive(Clone)]
+struct ConnectionContext {
+    db: Arc<RwLock<MindFry>>,
+    store: Arc<AkashicStore>,
+    warmup: mindfry::stability::WarmupTracker,
+    metrics: Arc<Metrics>,
+    recovery: RecoveryState,
+    tick_interval: Duration,
+    shutdown: ShutdownCoordinator,
+    drain_timeout: Duration,
+}
+
+impl ConnectionContext {
+    fn handler(&self) -> CommandHandler {
+        CommandHandler::with_warmup(Arc::clone(&self.db), self.warmup.clone())
+            .with_store(Arc::clone(&self.store))
+            .with_metrics(Arc::clone(&self.metrics))
+            .with_recovery(self.recovery)
+            .with_tick_interval(self.tick_interval)
+    }
 }
 
 /// Accept loop - runs until error or shutdown
 async fn accept_loop(
     listener: TcpListener,
-    db: Arc<RwLock<MindFry>>,
-    warmup: mindfry::stability::WarmupTracker,
-) -> Result<mindfry::stability::ShutdownReason, Box<dyn std::error::Error + Send + Sync>> {
+    ctx: ConnectionContext,
+) -> Result<ShutdownReason, Box<dyn std::error::Error + Send + Sync>> {
     loop {
         match listener.accept().await {
             Ok((socket, peer)) => {
                 info!("📥 New connection from {}", peer);
 
                 // Clone for the handler
-                let db_clone = Arc::clone(&db);
-                let warmup_clone = warmup.clone();
+                let ctx = ctx.clone();
 
                 // Spawn connection handler
                 tokio::spawn(async move {
-                    if let Err(e) = handle_connection(socket, db_clone, warmup_clone).await {
+                    let _connection = ctx.metrics.connection(Transport::Mfbp);
+                    if let Err(e) = handle_connection(socket, &ctx).await {
                         error!("Connection error: {}", e);
                     }
                     info!("📤 Connection closed: {}", peer);
@@ -283,16 +784,24 @@ async fn accept_loop(
 /// Handle a single client connection
 async fn handle_connection(
     mut socket: TcpStream,
-    db: Arc<RwLock<MindFry>>,
-    warmup: mindfry::stability::WarmupTracker,
+    ctx: &ConnectionContext,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let mut handler = CommandHandler::with_warmup(db, warmup);
+    let mut handler = ctx.handler();
     let mut buffer = vec![0u8; 4096];
     let mut read_buf = Vec::new();
 
     loop {
-        // Read data into buffer
-        let n = socket.read(&mut buffer).await?;
+        // Read data into buffer, unless the server starts draining first
+        let n = tokio::select! {
+            read = socket.read(&mut buffer) => read?,
+            _ = ctx.shutdown.draining() => {
+                let going_away = Response::GoingAway {
+                    drain_deadline_ms: ctx.drain_timeout.as_millis().min(u32::MAX as u128) as u32,
+                };
+                socket.write_all(&MfbpCodec::encode_response(&going_away)).await?;
+                return Ok(());
+            }
+        };
         if n == 0 {
             // Connection closed
             return Ok(());
@@ -324,6 +833,8 @@ async fn handle_connection(
             // Decode request
             match MfbpCodec::decode_request(&frame) {
                 Ok(request) => {
+                    let _in_flight = ctx.shutdown.begin_request();
+
                     // Log request type
                     log_request(&request);
 
@@ -331,7 +842,15 @@ async fn handle_connection(
                     let response = handler.handle(request);
 
                     // Encode response
-                    let response_bytes = MfbpCodec::encode_response(&response);
+                    let mut response_bytes = MfbpCodec::encode_response(&response);
+                    if response_bytes.len() - 4 > MAX_FRAME_SIZE {
+                        // The client would drop the frame; large exports go over HTTP
+                        warn!("Response too large: {} bytes", response_bytes.len());
+                        response_bytes = MfbpCodec::encode_response(&Response::Error {
+                            code: mindfry::protocol::ErrorCode::Internal,
+                            message: "Response exceeds the frame limit (use GET /export)".into(),
+                        });
+                    }
 
                     // Send response
                     socket.write_all(&response_bytes).await?;
@@ -339,7 +858,7 @@ async fn handle_connection(
                 Err(e) => {
                     warn!("Failed to decode request: {}", e);
                     // Send error response
-                    let error_response = mindfry::protocol::Response::Error {
+                    let error_response = Response::Error {
                         code: mindfry::protocol::ErrorCode::MalformedPayload,
                         message: format!("Failed to decode: {}", e),
                     };
@@ -356,6 +875,7 @@ fn log_request(request: &Request) {
     match request {
         Request::Ping => info!("  → PING"),
         Request::Stats => info!("  → STATS"),
+        Request::Health => info!("  → SYS.HEALTH"),
         Request::LineageCreate { id, .. } => info!("  → LINEAGE.CREATE '{}'", id),
         Request::LineageGet { id, flags } => {
             info!("  → LINEAGE.GET '{}' [flags:0x{:02X}]", id, flags)
@@ -366,13 +886,31 @@ fn log_request(request: &Request) {
                 id, delta, flags
             )
         }
-        Request::LineageForget { id } => info!("  → LINEAGE.FORGET '{}'", id),
+        Request::LineageForget { id, flags } => {
+            info!("  → LINEAGE.FORGET '{}' [flags:0x{:02X}]", id, flags)
+        }
+        Request::Cascade { id, mode, .. } => info!("  → CASCADE.{:?} '{}'", mode, id),
+        Request::LineageProtect { id, level, flags } => {
+            info!(
+                "  → LINEAGE.PROTECT '{}' level {} [flags:0x{:02X}]",
+                id, level, flags
+            )
+        }
+        Request::LineageUnlock { id } => info!("  → LINEAGE.UNLOCK '{}'", id),
         Request::BondConnect { source, target, .. } => {
             info!("  → BOND.CONNECT '{}' ↔ '{}'", source, target)
         }
-        Request::QueryConscious { .. } => info!("  → QUERY.CONSCIOUS"),
-        Request::QueryTopK { k } => info!("  → QUERY.TOP_K({})", k),
-        Request::QueryTrauma { min_rigidity } => info!("  → QUERY.TRAUMA(≥{})", min_rigidity),
+        Request::QueryConscious { flags, .. } => {
+            info!("  → QUERY.CONSCIOUS [flags:0x{:02X}]", flags)
+        }
+        Request::QueryTopK { k, flags } => info!("  → QUERY.TOP_K({}) [flags:0x{:02X}]", k, flags),
+        Request::QueryTrauma {
+            min_rigidity,
+            flags,
+        } => info!(
+            "  → QUERY.TRAUMA(≥{}) [flags:0x{:02X}]",
+            min_rigidity, flags
+        ),
         Request::Snapshot { name } => info!("  → SYS.SNAPSHOT '{}'", name),
         Request::Freeze { frozen } => {
             info!("  → SYS.{}", if *frozen { "FREEZE" } else { "THAW" })
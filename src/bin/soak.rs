@@ -0,0 +1,635 @@
+//! MindFry Soak - Cognitive drift harness
+//!
+//! Replays a seeded workload against two embedded instances sharing a
+//! manual clock:
+//!
+//! - **soak**: snapshots periodically and crash-restarts from its latest
+//!   snapshot (losing anything written since)
+//! - **baseline**: the same workload, never interrupted
+//!
+//! At every report it emits drift metrics between the two: energy
+//! distribution divergence, top-K overlap and graph growth, plus an
+//! identity check after each restart.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! cargo run --release --bin mindfry-soak -- --days 30 --seed 7
+//! cargo run --release --bin mindfry-soak -- --days 7 --crash-every 12 --json > drift.ndjson
+//! ```
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use mindfry::arena::key_hash;
+use mindfry::clock::{self, Clock, ManualClock};
+use mindfry::persistence::snapshot::AUTO_PREFIX;
+use mindfry::persistence::{AkashicConfig, AkashicStore, PhysicsSnapshot};
+use mindfry::protocol::{CommandHandler, ErrorCode, Request, Response};
+use mindfry::stability::{lineages, ExhaustionMonitor, RecoveryState, WarmupTracker};
+use mindfry::{MindFry, MindFryConfig};
+
+/// Energy histogram resolution for divergence
+const ENERGY_BINS: usize = 10;
+
+/// Soak configuration
+struct SoakConfig {
+    seed: u64,
+    /// Simulated days to run
+    days: u64,
+    /// Workload operations per simulated hour
+    ops_per_hour: u64,
+    /// Size of the key space
+    keys: usize,
+    /// Simulated seconds per maintenance tick
+    tick_secs: u64,
+    /// Hours between snapshots of the soak instance
+    snapshot_every: u64,
+    /// Hours between crash-restarts of the soak instance (0 = never)
+    crash_every: u64,
+    /// Hours between drift reports
+    report_every: u64,
+    top_k: usize,
+    /// Store directory (temporary when None)
+    data_dir: Option<String>,
+    json: bool,
+}
+
+impl Default for SoakConfig {
+    fn default() -> Self {
+        Self {
+            seed: 42,
+            days: 30,
+            ops_per_hour: 120,
+            keys: 512,
+            tick_secs: 60,
+            snapshot_every: 6,
+            crash_every: 24,
+            report_every: 24,
+            top_k: 20,
+            data_dir: None,
+            json: false,
+        }
+    }
+}
+
+impl SoakConfig {
+    fn from_args() -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut args = std::env::args().skip(1);
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {}", flag))
+            };
+            match flag.as_str() {
+                "--seed" => config.seed = parse_flag(&flag, &value()?)?,
+                "--days" => config.days = parse_flag(&flag, &value()?)?,
+                "--ops-per-hour" => config.ops_per_hour = parse_flag(&flag, &value()?)?,
+                "--keys" => config.keys = parse_flag(&flag, &value()?)?,
+                "--tick-secs" => config.tick_secs = parse_flag(&flag, &value()?)?,
+                "--snapshot-every" => config.snapshot_every = parse_flag(&flag, &value()?)?,
+                "--crash-every" => config.crash_every = parse_flag(&flag, &value()?)?,
+                "--report-every" => config.report_every = parse_flag(&flag, &value()?)?,
+                "--top-k" => config.top_k = parse_flag(&flag, &value()?)?,
+                "--data-dir" => config.data_dir = Some(value()?),
+                "--json" => config.json = true,
+                other => return Err(format!("Unknown argument: {}", other)),
+            }
+        }
+
+        if config.keys == 0 || config.tick_secs == 0 || config.tick_secs > 3600 {
+            return Err("--keys must be > 0 and --tick-secs in 1..=3600".into());
+        }
+        config.report_every = config.report_every.max(1);
+        Ok(config)
+    }
+}
+
+fn parse_flag<T: std::str::FromStr>(flag: &str, raw: &str) -> Result<T, String> {
+    raw.parse()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, raw))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// WORKLOAD
+// ═══════════════════════════════════════════════════════════════
+
+/// xorshift64* - deterministic, dependency-free
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Avoid the all-zero state
+        Self(seed ^ 0x9E37_79B9_7F4A_7C15)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Uniform in [0, 1)
+    fn unit(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn range(&mut self, lo: f32, hi: f32) -> f32 {
+        lo + (hi - lo) * self.unit()
+    }
+
+    /// Index skewed towards 0, so a few keys stay hot
+    fn skewed(&mut self, n: usize) -> usize {
+        let r = self.unit();
+        ((r * r * n as f32) as usize).min(n - 1)
+    }
+}
+
+/// Seeded request generator
+struct Workload {
+    rng: Rng,
+    keys: usize,
+}
+
+impl Workload {
+    fn key(i: usize) -> String {
+        format!("k{}", i)
+    }
+
+    fn next(&mut self) -> Request {
+        let roll = self.rng.unit();
+        let a = Self::key(self.rng.skewed(self.keys));
+        if roll < 0.40 {
+            Request::LineageStimulate {
+                id: a,
+                delta: self.rng.range(0.05, 0.3),
+                flags: 0,
+            }
+        } else if roll < 0.55 {
+            let i = (self.rng.next_u64() % self.keys as u64) as usize;
+            Request::LineageCreate {
+                id: Self::key(i),
+                energy: self.rng.range(0.3, 0.9),
+                threshold: 0.5,
+                decay_rate: 0.001,
+            }
+        } else if roll < 0.75 {
+            Request::BondConnect {
+                source: a,
+                target: Self::key(self.rng.skewed(self.keys)),
+                strength: self.rng.range(0.1, 0.6),
+                polarity: 1,
+            }
+        } else if roll < 0.90 {
+            Request::BondReinforce {
+                source: a,
+                target: Self::key(self.rng.skewed(self.keys)),
+                delta: self.rng.range(0.05, 0.2),
+            }
+        } else if roll < 0.95 {
+            Request::LineageForget { id: a, flags: 0 }
+        } else {
+            Request::LineageGet { id: a, flags: 0 }
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// INSTANCES
+// ═══════════════════════════════════════════════════════════════
+
+/// An embedded MindFry driven through the real command handler
+struct Instance {
+    db: Arc<RwLock<MindFry>>,
+    handler: CommandHandler,
+    store: Option<Arc<AkashicStore>>,
+    exhaustion: ExhaustionMonitor,
+    rejected: u64,
+    failed: u64,
+}
+
+impl Instance {
+    fn new(store: Option<Arc<AkashicStore>>, recovery: RecoveryState) -> Self {
+        let mut db = MindFry::with_config(db_config());
+        if let Some(store) = &store {
+            db = db.with_store(Arc::clone(store));
+            if let Err(e) = db.resurrect() {
+                eprintln!("⚠️ Resurrection failed: {}", e);
+            }
+        }
+        db.bootstrap_system_lineages();
+        db.apply_recovery(recovery);
+
+        let db = Arc::new(RwLock::new(db));
+        let warmup = WarmupTracker::new();
+        warmup.mark_ready();
+        let handler = CommandHandler::with_warmup(Arc::clone(&db), warmup).with_recovery(recovery);
+
+        Self {
+            db,
+            handler,
+            store,
+            exhaustion: ExhaustionMonitor::default(),
+            rejected: 0,
+            failed: 0,
+        }
+    }
+
+    fn apply(&mut self, request: Request) {
+        match self.handler.handle(request) {
+            Response::Rejected { .. } => self.rejected += 1,
+            Response::Error { code, .. }
+                if !matches!(
+                    code,
+                    ErrorCode::LineageExists
+                        | ErrorCode::LineageNotFound
+                        | ErrorCode::BondExists
+                        | ErrorCode::BondNotFound
+                ) =>
+            {
+                self.failed += 1
+            }
+            _ => {}
+        }
+    }
+
+    /// Same maintenance the server runs every tick
+    fn tick(&self) {
+        let mut db = self.db.write().unwrap();
+        db.settle_charges();
+        db.observe_exhaustion();
+        db.stimulate_system(lineages::STATE, self.exhaustion.recovery_rate);
+        db.maintenance_tick(false);
+    }
+
+    fn snapshot(&self, name: &str) {
+        if let Some(store) = &self.store {
+            // Same path as the server's scheduler: capture, then write unlocked
+            let checkpoint = self.db.write().unwrap().checkpoint();
+            if let Err(e) =
+                store.write_checkpoint(Some(name), &checkpoint, PhysicsSnapshot::default())
+            {
+                eprintln!("⚠️ Snapshot '{}' failed: {}", name, e);
+            }
+        }
+    }
+
+    /// Drop all in-memory state and resurrect from the latest snapshot
+    fn crash_restart(&mut self) {
+        let store = self.store.clone();
+        *self = Self {
+            rejected: self.rejected,
+            failed: self.failed,
+            ..Self::new(store, RecoveryState::Shock)
+        };
+    }
+
+    /// Energy of every workload key as of `now` (None when unresolvable)
+    fn key_energies(&self, keys: usize, now: u64) -> Vec<Option<f32>> {
+        let db = self.db.read().unwrap();
+        (0..keys)
+            .map(|i| {
+                db.psyche
+                    .lookup(key_hash(&Workload::key(i)))
+                    .and_then(|id| db.psyche.get(id))
+                    .map(|l| l.current_energy_at(now))
+            })
+            .collect()
+    }
+
+    fn graph(&self) -> GraphStats {
+        let db = self.db.read().unwrap();
+        let max_degree = db
+            .psyche
+            .iter()
+            .map(|(id, _)| db.bonds.neighbors(id).len())
+            .max()
+            .unwrap_or(0);
+        GraphStats {
+            lineages: db.psyche.len(),
+            bonds: db.bonds.len(),
+            max_degree,
+        }
+    }
+
+    fn energy_histogram(&self, now: u64) -> [f64; ENERGY_BINS] {
+        let db = self.db.read().unwrap();
+        let mut bins = [0.0; ENERGY_BINS];
+        for (_, lineage) in db.psyche.iter() {
+            let energy = lineage.current_energy_at(now).clamp(0.0, 1.0);
+            let bin = ((energy * ENERGY_BINS as f32) as usize).min(ENERGY_BINS - 1);
+            bins[bin] += 1.0;
+        }
+        bins
+    }
+}
+
+fn db_config() -> MindFryConfig {
+    MindFryConfig {
+        max_lineages: 4096,
+        max_bonds: 1 << 16,
+        ..Default::default()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// DRIFT METRICS
+// ═══════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Copy, Serialize)]
+struct GraphStats {
+    lineages: usize,
+    bonds: usize,
+    max_degree: usize,
+}
+
+/// Key-level comparison of a restored instance against its snapshot
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+struct IdentityCheck {
+    /// Keys alive at snapshot time
+    expected: usize,
+    /// Of those, keys still resolvable after restore
+    resolved: usize,
+    /// Largest energy difference among resolved keys
+    max_energy_diff: f32,
+}
+
+#[derive(Debug, Serialize)]
+struct DriftReport {
+    day: f64,
+    soak: GraphStats,
+    baseline: GraphStats,
+    /// Jensen-Shannon divergence of energy distributions (0 = identical, 1 = disjoint)
+    energy_divergence: f64,
+    /// Fraction of the baseline's top-K keys also in the soak's top-K
+    top_k_overlap: f64,
+    restarts: u64,
+    rejected: u64,
+    failed: u64,
+    last_identity: Option<IdentityCheck>,
+}
+
+fn js_divergence(p: &[f64; ENERGY_BINS], q: &[f64; ENERGY_BINS]) -> f64 {
+    let normalize = |h: &[f64; ENERGY_BINS]| {
+        let total: f64 = h.iter().sum();
+        h.map(|v| if total > 0.0 { v / total } else { 0.0 })
+    };
+    let (p, q) = (normalize(p), normalize(q));
+    let kl = |a: &[f64; ENERGY_BINS], m: &[f64; ENERGY_BINS]| {
+        a.iter()
+            .zip(m)
+            .filter(|(a, _)| **a > 0.0)
+            .map(|(a, m)| a * (a / m).log2())
+            .sum::<f64>()
+    };
+    let m: [f64; ENERGY_BINS] = std::array::from_fn(|i| (p[i] + q[i]) / 2.0);
+    (kl(&p, &m) + kl(&q, &m)) / 2.0
+}
+
+fn top_k(energies: &[Option<f32>], k: usize) -> Vec<usize> {
+    let mut ranked: Vec<(usize, f32)> = energies
+        .iter()
+        .enumerate()
+        .filter_map(|(i, e)| e.map(|e| (i, e)))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    ranked.into_iter().take(k).map(|(i, _)| i).collect()
+}
+
+fn top_k_overlap(soak: &[Option<f32>], baseline: &[Option<f32>], k: usize) -> f64 {
+    let expected = top_k(baseline, k);
+    if expected.is_empty() {
+        return 1.0;
+    }
+    let actual = top_k(soak, k);
+    let shared = expected.iter().filter(|i| actual.contains(i)).count();
+    shared as f64 / expected.len() as f64
+}
+
+fn identity_check(before: &[Option<f32>], after: &[Option<f32>]) -> IdentityCheck {
+    let mut check = IdentityCheck::default();
+    for (b, a) in before.iter().zip(after) {
+        if let Some(b) = b {
+            check.expected += 1;
+            if let Some(a) = a {
+                check.resolved += 1;
+                check.max_energy_diff = check.max_energy_diff.max((a - b).abs());
+            }
+        }
+    }
+    check
+}
+
+// ═══════════════════════════════════════════════════════════════
+// MAIN
+// ═══════════════════════════════════════════════════════════════
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let config = SoakConfig::from_args()?;
+
+    // Simulated time: both instances share one manually advanced clock
+    let clock = Arc::new(ManualClock::starting_now());
+    if clock::install(clock.clone()).is_err() {
+        return Err("clock already installed".into());
+    }
+
+    run(&config, &clock, |report| print_report(report, config.json))
+}
+
+/// Drive both instances through `config.days` on `clock`, handing each
+/// drift report to `emit`
+fn run(
+    config: &SoakConfig,
+    clock: &ManualClock,
+    mut emit: impl FnMut(&DriftReport) -> Result<(), Box<dyn std::error::Error>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (data_dir, temporary) = match &config.data_dir {
+        Some(dir) => (dir.clone(), false),
+        None => {
+            let dir = std::env::temp_dir().join(format!(
+                "mindfry-soak-{}-{}",
+                config.seed,
+                std::process::id()
+            ));
+            (dir.to_string_lossy().into_owned(), true)
+        }
+    };
+    let store = Arc::new(AkashicStore::open(AkashicConfig {
+        path: data_dir.clone(),
+        ..Default::default()
+    })?);
+
+    let mut soak = Instance::new(Some(store), RecoveryState::Normal);
+    let mut baseline = Instance::new(None, RecoveryState::Normal);
+    let mut workload = Workload {
+        rng: Rng::new(config.seed),
+        keys: config.keys,
+    };
+
+    if !config.json {
+        println!(
+            "🧪 Soak: {} days, {} ops/h, {} keys, seed {} (snapshot every {}h, crash every {}h)",
+            config.days,
+            config.ops_per_hour,
+            config.keys,
+            config.seed,
+            config.snapshot_every,
+            config.crash_every
+        );
+    }
+
+    let ticks_per_hour = 3600 / config.tick_secs;
+    let mut restarts = 0;
+    let mut snapshot_energies: Option<(u64, Vec<Option<f32>>)> = None;
+    let mut last_identity = None;
+
+    for hour in 1..=config.days * 24 {
+        for tick in 0..ticks_per_hour {
+            // Spread the hour's operations evenly over its ticks
+            let ops = config.ops_per_hour * (tick + 1) / ticks_per_hour
+                - config.ops_per_hour * tick / ticks_per_hour;
+            for _ in 0..ops {
+                let request = workload.next();
+                soak.apply(request.clone());
+                baseline.apply(request);
+            }
+            clock.advance(Duration::from_secs(config.tick_secs));
+            soak.tick();
+            baseline.tick();
+        }
+
+        if config.snapshot_every > 0 && hour % config.snapshot_every == 0 {
+            let now = clock.now_nanos();
+            soak.snapshot(&format!("{}soak-h{}", AUTO_PREFIX, hour));
+            snapshot_energies = Some((now, soak.key_energies(config.keys, now)));
+        }
+
+        if config.crash_every > 0 && hour % config.crash_every == 0 {
+            soak.crash_restart();
+            restarts += 1;
+            if let Some((taken_at, before)) = &snapshot_energies {
+                // Compare as of the snapshot instant, so decay since then cancels out
+                let after = soak.key_energies(config.keys, *taken_at);
+                last_identity = Some(identity_check(before, &after));
+            }
+        }
+
+        if hour % config.report_every == 0 {
+            let now = clock.now_nanos();
+            let report = DriftReport {
+                day: hour as f64 / 24.0,
+                soak: soak.graph(),
+                baseline: baseline.graph(),
+                energy_divergence: js_divergence(
+                    &soak.energy_histogram(now),
+                    &baseline.energy_histogram(now),
+                ),
+                top_k_overlap: top_k_overlap(
+                    &soak.key_energies(config.keys, now),
+                    &baseline.key_energies(config.keys, now),
+                    config.top_k,
+                ),
+                restarts,
+                rejected: soak.rejected,
+                failed: soak.failed,
+                last_identity,
+            };
+            emit(&report)?;
+        }
+    }
+
+    drop(soak);
+    if temporary {
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
+    Ok(())
+}
+
+fn print_report(report: &DriftReport, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if json {
+        println!("{}", serde_json::to_string(report)?);
+        return Ok(());
+    }
+
+    println!(
+        "day {:>6.1} | lineages {:>5}/{:<5} bonds {:>6}/{:<6} max-degree {:>4}/{:<4} | JS {:.4} | top-K {:.2} | restarts {} rejected {} failed {}",
+        report.day,
+        report.soak.lineages,
+        report.baseline.lineages,
+        report.soak.bonds,
+        report.baseline.bonds,
+        report.soak.max_degree,
+        report.baseline.max_degree,
+        report.energy_divergence,
+        report.top_k_overlap,
+        report.restarts,
+        report.rejected,
+        report.failed,
+    );
+    if let Some(identity) = report.last_identity {
+        println!(
+            "           identity: {}/{} keys resolved after restore, max Δenergy {:.6}",
+            identity.resolved, identity.expected, identity.max_energy_diff
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_soak_keeps_report_invariants() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = SoakConfig {
+            seed: 7,
+            days: 2,
+            ops_per_hour: 60,
+            keys: 64,
+            tick_secs: 600,
+            snapshot_every: 6,
+            crash_every: 12,
+            report_every: 12,
+            top_k: 10,
+            data_dir: Some(dir.path().to_string_lossy().into_owned()),
+            json: true,
+        };
+        // The only test in this binary, so it owns the process clock
+        let clock = Arc::new(ManualClock::new(clock::NANOS_PER_SEC));
+        clock::install(clock.clone()).unwrap();
+
+        let mut reports = Vec::new();
+        run(&config, &clock, |report| {
+            reports.push(serde_json::to_value(report)?);
+            Ok(())
+        })
+        .unwrap();
+
+        // Every report_every hours, a crash-restart right before each
+        assert_eq!(reports.len(), 4);
+        for (i, report) in reports.iter().enumerate() {
+            assert_eq!(report["day"], (i + 1) as f64 / 2.0);
+            assert_eq!(report["restarts"], i as u64 + 1);
+            assert_eq!(report["failed"], 0);
+
+            let divergence = report["energy_divergence"].as_f64().unwrap();
+            let overlap = report["top_k_overlap"].as_f64().unwrap();
+            assert!((0.0..=1.0).contains(&divergence));
+            assert!((0.0..=1.0).contains(&overlap));
+            assert!(report["soak"]["lineages"].as_u64().unwrap() > 0);
+            // Workload keys plus the handful of `_system.*` lineages
+            assert!(report["baseline"]["lineages"].as_u64().unwrap() <= config.keys as u64 + 16);
+
+            // Snapshots land on the crash hour, so a restore loses nothing
+            let identity = &report["last_identity"];
+            assert!(identity["expected"].as_u64().unwrap() > 0);
+            assert_eq!(identity["resolved"], identity["expected"]);
+            assert!(identity["max_energy_diff"].as_f64().unwrap() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Clock - Injectable time source
+//!
+//! All decay math is driven by "nanoseconds since epoch". Reading
+//! `SystemTime` directly makes decay untestable without sleeping, so
+//! time comes from a [`Clock`] instead:
+//!
+//! - [`SystemClock`]: wall time (default)
+//! - [`ManualClock`]: advanced by hand, for deterministic tests
+//! - [`AcceleratedClock`]: wall time scaled by a factor, for soak runs
+//!
+//! The process-wide clock is installed once via [`install`]; types that
+//! need determinism also accept an explicit `now` (`*_at` methods).
+
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Nanoseconds per second
+pub const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// Source of "now"
+pub trait Clock: Send + Sync + Debug {
+    /// Current time in nanoseconds since epoch
+    fn now_nanos(&self) -> u64;
+
+    /// Current time in whole seconds since epoch
+    fn now_secs(&self) -> u64 {
+        self.now_nanos() / NANOS_PER_SEC
+    }
+}
+
+/// Shared clock handle
+pub type SharedClock = Arc<dyn Clock>;
+
+// ═══════════════════════════════════════════════════════════════
+// IMPLEMENTATIONS
+// ═══════════════════════════════════════════════════════════════
+
+/// Wall-clock time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now_nanos(&self) -> u64 {
+        wall_nanos()
+    }
+}
+
+/// Clock that only moves when told to
+#[derive(Debug)]
+pub struct ManualClock {
+    nanos: AtomicU64,
+}
+
+impl ManualClock {
+    /// Start at the given timestamp (nanoseconds since epoch)
+    pub fn new(start_nanos: u64) -> Self {
+        Self {
+            nanos: AtomicU64::new(start_nanos),
+        }
+    }
+
+    /// Start at the current wall time
+    pub fn starting_now() -> Self {
+        Self::new(wall_nanos())
+    }
+
+    /// Move time forward
+    pub fn advance(&self, by: Duration) {
+        self.nanos.fetch_add(by.as_nanos() as u64, Ordering::AcqRel);
+    }
+
+    /// Jump to an absolute timestamp
+    pub fn set(&self, nanos: u64) {
+        self.nanos.store(nanos, Ordering::Release);
+    }
+}
+
+impl Clock for ManualClock {
+    #[inline]
+    fn now_nanos(&self) -> u64 {
+        self.nanos.load(Ordering::Acquire)
+    }
+}
+
+/// Wall time running `factor` times faster from the moment of creation
+///
+/// A factor of 60 turns a one-hour soak into 60 hours of decay.
+#[derive(Debug)]
+pub struct AcceleratedClock {
+    origin: u64,
+    factor: f64,
+}
+
+impl AcceleratedClock {
+    /// Create a clock running `factor`× wall speed (clamped to ≥ 0)
+    pub fn new(factor: f64) -> Self {
+        Self {
+            origin: wall_nanos(),
+            factor: factor.max(0.0),
+        }
+    }
+
+    /// Speed-up factor
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+}
+
+impl Clock for AcceleratedClock {
+    fn now_nanos(&self) -> u64 {
+        let real = wall_nanos().saturating_sub(self.origin);
+        self.origin + (real as f64 * self.factor) as u64
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// PROCESS CLOCK
+// ═══════════════════════════════════════════════════════════════
+
+static GLOBAL: OnceLock<SharedClock> = OnceLock::new();
+
+/// Install the process-wide clock
+///
+/// Must happen before anything reads the time; fails (returning the
+/// rejected clock) if a clock is already in use.
+pub fn install(clock: SharedClock) -> Result<(), SharedClock> {
+    GLOBAL.set(clock)
+}
+
+/// The process-wide clock ([`SystemClock`] unless [`install`]ed)
+pub fn global() -> SharedClock {
+    Arc::clone(GLOBAL.get_or_init(|| Arc::new(SystemClock)))
+}
+
+/// Current time from the process-wide clock (nanoseconds since epoch)
+#[inline]
+pub fn now_nanos() -> u64 {
+    match GLOBAL.get() {
+        Some(clock) => clock.now_nanos(),
+        None => wall_nanos(),
+    }
+}
+
+/// Current time from the process-wide clock (seconds since epoch)
+#[inline]
+pub fn now_secs() -> u64 {
+    now_nanos() / NANOS_PER_SEC
+}
+
+/// Seconds between `since` and `now` (0 if `now` is earlier)
+#[inline]
+pub fn elapsed_seconds(since: u64, now: u64) -> f32 {
+    (now.saturating_sub(since) as f64 / NANOS_PER_SEC as f64) as f32
+}
+
+#[inline]
+fn wall_nanos() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::new(1_000);
+        assert_eq!(clock.now_nanos(), 1_000);
+
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(clock.now_nanos(), 1_000 + 2 * NANOS_PER_SEC);
+        assert_eq!(clock.now_secs(), 2);
+
+        clock.set(0);
+        assert_eq!(clock.now_nanos(), 0);
+    }
+
+    #[test]
+    fn test_accelerated_clock() {
+        let clock = AcceleratedClock::new(1000.0);
+        let start = clock.now_nanos();
+        std::thread::sleep(Duration::from_millis(5));
+        // 5ms of wall time is at least 5s of clock time
+        assert!(clock.now_nanos() - start >= 5 * NANOS_PER_SEC);
+    }
+
+    #[test]
+    fn test_elapsed_seconds() {
+        assert_eq!(elapsed_seconds(0, 3 * NANOS_PER_SEC), 3.0);
+        assert_eq!(elapsed_seconds(5, 1), 0.0);
+    }
+}
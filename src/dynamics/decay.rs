@@ -5,7 +5,8 @@
 
 use rayon::prelude::*;
 
-use crate::arena::{Lineage, PsycheArena};
+use crate::arena::{Lineage, Protection, PsycheArena, Temperature};
+use crate::clock::{self, SharedClock};
 use crate::graph::{BondGraph, BOND_PRUNE_THRESHOLD};
 
 /// Decay engine configuration
@@ -22,6 +23,9 @@ pub struct DecayConfig {
 
     /// Whether to use parallel processing
     pub parallel: bool,
+
+    /// Temperature tier transitions
+    pub tiers: TierConfig,
 }
 
 impl Default for DecayConfig {
@@ -31,6 +35,64 @@ impl Default for DecayConfig {
             min_energy_threshold: 0.001,
             bond_prune_threshold: BOND_PRUNE_THRESHOLD,
             parallel: true,
+            tiers: TierConfig::default(),
+        }
+    }
+}
+
+/// When the decay tick moves lineages between temperature tiers
+///
+/// A lineage accessed since the previous tick is promoted one tier
+/// (Cold → Warm → Hot), so reaching Hot from Cold takes accesses in two
+/// separate ticks. An idle lineage is demoted one tier once it has spent
+/// the tier's idle window without access. Frozen is never left
+/// automatically; it takes an explicit unlock.
+#[derive(Debug, Clone, Copy)]
+pub struct TierConfig {
+    /// Idle seconds before Hot cools to Warm
+    pub hot_idle_secs: u64,
+    /// Idle seconds before Warm cools to Cold
+    pub warm_idle_secs: u64,
+    /// Idle seconds before Cold freezes (None = never freeze automatically)
+    pub cold_idle_secs: Option<u64>,
+}
+
+impl Default for TierConfig {
+    fn default() -> Self {
+        Self {
+            hot_idle_secs: 60,
+            warm_idle_secs: 3600,
+            cold_idle_secs: Some(30 * 24 * 3600),
+        }
+    }
+}
+
+impl TierConfig {
+    /// Tier a lineage should move to, if any
+    ///
+    /// `since` is the previous tick; accesses after it count as fresh.
+    pub fn next(&self, lineage: &Lineage, since: u64, now: u64) -> Option<Temperature> {
+        let tier = lineage.temperature();
+        if tier == Temperature::Frozen {
+            return None;
+        }
+
+        if lineage.last_access > since {
+            return match tier {
+                Temperature::Cold => Some(Temperature::Warm),
+                Temperature::Warm => Some(Temperature::Hot),
+                _ => None,
+            };
+        }
+
+        let idle_secs = now.saturating_sub(lineage.last_access) / clock::NANOS_PER_SEC;
+        match tier {
+            Temperature::Hot if idle_secs >= self.hot_idle_secs => Some(Temperature::Warm),
+            Temperature::Warm if idle_secs >= self.warm_idle_secs => Some(Temperature::Cold),
+            Temperature::Cold if self.cold_idle_secs.is_some_and(|s| idle_secs >= s) => {
+                Some(Temperature::Frozen)
+            }
+            _ => None,
         }
     }
 }
@@ -134,18 +196,31 @@ pub struct DecayEngine {
     lut: DecayLUT,
     /// Last tick timestamp (nanoseconds)
     last_tick: u64,
+    /// Time source for ticks
+    clock: SharedClock,
 }
 
 impl DecayEngine {
-    /// Create a new decay engine
+    /// Create a new decay engine on the process-wide clock
     pub fn new(config: DecayConfig) -> Self {
+        Self::with_clock(config, clock::global())
+    }
+
+    /// Create a decay engine driven by a specific clock
+    pub fn with_clock(config: DecayConfig, clock: SharedClock) -> Self {
         Self {
             config,
             lut: DecayLUT::new(),
-            last_tick: now_nanos(),
+            last_tick: clock.now_nanos(),
+            clock,
         }
     }
 
+    /// Clock driving this engine
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
     /// Get the decay factor using LUT
     #[inline]
     pub fn decay_factor(&self, decay_rate: f32, elapsed_secs: f32) -> f32 {
@@ -154,46 +229,65 @@ impl DecayEngine {
 
     /// Run a decay tick on the psyche arena
     ///
-    /// Returns the number of lineages that dropped below threshold
+    /// Counts lineages that dropped below threshold and moves lineages
+    /// between temperature tiers.
     pub fn tick_psyche(&mut self, psyche: &mut PsycheArena) -> DecayTickResult {
-        let now = now_nanos();
+        let now = self.clock.now_nanos();
 
         let mut dead_count = 0;
         let mut processed = 0;
+        let mut transitions = 0;
 
         // Get mutable slice for parallel processing
-        // Note: We iterate and check, but don't actually mutate energy here
-        // because decay is lazy (computed on access). This tick is for cleanup.
+        // Note: Decay is lazy (computed on access); energy is only folded in
+        // when a lineage changes tier. This tick is for cleanup.
         for (_id, lineage) in psyche.iter_mut() {
             processed += 1;
-            let energy = lineage.current_energy();
+            let energy = lineage.current_energy_at(now);
             if energy < self.config.min_energy_threshold {
                 dead_count += 1;
             }
+            if let Some(tier) = self.config.tiers.next(lineage, self.last_tick, now) {
+                lineage.set_temperature_at(tier, now);
+                transitions += 1;
+            }
         }
 
+        let elapsed_ms = now.saturating_sub(self.last_tick) / 1_000_000;
         self.last_tick = now;
 
         DecayTickResult {
             processed,
             dead_count,
-            elapsed_ms: (now - self.last_tick) / 1_000_000,
+            transitions,
+            elapsed_ms,
         }
     }
 
     /// Run bond pruning
     ///
-    /// Returns the number of bonds pruned
-    pub fn prune_bonds(&self, bonds: &mut BondGraph) -> usize {
-        bonds.prune(self.config.bond_prune_threshold)
+    /// Both endpoints of every pruned bond are marked `DIRTY`, so delta
+    /// checkpoints record the removal. Returns the number of bonds pruned.
+    pub fn prune_bonds(&self, bonds: &mut BondGraph, psyche: &mut PsycheArena) -> usize {
+        let pruned =
+            bonds.prune_endpoints_at(self.config.bond_prune_threshold, self.clock.now_nanos());
+        for &(source, target) in &pruned {
+            psyche.mark_dirty(source);
+            psyche.mark_dirty(target);
+        }
+        pruned.len()
     }
 
     /// Batch compute decay factors (for parallel processing)
     pub fn batch_decay_factors(&self, lineages: &[Lineage]) -> Vec<f32> {
+        let now = self.clock.now_nanos();
         if self.config.parallel {
-            lineages.par_iter().map(|l| l.current_energy()).collect()
+            lineages
+                .par_iter()
+                .map(|l| l.current_energy_at(now))
+                .collect()
         } else {
-            lineages.iter().map(|l| l.current_energy()).collect()
+            lineages.iter().map(|l| l.current_energy_at(now)).collect()
         }
     }
 
@@ -201,7 +295,8 @@ impl DecayEngine {
     ///
     /// This method evaluates each lineage's viability using the Cortex's
     /// personality-aware decision making, and uses the RetentionBuffer
-    /// for TTL-based safe deletion.
+    /// for TTL-based safe deletion. Protected lineages (any level above
+    /// Normal) always count as retained.
     ///
     /// # Returns
     /// `GcResult` with counts of processed, retained, pending, and pruned lineages.
@@ -221,9 +316,18 @@ impl DecayEngine {
         let preservation_bias =
             cortex.personality().get(dimension::PRESERVATION).weight() as f64 * 0.1;
 
+        let now = self.clock.now_nanos();
         for (id, lineage) in psyche.iter_mut() {
             processed += 1;
-            let energy = lineage.current_energy() as f64;
+
+            // Protected lineages are never collected
+            if lineage.protection() != Protection::Normal {
+                cortex.retention_mut().restore(id.index());
+                retained += 1;
+                continue;
+            }
+
+            let energy = lineage.current_energy_at(now) as f64;
 
             // Viability score: energy relative to death threshold
             // Positive = healthy, negative = dying
@@ -264,7 +368,11 @@ impl DecayEngine {
 
         // Execute pruning
         let pruned = disposables.len();
+        let mut pruned_keys = Vec::new();
         for id in disposables {
+            if let Some(key) = psyche.key_of(id) {
+                pruned_keys.push(key.to_string());
+            }
             psyche.free(id);
         }
 
@@ -273,6 +381,7 @@ impl DecayEngine {
             retained,
             pending,
             pruned,
+            pruned_keys,
         }
     }
 }
@@ -288,6 +397,8 @@ pub struct GcResult {
     pub pending: usize,
     /// Lineages pruned this tick
     pub pruned: usize,
+    /// Keys of the pruned lineages (for index cleanup)
+    pub pruned_keys: Vec<String>,
 }
 
 impl Default for DecayEngine {
@@ -303,22 +414,12 @@ pub struct DecayTickResult {
     pub processed: usize,
     /// Number of lineages below death threshold
     pub dead_count: usize,
+    /// Number of temperature tier changes
+    pub transitions: usize,
     /// Elapsed time since last tick (ms)
     pub elapsed_ms: u64,
 }
 
-// ═══════════════════════════════════════════════════════════════
-// TIME UTILITIES
-// ═══════════════════════════════════════════════════════════════
-
-#[inline]
-fn now_nanos() -> u64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_nanos() as u64)
-        .unwrap_or(0)
-}
-
 // ═══════════════════════════════════════════════════════════════
 // TESTS
 // ═══════════════════════════════════════════════════════════════
@@ -368,6 +469,29 @@ mod tests {
         assert_eq!(result.processed, 2);
     }
 
+    #[test]
+    fn test_decay_tick_with_manual_clock() {
+        use crate::clock::{Clock, ManualClock};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let clock = Arc::new(ManualClock::new(0));
+        let mut engine = DecayEngine::with_clock(DecayConfig::default(), clock.clone());
+        let mut psyche = PsycheArena::with_capacity(4);
+
+        let mut lineage = Lineage::with_config(0.0, 0.5, 0.1);
+        lineage.stimulate_at(0.5, clock.now_nanos());
+        psyche.alloc(lineage);
+
+        assert_eq!(engine.tick_psyche(&mut psyche).dead_count, 0);
+
+        // Two minutes of simulated time, no sleeping
+        clock.advance(Duration::from_secs(120));
+        let result = engine.tick_psyche(&mut psyche);
+        assert_eq!(result.dead_count, 1);
+        assert_eq!(result.elapsed_ms, 120_000);
+    }
+
     #[test]
     fn test_process_gc_with_retention() {
         use crate::setun::{dimension, Cortex, Octet, Trit};
@@ -408,4 +532,68 @@ mod tests {
         assert_eq!(psyche.len(), 1); // Only healthy one remains
         assert_eq!(cortex.pending_removal_count(), 0);
     }
+
+    #[test]
+    fn test_decay_tick_moves_temperature_tiers() {
+        use crate::arena::Temperature;
+        use crate::clock::{Clock, ManualClock};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let clock = Arc::new(ManualClock::new(0));
+        let mut engine = DecayEngine::with_clock(DecayConfig::default(), clock.clone());
+        let mut psyche = PsycheArena::with_capacity(4);
+
+        let mut lineage = Lineage::with_config(0.9, 0.5, 0.0);
+        lineage.last_access = 0;
+        let id = psyche.alloc(lineage);
+        let tier = |psyche: &PsycheArena| psyche.get(id).unwrap().temperature();
+
+        // Accessed since the last tick: Warm → Hot
+        clock.advance(Duration::from_secs(1));
+        psyche.get_mut(id).unwrap().touch_at(clock.now_nanos());
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(engine.tick_psyche(&mut psyche).transitions, 1);
+        assert_eq!(tier(&psyche), Temperature::Hot);
+
+        // A promotion is not an access; idle windows demote one tier each
+        clock.advance(Duration::from_secs(60));
+        engine.tick_psyche(&mut psyche);
+        assert_eq!(tier(&psyche), Temperature::Warm);
+        clock.advance(Duration::from_secs(3600));
+        engine.tick_psyche(&mut psyche);
+        assert_eq!(tier(&psyche), Temperature::Cold);
+        clock.advance(Duration::from_secs(30 * 24 * 3600));
+        engine.tick_psyche(&mut psyche);
+        assert_eq!(tier(&psyche), Temperature::Frozen);
+
+        // Frozen is sticky, even when accessed
+        psyche.get_mut(id).unwrap().touch_at(clock.now_nanos());
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(engine.tick_psyche(&mut psyche).transitions, 0);
+        assert_eq!(tier(&psyche), Temperature::Frozen);
+    }
+
+    #[test]
+    fn test_process_gc_skips_protected() {
+        use crate::setun::Cortex;
+
+        let engine = DecayEngine::default();
+        let mut psyche = PsycheArena::with_capacity(4);
+        let mut cortex = Cortex::default();
+
+        let mut pinned = Lineage::new(0.0);
+        pinned.flags.insert(crate::arena::LineageFlags::PINNED);
+        psyche.alloc(pinned);
+        psyche.alloc(Lineage::new(0.0));
+
+        for _ in 0..8 {
+            engine.process_gc(&mut psyche, &mut cortex);
+        }
+        assert_eq!(psyche.len(), 1);
+        assert_eq!(
+            psyche.get(crate::arena::LineageId(0)).unwrap().protection(),
+            Protection::Pinned
+        );
+    }
 }
@@ -2,11 +2,14 @@
 //!
 //! This module provides the core dynamics:
 //! - `DecayEngine`: Background decay computation
-//! - `SynapseEngine`: Polarity-aware signal propagation
+//! - `SynapseEngine`: Polarity-aware signal propagation and cascades
 //! - `Learner`: Hebbian association (future)
 
 mod decay;
 mod synapse;
 
-pub use decay::{DecayConfig, DecayEngine, GcResult};
-pub use synapse::{SynapseConfig, SynapseEngine};
+pub use decay::{DecayConfig, DecayEngine, DecayTickResult, GcResult, TierConfig};
+pub use synapse::{
+    CascadeBounds, CascadeHit, CascadeMode, PropagationStats, Suppression, Suppressor,
+    SynapseConfig, SynapseEngine,
+};
@@ -16,11 +16,25 @@
 //! - Hop 4: 0.0625 < 0.1 → CUTOFF
 //!
 //! Maximum propagation depth: ~3 hops.
+//!
+//! ## Cascades
+//!
+//! Explicit cascades walk the same damping law breadth-first over synergy
+//! bonds, bounded by depth and minimum bond strength. Recall boosts the
+//! cluster, Forget drains it, Lock freezes it and Unlock thaws it.
+//!
+//! ## Repression
+//!
+//! Conscious lineages push down on their antagonists with the same damped
+//! inhibition they would propagate. A lineage is repressed while that
+//! pressure outweighs its own energy.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::arena::{LineageId, PsycheArena};
-use crate::graph::BondGraph;
+use serde::{Deserialize, Serialize};
+
+use crate::arena::{LineageId, PsycheArena, Temperature};
+use crate::graph::{BondGraph, BondId};
 use crate::setun::Trit;
 
 /// Default resistance (50% energy loss per hop)
@@ -50,6 +64,99 @@ impl Default for SynapseConfig {
     }
 }
 
+/// Outcome of a traced propagation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PropagationStats {
+    /// Number of nodes stimulated
+    pub affected: usize,
+    /// Deepest hop reached (0 = signal never left the source)
+    pub depth: usize,
+}
+
+/// What a cascade does to the lineages it reaches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[repr(u8)]
+pub enum CascadeMode {
+    /// Boost related memories by the damped signal
+    Recall = 0,
+    /// Drain related memories by the damped signal
+    Forget = 1,
+    /// Freeze the cluster (Frozen tier, suspended decay)
+    Lock = 2,
+    /// Thaw frozen lineages in the cluster
+    Unlock = 3,
+}
+
+/// Limits of a cascade walk
+#[derive(Debug, Clone, Copy)]
+pub struct CascadeBounds {
+    /// Signal at the source (the energy delta for Recall and Forget)
+    pub strength: f32,
+    /// Maximum hops from the source (capped by `SynapseConfig::max_depth`)
+    pub max_depth: usize,
+    /// Bonds weaker than this are not followed
+    pub min_bond_strength: f32,
+}
+
+impl Default for CascadeBounds {
+    fn default() -> Self {
+        Self {
+            strength: 0.5,
+            max_depth: 3,
+            min_bond_strength: 0.1,
+        }
+    }
+}
+
+/// A lineage changed by a cascade
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CascadeHit {
+    /// Lineage reached
+    pub id: LineageId,
+    /// Hops from the source (0 = the source itself)
+    pub depth: usize,
+    /// Damped signal that arrived
+    pub signal: f32,
+}
+
+/// A conscious antagonist inhibiting a lineage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Suppressor {
+    /// The antagonist
+    pub id: LineageId,
+    /// Antagonistic bond carrying the inhibition
+    pub bond: BondId,
+    /// Bond strength
+    pub strength: f32,
+    /// Decayed energy of the antagonist
+    pub energy: f32,
+}
+
+/// Inhibition weighing on a lineage
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Suppression {
+    /// Summed damped inhibition from conscious antagonists
+    pub pressure: f32,
+    /// Antagonists contributing to the pressure
+    pub suppressors: Vec<Suppressor>,
+}
+
+impl Suppression {
+    /// Whether the pressure outweighs a lineage holding `energy`
+    pub fn represses(&self, energy: f32) -> bool {
+        self.pressure > energy
+    }
+}
+
+/// Traversal state shared across recursive hops
+#[derive(Default)]
+struct Walk {
+    visited: HashSet<LineageId>,
+    max_depth: usize,
+    now: u64,
+}
+
 /// Synapse Engine - Handles signal propagation through bonds
 pub struct SynapseEngine {
     config: SynapseConfig,
@@ -66,9 +173,15 @@ impl SynapseEngine {
         Self { config }
     }
 
+    /// Current configuration
+    pub fn config(&self) -> &SynapseConfig {
+        &self.config
+    }
+
     /// Propagate energy from a source lineage through its bonds
     ///
-    /// Returns the number of nodes affected by propagation.
+    /// Returns the number of nodes affected by propagation. Reads the
+    /// process clock; a `MindFry` passes its own to [`Self::propagate_traced_at`].
     pub fn propagate(
         &self,
         psyche: &mut PsycheArena,
@@ -76,8 +189,187 @@ impl SynapseEngine {
         source: LineageId,
         input_energy: f32,
     ) -> usize {
+        self.propagate_traced(psyche, bonds, source, input_energy)
+            .affected
+    }
+
+    /// Propagate and report both affected node count and depth reached
+    ///
+    /// Reads the process clock, like [`Self::propagate`].
+    pub fn propagate_traced(
+        &self,
+        psyche: &mut PsycheArena,
+        bonds: &BondGraph,
+        source: LineageId,
+        input_energy: f32,
+    ) -> PropagationStats {
+        let now = crate::clock::now_nanos();
+        self.propagate_traced_at(psyche, bonds, source, input_energy, now)
+    }
+
+    /// [`Self::propagate_traced`] as of `now` (nanoseconds since epoch)
+    pub fn propagate_traced_at(
+        &self,
+        psyche: &mut PsycheArena,
+        bonds: &BondGraph,
+        source: LineageId,
+        input_energy: f32,
+        now: u64,
+    ) -> PropagationStats {
+        let mut walk = Walk {
+            now,
+            ..Walk::default()
+        };
+        let affected = self.propagate_recursive(psyche, bonds, source, input_energy, &mut walk, 0);
+        PropagationStats {
+            affected,
+            depth: walk.max_depth,
+        }
+    }
+
+    /// Run a cascade from `source` as of `now` (nanoseconds since epoch)
+    ///
+    /// Walks synergy bonds breadth-first, so each lineage is reached once at
+    /// its shortest hop count. Hardened and Absolute lineages neither absorb
+    /// nor relay, and Recall/Forget do not reach into Frozen lineages.
+    /// Returns the lineages whose state changed, source first.
+    pub fn cascade(
+        &self,
+        psyche: &mut PsycheArena,
+        bonds: &BondGraph,
+        source: LineageId,
+        mode: CascadeMode,
+        bounds: CascadeBounds,
+        now: u64,
+    ) -> Vec<CascadeHit> {
+        let max_depth = bounds.max_depth.min(self.config.max_depth);
+        let mut hits = Vec::new();
         let mut visited = HashSet::new();
-        self.propagate_recursive(psyche, bonds, source, input_energy, &mut visited, 0)
+        let mut queue = VecDeque::from([(source, 0usize, bounds.strength)]);
+        visited.insert(source);
+
+        while let Some((id, depth, signal)) = queue.pop_front() {
+            let Some(lineage) = psyche.get_mut(id) else {
+                continue;
+            };
+            if lineage.protection().exempt_from_cascades() {
+                continue;
+            }
+            let changed = match mode {
+                CascadeMode::Recall | CascadeMode::Forget if lineage.is_frozen() => continue,
+                CascadeMode::Recall => {
+                    lineage.stimulate_at(signal, now);
+                    true
+                }
+                CascadeMode::Forget => {
+                    lineage.stimulate_at(-signal, now);
+                    true
+                }
+                CascadeMode::Lock if !lineage.is_frozen() => {
+                    lineage.set_temperature_at(Temperature::Frozen, now);
+                    true
+                }
+                CascadeMode::Unlock if lineage.is_frozen() => {
+                    lineage.set_temperature_at(Temperature::Warm, now);
+                    true
+                }
+                CascadeMode::Lock | CascadeMode::Unlock => false,
+            };
+            if changed {
+                hits.push(CascadeHit { id, depth, signal });
+            }
+
+            if depth >= max_depth {
+                continue;
+            }
+            for &bond_id in bonds.neighbors(id) {
+                let Some(bond) = bonds.get(bond_id) else {
+                    continue;
+                };
+                if !bond.is_active()
+                    || bond.polarity != Trit::True
+                    || bond.strength < bounds.min_bond_strength
+                {
+                    continue;
+                }
+                let next = signal * bond.strength * (1.0 - self.config.resistance);
+                let target = bond.other(id);
+                if next < self.config.cutoff || !visited.insert(target) {
+                    continue;
+                }
+                queue.push_back((target, depth + 1, next));
+            }
+        }
+
+        hits
+    }
+
+    /// Inhibition on `target` from conscious antagonists as of `now`
+    ///
+    /// Each active antagonistic bond to a conscious lineage contributes
+    /// `energy × strength × (1 - resistance)`, the damped inhibition of one hop.
+    pub fn suppression(
+        &self,
+        psyche: &PsycheArena,
+        bonds: &BondGraph,
+        target: LineageId,
+        now: u64,
+    ) -> Suppression {
+        let mut suppression = Suppression::default();
+        for &bond_id in bonds.neighbors(target) {
+            let Some(bond) = bonds.get(bond_id) else {
+                continue;
+            };
+            if !bond.is_active() || bond.polarity != Trit::False {
+                continue;
+            }
+            let id = bond.other(target);
+            let Some(antagonist) = psyche.get(id) else {
+                continue;
+            };
+            let energy = antagonist.current_energy_at(now);
+            if energy < antagonist.threshold {
+                continue;
+            }
+            suppression.pressure += energy * bond.strength * (1.0 - self.config.resistance);
+            suppression.suppressors.push(Suppressor {
+                id,
+                bond: bond_id,
+                strength: bond.strength,
+                energy,
+            });
+        }
+        suppression
+    }
+
+    /// Inhibition pressure on every lineage as of `now`, in one pass over the bonds
+    ///
+    /// Sums the same contributions as [`Self::suppression`]; lineages under
+    /// no pressure are absent. Meant for reads that weigh many lineages.
+    pub fn pressures(
+        &self,
+        psyche: &PsycheArena,
+        bonds: &BondGraph,
+        now: u64,
+    ) -> HashMap<LineageId, f32> {
+        let mut pressures = HashMap::new();
+        for (_, bond) in bonds.iter() {
+            if bond.polarity != Trit::False {
+                continue;
+            }
+            for (from, to) in [(bond.source, bond.target), (bond.target, bond.source)] {
+                let Some(antagonist) = psyche.get(from) else {
+                    continue;
+                };
+                let energy = antagonist.current_energy_at(now);
+                if energy < antagonist.threshold {
+                    continue;
+                }
+                *pressures.entry(to).or_insert(0.0) +=
+                    energy * bond.strength * (1.0 - self.config.resistance);
+            }
+        }
+        pressures
     }
 
     fn propagate_recursive(
@@ -86,7 +378,7 @@ impl SynapseEngine {
         bonds: &BondGraph,
         source: LineageId,
         input_energy: f32,
-        visited: &mut HashSet<LineageId>,
+        walk: &mut Walk,
         depth: usize,
     ) -> usize {
         // Cutoff: Signal too weak
@@ -100,10 +392,10 @@ impl SynapseEngine {
         }
 
         // Loop protection: Already processed
-        if visited.contains(&source) {
+        if walk.visited.contains(&source) {
             return 0;
         }
-        visited.insert(source);
+        walk.visited.insert(source);
 
         let mut affected = 0;
 
@@ -135,18 +427,17 @@ impl SynapseEngine {
                 // Apply to target
                 let target = bond.other(source);
                 if let Some(lineage) = psyche.get_mut(target) {
-                    lineage.stimulate(decayed);
+                    // Hardened and Absolute lineages neither absorb nor relay
+                    if lineage.protection().exempt_from_cascades() {
+                        continue;
+                    }
+                    lineage.stimulate_at(decayed, walk.now);
                     affected += 1;
+                    walk.max_depth = walk.max_depth.max(depth + 1);
 
                     // Recursive propagation
-                    affected += self.propagate_recursive(
-                        psyche,
-                        bonds,
-                        target,
-                        decayed,
-                        visited,
-                        depth + 1,
-                    );
+                    affected +=
+                        self.propagate_recursive(psyche, bonds, target, decayed, walk, depth + 1);
                 }
             }
         }
@@ -188,6 +479,19 @@ mod tests {
         (psyche, bonds)
     }
 
+    #[test]
+    fn test_propagate_traced_depth() {
+        let (mut psyche, bonds) = setup_chain();
+        let engine = SynapseEngine::new();
+
+        let stats = engine.propagate_traced(&mut psyche, &bonds, LineageId(0), 1.0);
+        assert!(stats.affected >= 2);
+        assert!(stats.depth >= 2); // A → B → C
+
+        let weak = engine.propagate_traced(&mut psyche, &bonds, LineageId(0), 0.05);
+        assert_eq!(weak, PropagationStats::default());
+    }
+
     #[test]
     fn test_synergy_propagation() {
         let (mut psyche, bonds) = setup_chain();
@@ -208,6 +512,71 @@ mod tests {
         assert!(c.energy > 0.1);
     }
 
+    #[test]
+    fn test_cascade_modes_and_bounds() {
+        let (mut psyche, bonds) = setup_chain();
+        let engine = SynapseEngine::new();
+        let (a, b, c) = (LineageId(0), LineageId(1), LineageId(2));
+        let bounds = CascadeBounds {
+            strength: 0.4,
+            max_depth: 1,
+            min_bond_strength: 0.5,
+        };
+
+        // Depth bound: A and B only, B gets the damped signal
+        let hits = engine.cascade(&mut psyche, &bonds, a, CascadeMode::Recall, bounds, 0);
+        assert_eq!(hits.len(), 2);
+        assert_eq!((hits[1].id, hits[1].depth), (b, 1));
+        assert!((hits[1].signal - 0.2).abs() < 1e-6);
+
+        // Forget drains the same cluster
+        let hits = engine.cascade(&mut psyche, &bonds, a, CascadeMode::Forget, bounds, 0);
+        assert_eq!(hits.len(), 2);
+        assert!((psyche.get(a).unwrap().energy - 0.5).abs() < 1e-6);
+
+        // Lock freezes the whole reachable cluster, Unlock thaws it
+        let deep = CascadeBounds {
+            strength: 1.0,
+            max_depth: 5,
+            ..bounds
+        };
+        let locked = engine.cascade(&mut psyche, &bonds, a, CascadeMode::Lock, deep, 0);
+        assert_eq!(locked.len(), 3);
+        assert!(psyche.get(c).unwrap().is_frozen());
+        assert!(engine
+            .cascade(&mut psyche, &bonds, a, CascadeMode::Recall, deep, 0)
+            .is_empty());
+        let thawed = engine.cascade(&mut psyche, &bonds, a, CascadeMode::Unlock, deep, 0);
+        assert_eq!(thawed.len(), 3);
+
+        // Strength bound: bonds weaker than the minimum are not followed
+        let strict = CascadeBounds {
+            min_bond_strength: 1.5,
+            ..deep
+        };
+        let hits = engine.cascade(&mut psyche, &bonds, a, CascadeMode::Lock, strict, 0);
+        assert_eq!(hits.len(), 1);
+        assert!(!psyche.get(b).unwrap().is_frozen());
+    }
+
+    #[test]
+    fn test_hardened_lineage_blocks_cascade() {
+        use crate::arena::Protection;
+
+        let (mut psyche, bonds) = setup_chain();
+        let engine = SynapseEngine::new();
+        let now = crate::clock::now_nanos();
+        psyche
+            .get_mut(LineageId(1))
+            .unwrap()
+            .set_protection_at(Protection::Hardened, now);
+
+        // B neither absorbs nor relays, so C is cut off as well
+        assert_eq!(engine.propagate(&mut psyche, &bonds, LineageId(0), 1.0), 0);
+        assert!(psyche.get(LineageId(1)).unwrap().energy <= 0.1);
+        assert!(psyche.get(LineageId(2)).unwrap().energy <= 0.1);
+    }
+
     #[test]
     fn test_neutral_insulation() {
         let mut psyche = PsycheArena::with_capacity(10);
@@ -263,4 +632,39 @@ mod tests {
         // Should be cut off immediately
         assert_eq!(affected, 0);
     }
+
+    #[test]
+    fn test_suppression_from_conscious_antagonists() {
+        let mut psyche = PsycheArena::with_capacity(10);
+        let mut bonds = BondGraph::with_capacity(10, 100);
+        let victim = psyche.alloc(Lineage::with_config(0.3, 0.5, 0.0));
+        let rival = psyche.alloc(Lineage::with_config(0.9, 0.5, 0.0));
+        let sleeper = psyche.alloc(Lineage::with_config(0.2, 0.5, 0.0));
+        for (other, strength) in [(rival, 0.8), (sleeper, 1.0)] {
+            let mut bond = Bond::new(other, victim, strength);
+            bond.polarity = Trit::False;
+            bonds.connect(bond);
+        }
+        let engine = SynapseEngine::new();
+        let now = crate::clock::now_nanos();
+
+        // Only the conscious rival pushes down: 0.9 × 0.8 × 0.5
+        let suppression = engine.suppression(&psyche, &bonds, victim, now);
+        assert_eq!(suppression.suppressors.len(), 1);
+        assert_eq!(suppression.suppressors[0].id, rival);
+        assert!((suppression.pressure - 0.36).abs() < 1e-6);
+        assert!(suppression.represses(0.3));
+        assert!(!suppression.represses(0.5));
+
+        // The rival itself is not inhibited by a dormant victim
+        assert!(engine
+            .suppression(&psyche, &bonds, rival, now)
+            .suppressors
+            .is_empty());
+
+        // One pass over the bonds agrees with the per-lineage view
+        let pressures = engine.pressures(&psyche, &bonds, now);
+        assert_eq!(pressures.len(), 1);
+        assert_eq!(pressures[&victim], suppression.pressure);
+    }
 }
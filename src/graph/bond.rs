@@ -6,9 +6,9 @@
 
 use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
-use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::arena::LineageId;
+use crate::clock::{self, elapsed_seconds};
 use crate::setun::Trit;
 
 /// Default polarity for backward compatibility (excitatory)
@@ -114,7 +114,7 @@ impl Default for Bond {
             strength: 1.0,
             cost: 0.1,
             decay_rate: 0.0005,
-            last_access: now_nanos(),
+            last_access: clock::now_nanos(),
             flags: BondFlags::ACTIVE,
             polarity: Trit::True,
         }
@@ -124,11 +124,16 @@ impl Default for Bond {
 impl Bond {
     /// Create a new bond between two lineages
     pub fn new(source: LineageId, target: LineageId, strength: f32) -> Self {
+        Self::new_at(source, target, strength, clock::now_nanos())
+    }
+
+    /// Create a new bond last accessed at `now` (nanoseconds since epoch)
+    pub fn new_at(source: LineageId, target: LineageId, strength: f32, now: u64) -> Self {
         Self {
             source,
             target,
             strength,
-            last_access: now_nanos(),
+            last_access: now,
             ..Default::default()
         }
     }
@@ -154,18 +159,28 @@ impl Bond {
 
     /// Compute current strength with decay applied
     pub fn current_strength(&self) -> f32 {
+        self.current_strength_at(clock::now_nanos())
+    }
+
+    /// Compute strength with decay applied up to `now` (nanoseconds since epoch)
+    pub fn current_strength_at(&self, now: u64) -> f32 {
         if self.flags.contains(BondFlags::PROTECTED) {
             return self.strength;
         }
 
-        let elapsed_secs = elapsed_seconds(self.last_access);
+        let elapsed_secs = elapsed_seconds(self.last_access, now);
         self.strength * (-self.decay_rate * elapsed_secs).exp()
     }
 
     /// Reinforce the bond (strengthen connection)
     pub fn reinforce(&mut self, delta: f32) {
-        self.strength = (self.current_strength() + delta).clamp(0.0, 1.0);
-        self.last_access = now_nanos();
+        self.reinforce_at(delta, clock::now_nanos());
+    }
+
+    /// Reinforce the bond as of `now` (nanoseconds since epoch)
+    pub fn reinforce_at(&mut self, delta: f32, now: u64) {
+        self.strength = (self.current_strength_at(now) + delta).clamp(0.0, 1.0);
+        self.last_access = now;
     }
 
     /// Get the other end of the bond given one endpoint
@@ -331,13 +346,23 @@ impl BondGraph {
 
     /// Prune weak bonds below threshold
     pub fn prune(&mut self, threshold: f32) -> usize {
-        let mut pruned = 0;
+        self.prune_at(threshold, clock::now_nanos())
+    }
+
+    /// Prune bonds whose strength as of `now` is below threshold
+    pub fn prune_at(&mut self, threshold: f32, now: u64) -> usize {
+        self.prune_endpoints_at(threshold, now).len()
+    }
+
+    /// Prune like [`Self::prune_at`], returning the endpoints of each pruned bond
+    pub fn prune_endpoints_at(&mut self, threshold: f32, now: u64) -> Vec<(LineageId, LineageId)> {
+        let mut pruned = Vec::new();
         let to_prune: Vec<_> = self
             .bonds
             .iter()
             .enumerate()
             .filter_map(|(i, bond)| {
-                if bond.is_active() && bond.current_strength() < threshold {
+                if bond.is_active() && bond.current_strength_at(now) < threshold {
                     Some(BondId(i as u32))
                 } else {
                     None
@@ -346,8 +371,9 @@ impl BondGraph {
             .collect();
 
         for id in to_prune {
+            let endpoints = self.get(id).map(|b| (b.source, b.target));
             if self.disconnect(id) {
-                pruned += 1;
+                pruned.extend(endpoints);
             }
         }
         pruned
@@ -363,28 +389,6 @@ impl BondGraph {
     }
 }
 
-// ═══════════════════════════════════════════════════════════════
-// TIME UTILITIES
-// ═══════════════════════════════════════════════════════════════
-
-#[inline]
-fn now_nanos() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_nanos() as u64)
-        .unwrap_or(0)
-}
-
-#[inline]
-fn elapsed_seconds(timestamp: u64) -> f32 {
-    let now = now_nanos();
-    if now > timestamp {
-        ((now - timestamp) as f64 / 1_000_000_000.0) as f32
-    } else {
-        0.0
-    }
-}
-
 // ═══════════════════════════════════════════════════════════════
 // TESTS
 // ═══════════════════════════════════════════════════════════════
@@ -414,6 +418,24 @@ mod tests {
         assert!(b.strength > 0.5);
     }
 
+    #[test]
+    fn test_bond_decay_is_deterministic() {
+        use crate::clock::NANOS_PER_SEC;
+
+        let mut b = Bond::new(LineageId(0), LineageId(1), 0.5);
+        b.decay_rate = 0.5;
+        b.reinforce_at(0.0, 0);
+
+        assert_eq!(b.current_strength_at(0), 0.5);
+        let later = b.current_strength_at(2 * NANOS_PER_SEC);
+        assert!((later - 0.5 * (-1.0f32).exp()).abs() < 1e-6);
+
+        let mut graph = BondGraph::with_capacity(2, 4);
+        graph.connect(b);
+        assert_eq!(graph.prune_at(0.1, NANOS_PER_SEC), 0);
+        assert_eq!(graph.prune_at(0.1, 10 * NANOS_PER_SEC), 1);
+    }
+
     #[test]
     fn test_bond_graph_connect() {
         let mut graph = BondGraph::with_capacity(100, 1000);
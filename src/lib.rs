@@ -33,12 +33,15 @@
 #![allow(clippy::module_inception)]
 
 pub mod arena;
+pub mod clock;
 pub mod dynamics;
 pub mod graph;
 pub mod setun;
 
 // Feature-gated modules
 #[cfg(feature = "server")]
+pub mod metrics;
+#[cfg(feature = "server")]
 pub mod persistence;
 #[cfg(feature = "server")]
 pub mod protocol;
@@ -62,6 +65,9 @@ pub const DEFAULT_MAX_BONDS: usize = 1 << 22; // 4M bonds
 /// Default maximum engrams per lineage (history depth)
 pub const DEFAULT_STRATA_DEPTH: usize = 64;
 
+/// Default observer effect (energy a read adds to the lineage it returns)
+pub const DEFAULT_OBSERVER_DELTA: f32 = 0.01;
+
 /// MindFry database instance
 pub struct MindFry {
     /// Active memory storage
@@ -76,9 +82,37 @@ pub struct MindFry {
     pub cortex: Cortex,
     /// Signal propagation engine
     pub synapse: dynamics::SynapseEngine,
+    /// Writes refused by protection levels
+    protection_violations: u64,
+    /// Energy a reinforcing read adds (observer effect)
+    observer_delta: f32,
     /// Persistent storage (optional)
     #[cfg(feature = "server")]
     pub store: Option<std::sync::Arc<persistence::AkashicStore>>,
+    /// Adaptive exhaustion thresholds learned from `_system.state`
+    #[cfg(feature = "server")]
+    pub exhaustion_tuner: stability::ExhaustionTuner,
+    /// Post-crash resistance (0.0 - 1.0), decays every maintenance tick
+    #[cfg(feature = "server")]
+    resistance: f32,
+    /// Request costs not yet drained from `_system.state` (f32 bits)
+    #[cfg(feature = "server")]
+    pending_cost: std::sync::atomic::AtomicU32,
+    /// Snapshot the next delta checkpoint builds on
+    #[cfg(feature = "server")]
+    last_checkpoint: Option<u64>,
+    /// Cold lineages of an unfinished progressive resurrection
+    #[cfg(feature = "server")]
+    resurrection: Option<persistence::Resurrection>,
+    /// Which lineages maintenance moves to the cold tier (None = never)
+    #[cfg(feature = "server")]
+    eviction: Option<persistence::EvictionPolicy>,
+    /// Lineages currently held only by the cold tier
+    #[cfg(feature = "server")]
+    evicted: usize,
+    /// Faulted-in keys whose cold copy goes once a checkpoint holds them
+    #[cfg(feature = "server")]
+    faulted: Vec<String>,
 }
 
 impl MindFry {
@@ -94,7 +128,7 @@ impl MindFry {
         let psyche = PsycheArena::with_capacity(config.max_lineages);
         let strata = StrataArena::with_capacity(config.max_lineages, config.strata_depth);
         let bonds = BondGraph::with_capacity(config.max_lineages, config.max_bonds);
-        let decay = DecayEngine::new(config.decay);
+        let decay = DecayEngine::with_clock(config.decay, config.clock);
 
         // Default personality: Curious and Preserving
         let mut personality = Octet::neutral();
@@ -109,87 +143,144 @@ impl MindFry {
             decay,
             cortex,
             synapse: dynamics::SynapseEngine::new(),
+            protection_violations: 0,
+            observer_delta: config.observer_delta,
             #[cfg(feature = "server")]
             store: None,
+            #[cfg(feature = "server")]
+            exhaustion_tuner: stability::ExhaustionTuner::new(config.tuner, 0.5),
+            #[cfg(feature = "server")]
+            resistance: 0.0,
+            #[cfg(feature = "server")]
+            pending_cost: std::sync::atomic::AtomicU32::new(0),
+            #[cfg(feature = "server")]
+            last_checkpoint: None,
+            #[cfg(feature = "server")]
+            resurrection: None,
+            #[cfg(feature = "server")]
+            eviction: config.eviction,
+            #[cfg(feature = "server")]
+            evicted: 0,
+            #[cfg(feature = "server")]
+            faulted: Vec::new(),
         }
     }
 
     /// Attach persistent storage to this MindFry instance
     #[cfg(feature = "server")]
     pub fn with_store(mut self, store: std::sync::Arc<persistence::AkashicStore>) -> Self {
+        self.evicted = store.cold_tier().len();
         self.store = Some(store);
         self
     }
 
-    /// Attempt to resurrect from the latest snapshot
+    /// Attempt to resurrect from the newest restorable snapshot
+    ///
+    /// Snapshots that fail their checksums, cannot be decoded or sit on a
+    /// broken delta chain are skipped with a warning, walking back until
+    /// one restores. Returns Ok(true) if resurrection succeeded, Ok(false)
+    /// if no snapshot could be restored.
     ///
-    /// Returns Ok(true) if resurrection succeeded, Ok(false) if no snapshot found.
-    /// On corruption, logs error and returns Ok(false) for graceful degradation.
+    /// Restores everything at once; see [`Self::begin_resurrection`] for
+    /// the progressive path.
     #[cfg(feature = "server")]
     pub fn resurrect(&mut self) -> Result<bool, persistence::AkashicError> {
-        use std::time::Instant;
-
-        let store = match &self.store {
-            Some(s) => s,
-            None => return Ok(false),
+        let Some(ref store) = self.store else {
+            return Ok(false);
         };
+        let now = self.clock().now_nanos();
+        match persistence::Resurrection::stage(store, self.strata.depth(), now)? {
+            Some(staged) => {
+                self.begin_resurrection(staged, usize::MAX);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
 
-        // Check for latest snapshot
-        let t0 = Instant::now();
-        let snapshot = match store.latest_snapshot()? {
-            Some(s) => s,
-            None => return Ok(false),
-        };
-        tracing::debug!("Snapshot loaded in {:?}", t0.elapsed());
+    /// Start a progressive resurrection from a staged snapshot
+    ///
+    /// Replaces the arenas and installs the `hot_set` most energetic
+    /// lineages with the bonds between them. The remainder is installed by
+    /// [`Self::resume_resurrection`] or on demand by [`Self::fault_in`].
+    #[cfg(feature = "server")]
+    pub fn begin_resurrection(&mut self, mut staged: persistence::Resurrection, hot_set: usize) {
+        let depth = self.strata.depth();
+        self.psyche = PsycheArena::with_capacity(self.psyche.capacity());
+        self.psyche.reserve_slots(staged.span());
+        self.strata = StrataArena::with_capacity(self.psyche.capacity(), depth);
+        self.bonds = BondGraph::with_capacity(self.psyche.capacity(), self.bonds.capacity());
+        self.last_checkpoint = Some(staged.meta().id);
 
+        if let Some(cortex) = staged.take_cortex() {
+            tracing::info!("🧠 Cortex restored (mood: {:.2})", cortex.mood());
+            self.cortex = cortex;
+        }
+
+        staged.restore_next(hot_set, &mut self.psyche, &mut self.strata, &mut self.bonds);
+        if staged.is_complete() {
+            self.finish_resurrection();
+            return;
+        }
         tracing::info!(
-            "Restoring '{}' ({} lineages, {} KB)",
-            snapshot.meta.name.as_deref().unwrap_or("unnamed"),
-            snapshot.meta.lineage_count,
-            snapshot.meta.size_bytes / 1024
+            "🔥 Hot set restored: {} lineages ({} still cold)",
+            self.psyche.len(),
+            staged.remaining()
         );
+        self.resurrection = Some(staged);
+    }
 
-        // Restore arenas
-        let t1 = Instant::now();
-        let (psyche, strata, bonds, _physics) = store.restore_snapshot(
-            &snapshot,
-            self.psyche.capacity(),
-            self.bonds.capacity(),
-            64, // strata depth
-        )?;
-        tracing::debug!("Arenas restored in {:?}", t1.elapsed());
-
-        self.psyche = psyche;
-        self.strata = strata;
-        self.bonds = bonds;
-
-        // Restore Cortex if available
-        if let Some(ref cortex_data) = snapshot.cortex_data {
-            match bincode::deserialize::<Cortex>(cortex_data) {
-                Ok(restored_cortex) => {
-                    tracing::info!("🧠 Cortex restored (mood: {:.2})", restored_cortex.mood());
-                    self.cortex = restored_cortex;
-                }
-                Err(e) => {
-                    tracing::warn!("⚠️ Failed to restore Cortex: {}, using default", e);
-                }
+    /// Install the next `batch` cold lineages of a progressive resurrection
+    ///
+    /// Returns true once nothing is left to restore.
+    #[cfg(feature = "server")]
+    pub fn resume_resurrection(&mut self, batch: usize) -> bool {
+        let Some(ref mut staged) = self.resurrection else {
+            return true;
+        };
+        staged.restore_next(batch, &mut self.psyche, &mut self.strata, &mut self.bonds);
+        if !staged.is_complete() {
+            return false;
+        }
+        self.resurrection = None;
+        self.finish_resurrection();
+        true
+    }
+
+    /// Bring a lineage that is not in the arena back in
+    ///
+    /// Installs it ahead of its turn (with the lineages it bonds to) while a
+    /// resurrection is streaming, or loads it from the cold tier. Returns
+    /// true if the key was found in either.
+    #[cfg(feature = "server")]
+    pub fn fault_in(&mut self, key: &str) -> bool {
+        if let Some(ref mut staged) = self.resurrection {
+            if staged.fault_in(key, &mut self.psyche, &mut self.strata, &mut self.bonds) {
+                return true;
             }
         }
+        self.evicted > 0 && self.fault_in_cold(key)
+    }
 
-        // Note: Index rebuild deferred - lineage keys stored in protocol layer
-        // For now, index will be rebuilt on-demand through handler interactions
-        tracing::info!(
-            "📇 Index rebuild deferred (lineages: {})",
-            self.psyche.len()
-        );
+    /// True while cold lineages are still being restored
+    #[cfg(feature = "server")]
+    pub fn is_resurrecting(&self) -> bool {
+        self.resurrection.is_some()
+    }
+
+    #[cfg(feature = "server")]
+    fn finish_resurrection(&mut self) {
+        self.psyche.rebuild_free_list();
+
+        // The index may hold keys written after this snapshot
+        self.rebuild_index();
+        self.reconcile_cold_tier();
 
         tracing::info!(
             "✅ Resurrection complete: {} lineages, {} bonds",
             self.psyche.len(),
             self.bonds.len()
         );
-
-        Ok(true)
     }
 
     /// Sync a newly created lineage to the index
@@ -202,13 +293,469 @@ impl MindFry {
         }
     }
 
-    /// Remove a lineage from the index
+    /// Replace the index with the arena's key table
+    ///
+    /// Returns the number of indexed keys (0 without a store or on failure).
     #[cfg(feature = "server")]
-    pub fn sync_index_remove(&self, key: &str) {
+    pub fn rebuild_index(&self) -> usize {
+        let Some(ref store) = self.store else {
+            return 0;
+        };
+        let entries = self.psyche.keys().map(|(id, key)| (key.to_string(), id));
+        match store.indexer().rebuild(entries) {
+            Ok(count) => {
+                tracing::info!("📇 Index rebuilt ({} keys)", count);
+                count
+            }
+            Err(e) => {
+                tracing::warn!("⚠️ Index rebuild failed: {}", e);
+                0
+            }
+        }
+    }
+
+    /// Remove a forgotten lineage from the index and the cold tier
+    #[cfg(feature = "server")]
+    pub fn sync_index_remove(&mut self, key: &str) {
         if let Some(ref store) = self.store {
             if let Err(e) = store.indexer().remove(key) {
                 tracing::warn!("Failed to remove lineage '{}' from index: {}", key, e);
             }
+            // A faulted-in lineage still has its cold copy
+            if let Err(e) = store.cold_tier().remove(key) {
+                tracing::warn!("Failed to remove lineage '{}' from cold tier: {}", key, e);
+            }
+        }
+        self.faulted.retain(|k| k != key);
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // COLD TIER
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Lineages held only by the cold tier
+    #[cfg(feature = "server")]
+    pub fn evicted_count(&self) -> usize {
+        self.evicted
+    }
+
+    /// Move a keyed lineage, its engrams and its bonds to the cold tier
+    ///
+    /// Frees the slot. Bonds to unkeyed lineages are dropped, since nothing
+    /// could find them again. Returns Ok(false) without a store or for an
+    /// unkeyed or inactive lineage.
+    #[cfg(feature = "server")]
+    pub fn evict(&mut self, id: LineageId) -> Result<bool, persistence::AkashicError> {
+        use persistence::{EvictedBond, EvictedLineage};
+
+        let Some(store) = self.store.clone() else {
+            return Ok(false);
+        };
+        let (Some(lineage), Some(key)) = (
+            self.psyche.get(id).copied(),
+            self.psyche.key_of(id).map(str::to_string),
+        ) else {
+            return Ok(false);
+        };
+
+        let bond_ids = self.bonds.neighbors(id).to_vec();
+        let mut bonds = Vec::with_capacity(bond_ids.len());
+        for &bond_id in &bond_ids {
+            let Some(bond) = self.bonds.get(bond_id) else {
+                continue;
+            };
+            if let Some(partner) = self.psyche.key_of(bond.other(id)) {
+                bonds.push(EvictedBond {
+                    partner: partner.to_string(),
+                    outgoing: bond.source == id,
+                    bond: *bond,
+                });
+            }
+        }
+        let base = (id.index() * self.strata.depth()) as u32;
+        let record = EvictedLineage::new(lineage, self.strata.window_sparse(id), base, bonds);
+        store.cold_tier().put(&key, &record)?;
+
+        // Durable in the cold tier; now vacate the slot
+        for bond_id in bond_ids {
+            if let Some(partner) = self.bonds.get(bond_id).map(|b| b.other(id)) {
+                self.bonds.disconnect(bond_id);
+                self.psyche.mark_dirty(partner);
+            }
+        }
+        self.strata.clear_window(id);
+        self.cortex.retention_mut().restore(id.index());
+        self.psyche.free(id);
+        if let Err(e) = store.indexer().remove(&key) {
+            tracing::warn!("Failed to remove lineage '{}' from index: {}", key, e);
+        }
+        self.faulted.retain(|k| *k != key);
+        self.evicted += 1;
+        Ok(true)
+    }
+
+    /// Evict idle, low-energy lineages according to the eviction policy
+    #[cfg(feature = "server")]
+    fn evict_cold(&mut self) -> usize {
+        let Some(policy) = self.eviction else {
+            return 0;
+        };
+        if self.store.is_none() || self.is_resurrecting() {
+            return 0;
+        }
+
+        let now = self.clock().now_nanos();
+        let min_idle = policy.min_idle_secs.saturating_mul(clock::NANOS_PER_SEC);
+        let candidates: Vec<LineageId> = self
+            .psyche
+            .iter()
+            .filter(|(id, lineage)| {
+                now.saturating_sub(lineage.last_access) >= min_idle
+                    && lineage.current_energy_at(now) <= policy.max_energy
+                    && lineage.protection() == arena::Protection::Normal
+                    && self
+                        .psyche
+                        .key_of(*id)
+                        .is_some_and(|key| !key.starts_with(stability::lineages::PREFIX))
+            })
+            .map(|(id, _)| id)
+            .take(policy.max_per_tick)
+            .collect();
+
+        let mut evicted = 0;
+        for id in candidates {
+            match self.evict(id) {
+                Ok(true) => evicted += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    tracing::warn!("⚠️ Eviction failed: {}", e);
+                    break;
+                }
+            }
+        }
+        if evicted > 0 {
+            tracing::debug!("🧊 Evicted {} cold lineage(s)", evicted);
+        }
+        evicted
+    }
+
+    /// Load an evicted lineage into a free slot and reconnect its bonds
+    ///
+    /// Bonds to lineages that are themselves evicted move to the partner's
+    /// record, so they reconnect when it returns.
+    #[cfg(feature = "server")]
+    fn fault_in_cold(&mut self, key: &str) -> bool {
+        let Some(store) = self.store.clone() else {
+            return false;
+        };
+        if self.psyche.lookup(arena::key_hash(key)).is_some() {
+            return false;
+        }
+        let record = match store.cold_tier().get(key) {
+            Ok(Some(record)) => record,
+            Ok(None) => return false,
+            Err(e) => {
+                tracing::warn!("⚠️ Cold tier read for '{}' failed: {}", key, e);
+                return false;
+            }
+        };
+
+        let id = self.psyche.alloc_named(key, record.lineage);
+        let (lineage, engrams) = record.rebase((id.index() * self.strata.depth()) as u32);
+        if let Some(slot) = self.psyche.get_mut(id) {
+            slot.head_index = lineage.head_index;
+        }
+        self.strata.clear_window(id);
+        self.strata.restore_from_sparse(engrams);
+
+        for evicted in record.bonds {
+            match self.psyche.lookup(arena::key_hash(&evicted.partner)) {
+                Some(partner) => {
+                    let mut bond = evicted.bond;
+                    (bond.source, bond.target) = if evicted.outgoing {
+                        (id, partner)
+                    } else {
+                        (partner, id)
+                    };
+                    self.bonds.connect(bond);
+                    self.psyche.mark_dirty(partner);
+                }
+                None => {
+                    let handed = persistence::EvictedBond {
+                        partner: key.to_string(),
+                        outgoing: !evicted.outgoing,
+                        bond: evicted.bond,
+                    };
+                    if let Err(e) = store.cold_tier().attach_bond(&evicted.partner, handed) {
+                        tracing::warn!("Failed to park bond to '{}': {}", evicted.partner, e);
+                    }
+                }
+            }
+        }
+
+        self.sync_index_insert(key, id);
+        self.faulted.push(key.to_string());
+        self.evicted = self.evicted.saturating_sub(1);
+        true
+    }
+
+    /// Drop cold copies of lineages the arena holds after a state swap
+    ///
+    /// A restored snapshot wins over a cold copy of the same key.
+    #[cfg(feature = "server")]
+    pub fn reconcile_cold_tier(&mut self) {
+        self.faulted.clear();
+        let Some(store) = self.store.clone() else {
+            return;
+        };
+        let tier = store.cold_tier();
+        let keys: Vec<String> = tier.keys().filter_map(|key| key.ok()).collect();
+        for key in &keys {
+            if self.psyche.lookup(arena::key_hash(key)).is_some() {
+                if let Err(e) = tier.remove(key) {
+                    tracing::warn!("Failed to drop cold copy of '{}': {}", key, e);
+                }
+            }
+        }
+        self.evicted = tier.len();
+    }
+
+    /// Remove the cold copies of faulted-in lineages (a checkpoint holds them now)
+    #[cfg(feature = "server")]
+    fn settle_faulted(&mut self) {
+        let Some(ref store) = self.store else {
+            return;
+        };
+        for key in self.faulted.drain(..) {
+            if let Err(e) = store.cold_tier().remove(&key) {
+                tracing::warn!("Failed to drop cold copy of '{}': {}", key, e);
+            }
+        }
+    }
+
+    /// Lineages modified since the last checkpoint
+    pub fn dirty_count(&self) -> usize {
+        self.psyche.dirty_count()
+    }
+
+    /// Dirty lineages outside the reserved `_system.*` namespace
+    ///
+    /// The maintenance pulse touches system lineages every tick, so only
+    /// client changes should make an automatic snapshot due.
+    #[cfg(feature = "server")]
+    pub fn client_dirty_count(&self) -> usize {
+        use stability::lineages::PREFIX;
+
+        self.psyche
+            .dirty_ids()
+            .filter(|&id| {
+                !self
+                    .psyche
+                    .key_of(id)
+                    .is_some_and(|k| k.starts_with(PREFIX))
+            })
+            .count()
+    }
+
+    /// Copy the persisted state and mark it clean
+    ///
+    /// Hold the write lock only for this call; write the checkpoint with
+    /// `AkashicStore::write_checkpoint` after releasing it.
+    #[cfg(feature = "server")]
+    pub fn checkpoint(&mut self) -> persistence::Checkpoint {
+        let checkpoint = persistence::Checkpoint::capture(
+            &self.psyche,
+            &self.strata,
+            &self.bonds,
+            Some(&self.cortex),
+        );
+        self.psyche.clear_dirty();
+        self.settle_faulted();
+        self.last_checkpoint = Some(checkpoint.id());
+        checkpoint
+    }
+
+    /// Copy only what changed since the last checkpoint and mark it clean
+    ///
+    /// Falls back to a full checkpoint when there is nothing to build on.
+    #[cfg(feature = "server")]
+    pub fn checkpoint_delta(&mut self) -> persistence::Checkpoint {
+        let parent = match self.last_checkpoint {
+            Some(parent) => parent,
+            None => return self.checkpoint(),
+        };
+        let checkpoint = persistence::Checkpoint::capture_delta(
+            parent,
+            &self.psyche,
+            &self.strata,
+            &self.bonds,
+            Some(&self.cortex),
+        );
+        self.psyche.clear_dirty();
+        self.settle_faulted();
+        self.last_checkpoint = Some(checkpoint.id());
+        checkpoint
+    }
+
+    /// Forget the delta base, e.g. after a checkpoint failed to persist
+    ///
+    /// The next `checkpoint_delta` becomes a full checkpoint.
+    #[cfg(feature = "server")]
+    pub fn invalidate_checkpoint_chain(&mut self) {
+        self.last_checkpoint = None;
+    }
+
+    /// Snapshot the next delta checkpoint builds on
+    #[cfg(feature = "server")]
+    pub fn last_checkpoint(&self) -> Option<u64> {
+        self.last_checkpoint
+    }
+
+    /// Write the whole state as versioned NDJSON
+    #[cfg(feature = "server")]
+    pub fn export_ndjson<W: std::io::Write>(
+        &self,
+        out: W,
+    ) -> std::io::Result<persistence::ExportSummary> {
+        persistence::export_ndjson(
+            out,
+            &self.psyche,
+            &self.strata,
+            &self.bonds,
+            Some(&self.cortex),
+        )
+    }
+
+    /// Replace the whole state with a validated NDJSON export
+    ///
+    /// On error nothing changes. Imported lineages are marked dirty, system
+    /// lineages missing from the export are recreated, and the next
+    /// checkpoint is a full one.
+    #[cfg(feature = "server")]
+    pub fn import_ndjson<R: std::io::BufRead>(
+        &mut self,
+        input: R,
+    ) -> Result<persistence::ExportSummary, persistence::ImportError> {
+        let imported = persistence::import_ndjson(
+            input,
+            self.psyche.capacity(),
+            self.bonds.capacity(),
+            self.strata.depth(),
+        )?;
+
+        self.psyche = imported.psyche;
+        self.strata = imported.strata;
+        self.bonds = imported.bonds;
+        if let Some(cortex) = imported.cortex {
+            self.cortex = cortex;
+        }
+        let ids: Vec<LineageId> = self.psyche.iter().map(|(id, _)| id).collect();
+        for id in ids {
+            self.psyche.mark_dirty(id);
+        }
+        self.bootstrap_system_lineages();
+        self.invalidate_checkpoint_chain();
+        self.rebuild_index();
+        // The import replaces everything, evicted lineages included
+        if let Some(ref store) = self.store {
+            if let Err(e) = store.cold_tier().clear() {
+                tracing::warn!("⚠️ Failed to clear the cold tier: {}", e);
+            }
+        }
+        self.faulted.clear();
+        self.evicted = 0;
+
+        Ok(imported.summary)
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // PROTECTION
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Writes refused by protection levels since startup
+    ///
+    /// Each refusal is an attempted violation that enforcement stopped,
+    /// which is what soak runs watch for the invariant-violations criterion.
+    pub fn protection_violations(&self) -> u64 {
+        self.protection_violations
+    }
+
+    /// Count a write refused by a lineage's protection level
+    pub fn record_protection_violation(&mut self, key: &str, action: &str) {
+        self.protection_violations += 1;
+        tracing::warn!("🛡️ Refused to {} protected lineage '{}'", action, key);
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // OBSERVER EFFECT
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Energy a reinforcing read adds to each lineage it returns
+    pub fn observer_delta(&self) -> f32 {
+        self.observer_delta
+    }
+
+    /// Retune the observer effect (0.0 turns it off)
+    pub fn set_observer_delta(&mut self, delta: f32) {
+        self.observer_delta = delta;
+        tracing::info!("👁️ Observer delta set to {}", delta);
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // BACKGROUND MAINTENANCE
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Clock driving decay for this instance
+    pub fn clock(&self) -> &clock::SharedClock {
+        self.decay.clock()
+    }
+
+    /// Run one background maintenance pass
+    ///
+    /// Performs the decay tick and bond pruning, and optionally the
+    /// Cortex-driven lineage GC (which frees lineages after their
+    /// retention TTL expires). With an eviction policy and a store, idle
+    /// low-energy lineages then move to the cold tier.
+    pub fn maintenance_tick(&mut self, run_gc: bool) -> MaintenanceReport {
+        use std::time::Instant;
+
+        let t0 = Instant::now();
+        let decay = self.decay.tick_psyche(&mut self.psyche);
+        let bonds_pruned = self.decay.prune_bonds(&mut self.bonds, &mut self.psyche);
+        let decay_duration = t0.elapsed();
+
+        let (gc, gc_duration) = if run_gc {
+            let t1 = Instant::now();
+            let result = self.decay.process_gc(&mut self.psyche, &mut self.cortex);
+            #[cfg(feature = "server")]
+            for key in &result.pruned_keys {
+                self.sync_index_remove(key);
+            }
+            (Some(result), t1.elapsed())
+        } else {
+            (None, std::time::Duration::ZERO)
+        };
+
+        #[cfg(feature = "server")]
+        let evicted = self.evict_cold();
+        #[cfg(not(feature = "server"))]
+        let evicted = 0;
+
+        #[cfg(feature = "server")]
+        {
+            self.resistance = stability::recovery::decay_resistance(
+                self.resistance,
+                stability::RESISTANCE_DECAY_RATE,
+            );
+        }
+
+        MaintenanceReport {
+            decay,
+            bonds_pruned,
+            decay_duration,
+            gc,
+            gc_duration,
+            evicted,
         }
     }
 
@@ -239,16 +786,9 @@ impl MindFry {
     /// Ensure a lineage exists, create if not
     #[cfg(feature = "server")]
     fn ensure_lineage(&mut self, key: &str, initial_energy: f32) {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
-        let mut hasher = DefaultHasher::new();
-        key.hash(&mut hasher);
-        let hash = hasher.finish();
-
-        if self.psyche.lookup(hash).is_none() {
-            let lineage = Lineage::new(initial_energy);
-            let id = self.psyche.alloc_with_key(hash, lineage);
+        if self.psyche.lookup(arena::key_hash(key)).is_none() {
+            let lineage = Lineage::new_at(initial_energy, self.clock().now_nanos());
+            let id = self.psyche.alloc_named(key, lineage);
             self.sync_index_insert(key, id);
             tracing::trace!("Created system lineage: {}", key);
         }
@@ -257,32 +797,169 @@ impl MindFry {
     /// Get energy of a system lineage
     #[cfg(feature = "server")]
     pub fn get_system_energy(&self, key: &str) -> Option<f32> {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
-        let mut hasher = DefaultHasher::new();
-        key.hash(&mut hasher);
-        let hash = hasher.finish();
-
         self.psyche
-            .lookup(hash)
+            .lookup(arena::key_hash(key))
             .and_then(|id| self.psyche.get(id))
-            .map(|l| l.current_energy())
+            .map(|l| l.current_energy_at(self.clock().now_nanos()))
     }
 
-    /// Stimulate a system lineage
+    /// Current exhaustion thresholds (static defaults until the tuner warms up)
+    ///
+    /// Learned thresholds stay within the tuner's band around the static
+    /// defaults (see [`stability::ExhaustionTuner::bounded_threshold`]).
+    /// Post-crash resistance then tightens every level towards 1.0,
+    /// leaving less headroom before backpressure kicks in.
     #[cfg(feature = "server")]
-    pub fn stimulate_system(&mut self, key: &str, delta: f32) {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+    pub fn exhaustion_thresholds(&self) -> stability::ExhaustionThresholds {
+        let learned = self.exhaustion_tuner.bounded_threshold();
+        let tighten = |t: f32| t + (1.0 - t) * self.resistance;
+        stability::ExhaustionThresholds {
+            normal: tighten(learned.normal),
+            elevated: tighten(learned.elevated),
+            exhausted: tighten(learned.exhausted),
+        }
+    }
+
+    /// Apply restart trauma detected by `RecoveryAnalyzer`
+    ///
+    /// Records the trauma in `_system.shock` / `_system.coma`, feeds
+    /// `_system.resistance` and raises the runtime resistance, which
+    /// then decays by `RESISTANCE_DECAY_RATE` per maintenance tick.
+    #[cfg(feature = "server")]
+    pub fn apply_recovery(&mut self, state: stability::RecoveryState) {
+        use stability::{lineages, RecoveryState};
+
+        let intensity = state.intensity();
+        let key = match state {
+            RecoveryState::Normal => return,
+            RecoveryState::Shock => lineages::SHOCK,
+            RecoveryState::Coma => lineages::COMA,
+        };
+
+        self.ensure_lineage(key, 0.0);
+        self.stimulate_system(key, intensity);
+        self.ensure_lineage(lineages::RESISTANCE, 0.5);
+        self.stimulate_system(lineages::RESISTANCE, intensity);
+        self.resistance = (self.resistance + intensity).min(1.0);
+
+        tracing::info!(
+            "🩹 Trauma applied: {} (resistance {:.2})",
+            state.description(),
+            self.resistance
+        );
+    }
+
+    /// Record a shutdown experience
+    ///
+    /// Stimulates the matching `_system.shutdown.*` (or trauma) lineage by
+    /// the reason's intensity and appends an engram whose `source_id` is
+    /// [`stability::ShutdownReason::code`]. Intense experiences also slow
+    /// the lineage's decay, so scars outlive routine exits.
+    #[cfg(feature = "server")]
+    pub fn record_shutdown(&mut self, reason: &stability::ShutdownReason) {
+        let key = reason.lineage_key();
+        let intensity = reason.intensity();
+        let now = self.clock().now_nanos();
+        self.ensure_lineage(key, 0.0);
+
+        if let Some(id) = self.psyche.lookup(arena::key_hash(key)) {
+            if let Some(lineage) = self.psyche.get_mut(id) {
+                lineage.stimulate_at(intensity, now);
+                lineage.decay_rate = Lineage::default().decay_rate * (1.0 - intensity);
+
+                let engram = Engram {
+                    source_id: reason.code(),
+                    ..Engram::new(now, intensity)
+                };
+                lineage.head_index = self.strata.record(id, lineage.head_index, engram);
+            }
+        }
+    }
+
+    /// Current post-crash resistance (0.0 = none)
+    #[cfg(feature = "server")]
+    pub fn resistance(&self) -> f32 {
+        self.resistance
+    }
+
+    /// Maximum propagation depth under the current resistance
+    ///
+    /// Scales the synapse safety limit down while resistance is high,
+    /// never below a single hop.
+    #[cfg(feature = "server")]
+    pub fn propagation_depth(&self) -> usize {
+        let max = self.synapse.config().max_depth as f32;
+        ((max * (1.0 - self.resistance)).round() as usize).max(1)
+    }
 
-        let mut hasher = DefaultHasher::new();
-        key.hash(&mut hasher);
-        let hash = hasher.finish();
+    /// Queue a request cost against `_system.state`
+    ///
+    /// Lock-free, so request paths only need the read lock; the cost is
+    /// drained by [`Self::settle_charges`] on the next maintenance tick.
+    #[cfg(feature = "server")]
+    pub fn charge(&self, cost: f32) {
+        use std::sync::atomic::Ordering;
+
+        let _ = self
+            .pending_cost
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                Some((f32::from_bits(bits) + cost).to_bits())
+            });
+    }
 
-        if let Some(id) = self.psyche.lookup(hash) {
+    /// Request costs queued since the last [`Self::settle_charges`]
+    #[cfg(feature = "server")]
+    pub fn pending_cost(&self) -> f32 {
+        f32::from_bits(self.pending_cost.load(std::sync::atomic::Ordering::Relaxed))
+    }
+
+    /// Drain queued request costs from `_system.state`
+    ///
+    /// Called once per maintenance tick, before recovery.
+    #[cfg(feature = "server")]
+    pub fn settle_charges(&mut self) {
+        let cost = f32::from_bits(*self.pending_cost.get_mut());
+        *self.pending_cost.get_mut() = 0;
+        if cost != 0.0 {
+            self.stimulate_system(stability::lineages::STATE, -cost);
+        }
+    }
+
+    /// `_system.state` energy with queued request costs already taken off
+    #[cfg(feature = "server")]
+    pub fn state_energy(&self) -> f32 {
+        let energy = self
+            .get_system_energy(stability::lineages::STATE)
+            .unwrap_or(1.0);
+        (energy - self.pending_cost()).max(0.0)
+    }
+
+    /// Current exhaustion level of `_system.state` under the learned thresholds
+    #[cfg(feature = "server")]
+    pub fn exhaustion_level(&self) -> stability::ExhaustionLevel {
+        stability::ExhaustionLevel::from_energy_with_thresholds(
+            self.state_energy(),
+            &self.exhaustion_thresholds(),
+        )
+    }
+
+    /// Feed the current `_system.state` energy to the exhaustion tuner
+    ///
+    /// Called once per maintenance tick.
+    #[cfg(feature = "server")]
+    pub fn observe_exhaustion(&mut self) {
+        if let Some(energy) = self.get_system_energy(stability::lineages::STATE) {
+            self.exhaustion_tuner.observe(energy);
+        }
+    }
+
+    /// Stimulate a system lineage
+    #[cfg(feature = "server")]
+    pub fn stimulate_system(&mut self, key: &str, delta: f32) {
+        if let Some(id) = self.psyche.lookup(arena::key_hash(key)) {
+            let now = self.clock().now_nanos();
             if let Some(lineage) = self.psyche.get_mut(id) {
-                lineage.stimulate(delta);
+                lineage.stimulate_at(delta, now);
             }
         }
     }
@@ -294,6 +971,23 @@ impl Default for MindFry {
     }
 }
 
+/// Outcome of [`MindFry::maintenance_tick`]
+#[derive(Debug, Clone)]
+pub struct MaintenanceReport {
+    /// Decay tick result
+    pub decay: dynamics::DecayTickResult,
+    /// Bonds pruned below the strength threshold
+    pub bonds_pruned: usize,
+    /// Time spent in decay tick and bond pruning
+    pub decay_duration: std::time::Duration,
+    /// GC result (None when GC was skipped)
+    pub gc: Option<dynamics::GcResult>,
+    /// Time spent in GC
+    pub gc_duration: std::time::Duration,
+    /// Lineages moved to the cold tier
+    pub evicted: usize,
+}
+
 /// Configuration for MindFry instance
 #[derive(Debug, Clone)]
 pub struct MindFryConfig {
@@ -305,6 +999,16 @@ pub struct MindFryConfig {
     pub strata_depth: usize,
     /// Decay engine configuration
     pub decay: DecayConfig,
+    /// Time source for decay (process-wide clock by default)
+    pub clock: clock::SharedClock,
+    /// Energy a reinforcing read adds (observer effect)
+    pub observer_delta: f32,
+    /// Exhaustion tuner configuration
+    #[cfg(feature = "server")]
+    pub tuner: stability::TunerConfig,
+    /// Cold tier eviction during maintenance (None = keep everything in RAM)
+    #[cfg(feature = "server")]
+    pub eviction: Option<persistence::EvictionPolicy>,
 }
 
 impl Default for MindFryConfig {
@@ -314,6 +1018,12 @@ impl Default for MindFryConfig {
             max_bonds: DEFAULT_MAX_BONDS,
             strata_depth: DEFAULT_STRATA_DEPTH,
             decay: DecayConfig::default(),
+            clock: clock::global(),
+            observer_delta: DEFAULT_OBSERVER_DELTA,
+            #[cfg(feature = "server")]
+            tuner: stability::TunerConfig::default(),
+            #[cfg(feature = "server")]
+            eviction: None,
         }
     }
 }
@@ -322,10 +1032,183 @@ impl Default for MindFryConfig {
 mod tests {
     use super::*;
 
+    /// Config for a tiny instance: 16 lineages, 16 bonds, 4 engrams deep
+    fn small_config() -> MindFryConfig {
+        MindFryConfig {
+            max_lineages: 16,
+            max_bonds: 16,
+            strata_depth: 4,
+            ..Default::default()
+        }
+    }
+
+    fn small_db() -> MindFry {
+        MindFry::with_config(small_config())
+    }
+
     #[test]
     fn test_create_mindfry() {
         let db = MindFry::new();
         assert_eq!(db.psyche.len(), 0);
         assert_eq!(db.bonds.len(), 0);
     }
+
+    #[test]
+    fn test_maintenance_tick() {
+        let mut db = small_db();
+        let a = db.psyche.alloc(Lineage::new(0.9));
+        let b = db.psyche.alloc(Lineage::new(0.9));
+        db.bonds.connect(Bond::new(a, b, 0.01)); // Below prune threshold
+
+        let report = db.maintenance_tick(false);
+        assert_eq!(report.decay.processed, 2);
+        assert_eq!(report.bonds_pruned, 1);
+        assert!(report.gc.is_none());
+
+        let report = db.maintenance_tick(true);
+        assert_eq!(report.gc.unwrap().processed, 2);
+    }
+
+    #[test]
+    fn test_exhaustion_tuner_thresholds() {
+        use stability::{lineages, ExhaustionLevel, ExhaustionThresholds, TunerConfig};
+
+        let mut db = MindFry::with_config(MindFryConfig {
+            tuner: TunerConfig {
+                warmup_ticks: 3,
+                min_floor: 0.05,
+                ..Default::default()
+            },
+            ..small_config()
+        });
+        db.bootstrap_system_lineages();
+
+        // Static defaults until warmed up
+        db.stimulate_system(lineages::STATE, -0.5);
+        db.observe_exhaustion();
+        assert_eq!(
+            db.exhaustion_thresholds().normal,
+            ExhaustionThresholds::default().normal
+        );
+        assert_eq!(db.exhaustion_level(), ExhaustionLevel::Elevated);
+
+        // A system that normally idles around 0.5 learns that 0.5 is normal
+        db.observe_exhaustion();
+        db.observe_exhaustion();
+        assert!(db.exhaustion_tuner.get_stats().is_warmed_up);
+        assert!(db.exhaustion_thresholds().normal < 0.5);
+        assert_eq!(db.exhaustion_level(), ExhaustionLevel::Normal);
+    }
+
+    #[test]
+    fn test_apply_recovery_resistance() {
+        use stability::{lineages, ExhaustionThresholds, RecoveryState};
+
+        let mut db = small_db();
+        db.bootstrap_system_lineages();
+
+        db.apply_recovery(RecoveryState::Normal);
+        assert_eq!(db.resistance(), 0.0);
+        assert_eq!(db.propagation_depth(), db.synapse.config().max_depth);
+
+        db.apply_recovery(RecoveryState::Coma);
+        assert_eq!(db.resistance(), RecoveryState::Coma.intensity());
+        assert!(db.get_system_energy(lineages::COMA).unwrap() > 0.0);
+        assert!(db.get_system_energy(lineages::SHOCK).is_none());
+        assert!(db.propagation_depth() < db.synapse.config().max_depth);
+        assert!(db.exhaustion_thresholds().elevated > ExhaustionThresholds::default().elevated);
+
+        // Resistance fades with maintenance ticks
+        db.maintenance_tick(false);
+        assert!(db.resistance() < RecoveryState::Coma.intensity());
+        for _ in 0..100 {
+            db.maintenance_tick(false);
+        }
+        assert_eq!(db.resistance(), 0.0);
+        assert_eq!(
+            db.exhaustion_thresholds().elevated,
+            ExhaustionThresholds::default().elevated
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_clears_dirty() {
+        let mut db = small_db();
+        let a = db.psyche.alloc(Lineage::new(0.9));
+        let b = db.psyche.alloc(Lineage::new(0.9));
+        db.bonds.connect(Bond::new(a, b, 0.5));
+        assert_eq!(db.dirty_count(), 2);
+
+        let checkpoint = db.checkpoint();
+        assert_eq!(checkpoint.lineage_count(), 2);
+        assert_eq!(checkpoint.bond_count(), 1);
+        assert_eq!(db.dirty_count(), 0);
+
+        // Later mutations are not part of the captured state
+        db.psyche.get_mut(a).unwrap().stimulate(0.1);
+        assert_eq!(db.dirty_count(), 1);
+        assert_eq!(checkpoint.lineage_count(), 2);
+
+        // Deltas build on the last checkpoint and carry only dirty slots
+        let delta = db.checkpoint_delta();
+        assert_eq!(delta.parent(), Some(checkpoint.id()));
+        assert_eq!(delta.lineage_count(), 1);
+        assert_eq!(db.last_checkpoint(), Some(delta.id()));
+
+        // Without a base the next delta is a full checkpoint
+        db.invalidate_checkpoint_chain();
+        assert!(!db.checkpoint_delta().is_delta());
+    }
+
+    #[test]
+    fn test_record_shutdown_experience() {
+        use stability::{lineages, ShutdownReason};
+
+        let mut db = small_db();
+        let reason = ShutdownReason::Signal { signal: 15 };
+        db.record_shutdown(&reason);
+        db.record_shutdown(&reason);
+
+        let id = db
+            .psyche
+            .lookup(arena::key_hash(lineages::SHUTDOWN_FORCED))
+            .unwrap();
+        let lineage = db.psyche.get(id).unwrap();
+        assert!(lineage.current_energy() > 0.0);
+        assert!(lineage.decay_rate < Lineage::default().decay_rate);
+
+        let history: Vec<_> = db.strata.history(lineage.head_index).collect();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].source_id, reason.code());
+        assert_eq!(history[0].stimulation, reason.intensity());
+    }
+
+    #[test]
+    fn test_system_pulse_does_not_make_snapshot_due() {
+        let mut db = small_db();
+        db.bootstrap_system_lineages();
+        db.checkpoint();
+
+        // Pulses stay dirty for the next delta but don't count as changes
+        db.stimulate_system(stability::lineages::HEALTH, 0.1);
+        assert_eq!((db.dirty_count(), db.client_dirty_count()), (1, 0));
+
+        db.psyche.alloc(Lineage::new(0.9));
+        assert_eq!((db.dirty_count(), db.client_dirty_count()), (2, 1));
+    }
+
+    #[test]
+    fn test_pruned_bonds_reach_delta_checkpoints() {
+        let mut db = small_db();
+        let a = db.psyche.alloc(Lineage::new(0.9));
+        let b = db.psyche.alloc(Lineage::new(0.9));
+        db.bonds.connect(Bond::new(a, b, 0.01)); // Below prune threshold
+        db.checkpoint();
+
+        assert_eq!(db.decay.prune_bonds(&mut db.bonds, &mut db.psyche), 1);
+        assert_eq!(db.dirty_count(), 2);
+        let delta = db.checkpoint_delta();
+        assert_eq!(delta.lineage_count(), 2);
+        assert_eq!(delta.bond_count(), 0);
+    }
 }
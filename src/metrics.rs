@@ -0,0 +1,684 @@
+//! Metrics - Prometheus text exposition
+//!
+//! Lock-free counters and fixed-bucket histograms shared between the
+//! MFBP server, the HTTP gateway and the background maintenance loop.
+//! Gauges derived from the memory itself (lineage counts, energy
+//! distribution, exhaustion) are computed at scrape time.
+//!
+//! Served as `GET /metrics` by the HTTP gateway.
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::arena::Temperature;
+use crate::dynamics::GcResult;
+use crate::protocol::{ErrorCode, OpCode};
+use crate::stability::{lineages, ExhaustionLevel, HealthStatus, WarmupState};
+use crate::{MaintenanceReport, MindFry};
+
+/// Latency bucket upper bounds (seconds)
+const LATENCY_BUCKETS: [f64; 14] = [
+    0.00005, 0.0001, 0.00025, 0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 1.0, 5.0,
+];
+
+/// Energy distribution bucket upper bounds
+const ENERGY_BUCKETS: [f64; 10] = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
+
+/// Number of addressable OpCode / ErrorCode bytes
+const CODE_SPACE: usize = 256;
+
+// ═══════════════════════════════════════════════════════════════
+// HISTOGRAM
+// ═══════════════════════════════════════════════════════════════
+
+/// Fixed-bucket latency histogram
+pub struct Histogram {
+    /// Per-bucket counts (non-cumulative; last slot is +Inf)
+    buckets: [AtomicU64; LATENCY_BUCKETS.len() + 1],
+    /// Sum of observations in nanoseconds
+    sum_nanos: AtomicU64,
+    /// Observation count
+    count: AtomicU64,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            sum_nanos: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    /// Record a single duration
+    pub fn observe(&self, duration: Duration) {
+        let secs = duration.as_secs_f64();
+        let slot = LATENCY_BUCKETS
+            .iter()
+            .position(|&le| secs <= le)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.buckets[slot].fetch_add(1, Ordering::Relaxed);
+        self.sum_nanos
+            .fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Number of observations
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let sep = if labels.is_empty() { "" } else { "," };
+        let mut cumulative = 0;
+        for (i, le) in LATENCY_BUCKETS.iter().enumerate() {
+            cumulative += self.buckets[i].load(Ordering::Relaxed);
+            let _ = writeln!(
+                out,
+                "{}_bucket{{{}{}le=\"{}\"}} {}",
+                name, labels, sep, le, cumulative
+            );
+        }
+        cumulative += self.buckets[LATENCY_BUCKETS.len()].load(Ordering::Relaxed);
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{}{}le=\"+Inf\"}} {}",
+            name, labels, sep, cumulative
+        );
+        let braces = if labels.is_empty() {
+            String::new()
+        } else {
+            format!("{{{}}}", labels)
+        };
+        let sum = self.sum_nanos.load(Ordering::Relaxed) as f64 / 1e9;
+        let _ = writeln!(out, "{}_sum{} {}", name, braces, sum);
+        let _ = writeln!(out, "{}_count{} {}", name, braces, self.count());
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// METRICS REGISTRY
+// ═══════════════════════════════════════════════════════════════
+
+/// Transport a connection arrived on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    /// Binary MFBP over TCP
+    Mfbp,
+    /// HTTP/JSON gateway
+    Http,
+}
+
+impl Transport {
+    fn label(self) -> &'static str {
+        match self {
+            Transport::Mfbp => "mfbp",
+            Transport::Http => "http",
+        }
+    }
+}
+
+/// Process-wide metrics registry
+pub struct Metrics {
+    requests: [AtomicU64; CODE_SPACE],
+    latency: Vec<Histogram>,
+    errors: [AtomicU64; CODE_SPACE],
+
+    decay_ticks: Histogram,
+    decay_dead: AtomicU64,
+    bonds_pruned: AtomicU64,
+    gc_ticks: Histogram,
+    gc_pruned: AtomicU64,
+    gc_pending: AtomicU64,
+
+    snapshot_duration: Histogram,
+    snapshot_last_bytes: AtomicU64,
+    snapshot_bytes: AtomicU64,
+    snapshot_failures: AtomicU64,
+
+    connections_mfbp: AtomicU64,
+    connections_http: AtomicU64,
+}
+
+impl Metrics {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            requests: std::array::from_fn(|_| AtomicU64::new(0)),
+            latency: (0..CODE_SPACE).map(|_| Histogram::new()).collect(),
+            errors: std::array::from_fn(|_| AtomicU64::new(0)),
+            decay_ticks: Histogram::new(),
+            decay_dead: AtomicU64::new(0),
+            bonds_pruned: AtomicU64::new(0),
+            gc_ticks: Histogram::new(),
+            gc_pruned: AtomicU64::new(0),
+            gc_pending: AtomicU64::new(0),
+            snapshot_duration: Histogram::new(),
+            snapshot_last_bytes: AtomicU64::new(0),
+            snapshot_bytes: AtomicU64::new(0),
+            snapshot_failures: AtomicU64::new(0),
+            connections_mfbp: AtomicU64::new(0),
+            connections_http: AtomicU64::new(0),
+        }
+    }
+
+    /// Record a handled request
+    pub fn record_request(&self, opcode: OpCode, latency: Duration, error: Option<ErrorCode>) {
+        let idx = opcode as u8 as usize;
+        self.requests[idx].fetch_add(1, Ordering::Relaxed);
+        self.latency[idx].observe(latency);
+        if let Some(code) = error {
+            self.errors[code as u8 as usize].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Number of requests seen for an OpCode
+    pub fn request_count(&self, opcode: OpCode) -> u64 {
+        self.requests[opcode as u8 as usize].load(Ordering::Relaxed)
+    }
+
+    /// Number of errors returned with a code
+    pub fn error_count(&self, code: ErrorCode) -> u64 {
+        self.errors[code as u8 as usize].load(Ordering::Relaxed)
+    }
+
+    /// Record a background maintenance pass
+    pub fn record_maintenance(&self, report: &MaintenanceReport) {
+        self.decay_ticks.observe(report.decay_duration);
+        self.decay_dead
+            .store(report.decay.dead_count as u64, Ordering::Relaxed);
+        self.bonds_pruned
+            .fetch_add(report.bonds_pruned as u64, Ordering::Relaxed);
+        if let Some(GcResult {
+            pruned, pending, ..
+        }) = report.gc
+        {
+            self.gc_ticks.observe(report.gc_duration);
+            self.gc_pruned.fetch_add(pruned as u64, Ordering::Relaxed);
+            self.gc_pending.store(pending as u64, Ordering::Relaxed);
+        }
+    }
+
+    /// Record a completed snapshot
+    pub fn record_snapshot(&self, duration: Duration, size_bytes: u64) {
+        self.snapshot_duration.observe(duration);
+        self.snapshot_last_bytes
+            .store(size_bytes, Ordering::Relaxed);
+        self.snapshot_bytes.fetch_add(size_bytes, Ordering::Relaxed);
+    }
+
+    /// Record a failed snapshot attempt
+    pub fn record_snapshot_failure(&self) {
+        self.snapshot_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Track an open connection until the guard is dropped
+    pub fn connection(&self, transport: Transport) -> ConnectionGuard<'_> {
+        self.connection_counter(transport)
+            .fetch_add(1, Ordering::Relaxed);
+        ConnectionGuard {
+            metrics: self,
+            transport,
+        }
+    }
+
+    /// Currently open connections on a transport
+    pub fn active_connections(&self, transport: Transport) -> u64 {
+        self.connection_counter(transport).load(Ordering::Relaxed)
+    }
+
+    fn connection_counter(&self, transport: Transport) -> &AtomicU64 {
+        match transport {
+            Transport::Mfbp => &self.connections_mfbp,
+            Transport::Http => &self.connections_http,
+        }
+    }
+
+    /// Render all metrics in Prometheus text format
+    pub fn render(&self, db: &MindFry, warmup: WarmupState) -> String {
+        let mut out = String::with_capacity(16 * 1024);
+
+        // ── Requests ────────────────────────────────────────────
+        header(
+            &mut out,
+            "mindfry_requests_total",
+            "counter",
+            "Requests handled per OpCode",
+        );
+        for (opcode, count) in self.opcodes_seen() {
+            let _ = writeln!(
+                out,
+                "mindfry_requests_total{{opcode=\"{:?}\"}} {}",
+                opcode, count
+            );
+        }
+
+        header(
+            &mut out,
+            "mindfry_request_duration_seconds",
+            "histogram",
+            "Request handling latency per OpCode",
+        );
+        for (opcode, _) in self.opcodes_seen() {
+            self.latency[opcode as u8 as usize].render(
+                &mut out,
+                "mindfry_request_duration_seconds",
+                &format!("opcode=\"{:?}\"", opcode),
+            );
+        }
+
+        header(
+            &mut out,
+            "mindfry_errors_total",
+            "counter",
+            "Error responses per ErrorCode",
+        );
+        for (byte, counter) in self.errors.iter().enumerate() {
+            let count = counter.load(Ordering::Relaxed);
+            if count > 0 {
+                let _ = writeln!(
+                    out,
+                    "mindfry_errors_total{{code=\"{:?}\"}} {}",
+                    ErrorCode::from_byte(byte as u8),
+                    count
+                );
+            }
+        }
+
+        // ── Memory ──────────────────────────────────────────────
+        let mut energy_buckets = [0u64; ENERGY_BUCKETS.len()];
+        let mut energy_sum = 0.0f64;
+        let mut conscious = 0u64;
+        let mut tiers = [0u64; 4];
+        let now = db.clock().now_nanos();
+        for (_, lineage) in db.psyche.iter() {
+            tiers[lineage.temperature() as usize] += 1;
+            let energy = lineage.current_energy_at(now) as f64;
+            energy_sum += energy;
+            if lineage.is_conscious() {
+                conscious += 1;
+            }
+            let slot = ENERGY_BUCKETS
+                .iter()
+                .position(|&le| energy <= le)
+                .unwrap_or(ENERGY_BUCKETS.len() - 1);
+            energy_buckets[slot] += 1;
+        }
+
+        gauge(
+            &mut out,
+            "mindfry_lineages",
+            "Active lineages",
+            db.psyche.len(),
+        );
+        gauge(
+            &mut out,
+            "mindfry_lineages_evicted",
+            "Lineages evicted to the cold tier",
+            db.evicted_count(),
+        );
+        header(
+            &mut out,
+            "mindfry_lineages_by_temperature",
+            "gauge",
+            "Resident lineages per temperature tier",
+        );
+        for tier in Temperature::ALL {
+            let _ = writeln!(
+                out,
+                "mindfry_lineages_by_temperature{{tier=\"{}\"}} {}",
+                tier.name(),
+                tiers[tier as usize]
+            );
+        }
+        gauge(&mut out, "mindfry_bonds", "Active bonds", db.bonds.len());
+        counter(
+            &mut out,
+            "mindfry_protection_violations_total",
+            "Writes refused by lineage protection levels",
+            db.protection_violations(),
+        );
+        gauge(
+            &mut out,
+            "mindfry_conscious_lineages",
+            "Lineages at or above their threshold",
+            conscious,
+        );
+
+        header(
+            &mut out,
+            "mindfry_lineage_energy",
+            "histogram",
+            "Distribution of decayed lineage energy",
+        );
+        let mut cumulative = 0;
+        for (le, count) in ENERGY_BUCKETS.iter().zip(energy_buckets.iter()) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "mindfry_lineage_energy_bucket{{le=\"{}\"}} {}",
+                le, cumulative
+            );
+        }
+        let _ = writeln!(
+            out,
+            "mindfry_lineage_energy_bucket{{le=\"+Inf\"}} {}",
+            cumulative
+        );
+        let _ = writeln!(out, "mindfry_lineage_energy_sum {}", energy_sum);
+        let _ = writeln!(out, "mindfry_lineage_energy_count {}", cumulative);
+
+        // ── Stability ───────────────────────────────────────────
+        let health_energy = db.get_system_energy(lineages::HEALTH).unwrap_or(1.0);
+        gauge(
+            &mut out,
+            "mindfry_health_energy",
+            "Energy of the _system.health lineage",
+            health_energy,
+        );
+        let health = HealthStatus::from_energy(health_energy);
+        header(
+            &mut out,
+            "mindfry_health_status",
+            "gauge",
+            "Self-diagnostic health status (1 = active)",
+        );
+        for candidate in [
+            HealthStatus::Healthy,
+            HealthStatus::Degraded,
+            HealthStatus::Unhealthy,
+        ] {
+            let _ = writeln!(
+                out,
+                "mindfry_health_status{{status=\"{:?}\"}} {}",
+                candidate,
+                (candidate == health) as u8
+            );
+        }
+
+        let state_energy = db.state_energy();
+        gauge(
+            &mut out,
+            "mindfry_system_state_energy",
+            "Energy of the _system.state lineage",
+            state_energy,
+        );
+        let level = db.exhaustion_level();
+        header(
+            &mut out,
+            "mindfry_exhaustion_level",
+            "gauge",
+            "Current exhaustion level (1 = active)",
+        );
+        for candidate in [
+            ExhaustionLevel::Normal,
+            ExhaustionLevel::Elevated,
+            ExhaustionLevel::Exhausted,
+            ExhaustionLevel::Emergency,
+        ] {
+            let _ = writeln!(
+                out,
+                "mindfry_exhaustion_level{{level=\"{:?}\"}} {}",
+                candidate,
+                (candidate == level) as u8
+            );
+        }
+
+        let thresholds = db.exhaustion_thresholds();
+        header(
+            &mut out,
+            "mindfry_exhaustion_threshold",
+            "gauge",
+            "Live lower bound of each exhaustion level",
+        );
+        for (label, value) in [
+            ("Normal", thresholds.normal),
+            ("Elevated", thresholds.elevated),
+            ("Exhausted", thresholds.exhausted),
+        ] {
+            let _ = writeln!(
+                out,
+                "mindfry_exhaustion_threshold{{level=\"{}\"}} {}",
+                label, value
+            );
+        }
+
+        let tuner = db.exhaustion_tuner.get_stats();
+        gauge(
+            &mut out,
+            "mindfry_exhaustion_tuner_mean",
+            "EMA mean of observed _system.state energy",
+            tuner.mean,
+        );
+        gauge(
+            &mut out,
+            "mindfry_exhaustion_tuner_std_dev",
+            "EMA standard deviation of observed _system.state energy",
+            tuner.std_dev,
+        );
+        gauge(
+            &mut out,
+            "mindfry_exhaustion_tuner_samples",
+            "Observations fed to the exhaustion tuner",
+            tuner.sample_count,
+        );
+        gauge(
+            &mut out,
+            "mindfry_exhaustion_tuner_warmed_up",
+            "Whether adaptive thresholds are active",
+            tuner.is_warmed_up as u8,
+        );
+        gauge(
+            &mut out,
+            "mindfry_recovery_resistance",
+            "Post-crash resistance (decays per maintenance tick)",
+            db.resistance(),
+        );
+
+        header(
+            &mut out,
+            "mindfry_warmup_state",
+            "gauge",
+            "Current warmup state (1 = active)",
+        );
+        for candidate in [
+            WarmupState::Cold,
+            WarmupState::Resurrecting,
+            WarmupState::Partial,
+            WarmupState::Ready,
+        ] {
+            let _ = writeln!(
+                out,
+                "mindfry_warmup_state{{state=\"{:?}\"}} {}",
+                candidate,
+                (candidate == warmup) as u8
+            );
+        }
+
+        // ── Maintenance ─────────────────────────────────────────
+        header(
+            &mut out,
+            "mindfry_decay_tick_duration_seconds",
+            "histogram",
+            "Decay tick and bond pruning duration",
+        );
+        self.decay_ticks
+            .render(&mut out, "mindfry_decay_tick_duration_seconds", "");
+        gauge(
+            &mut out,
+            "mindfry_decay_dead_lineages",
+            "Lineages below the death threshold at the last tick",
+            self.decay_dead.load(Ordering::Relaxed),
+        );
+        counter(
+            &mut out,
+            "mindfry_bonds_pruned_total",
+            "Bonds pruned by the decay engine",
+            self.bonds_pruned.load(Ordering::Relaxed),
+        );
+        header(
+            &mut out,
+            "mindfry_gc_tick_duration_seconds",
+            "histogram",
+            "Lineage GC duration",
+        );
+        self.gc_ticks
+            .render(&mut out, "mindfry_gc_tick_duration_seconds", "");
+        counter(
+            &mut out,
+            "mindfry_gc_pruned_total",
+            "Lineages freed by GC",
+            self.gc_pruned.load(Ordering::Relaxed),
+        );
+        gauge(
+            &mut out,
+            "mindfry_gc_pending_lineages",
+            "Lineages in the retention buffer",
+            self.gc_pending.load(Ordering::Relaxed),
+        );
+
+        // ── Snapshots ───────────────────────────────────────────
+        header(
+            &mut out,
+            "mindfry_snapshot_duration_seconds",
+            "histogram",
+            "Snapshot serialization and write duration",
+        );
+        self.snapshot_duration
+            .render(&mut out, "mindfry_snapshot_duration_seconds", "");
+        gauge(
+            &mut out,
+            "mindfry_snapshot_last_size_bytes",
+            "Size of the most recent snapshot",
+            self.snapshot_last_bytes.load(Ordering::Relaxed),
+        );
+        counter(
+            &mut out,
+            "mindfry_snapshot_bytes_total",
+            "Bytes written by snapshots",
+            self.snapshot_bytes.load(Ordering::Relaxed),
+        );
+        counter(
+            &mut out,
+            "mindfry_snapshot_failures_total",
+            "Failed snapshot attempts",
+            self.snapshot_failures.load(Ordering::Relaxed),
+        );
+
+        // ── Connections ─────────────────────────────────────────
+        header(
+            &mut out,
+            "mindfry_active_connections",
+            "gauge",
+            "Open client connections",
+        );
+        for transport in [Transport::Mfbp, Transport::Http] {
+            let _ = writeln!(
+                out,
+                "mindfry_active_connections{{transport=\"{}\"}} {}",
+                transport.label(),
+                self.active_connections(transport)
+            );
+        }
+
+        out
+    }
+
+    fn opcodes_seen(&self) -> impl Iterator<Item = (OpCode, u64)> + '_ {
+        self.requests
+            .iter()
+            .enumerate()
+            .filter_map(|(byte, counter)| {
+                let count = counter.load(Ordering::Relaxed);
+                let opcode = OpCode::from_byte(byte as u8)?;
+                (count > 0).then_some((opcode, count))
+            })
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Decrements the active connection gauge on drop
+pub struct ConnectionGuard<'a> {
+    metrics: &'a Metrics,
+    transport: Transport,
+}
+
+impl Drop for ConnectionGuard<'_> {
+    fn drop(&mut self) {
+        self.metrics
+            .connection_counter(self.transport)
+            .fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn gauge(out: &mut String, name: &str, help: &str, value: impl std::fmt::Display) {
+    header(out, name, "gauge", help);
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+fn counter(out: &mut String, name: &str, help: &str, value: u64) {
+    header(out, name, "counter", help);
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets() {
+        let h = Histogram::new();
+        h.observe(Duration::from_micros(10));
+        h.observe(Duration::from_millis(3));
+        h.observe(Duration::from_secs(60));
+
+        let mut out = String::new();
+        h.render(&mut out, "x", "");
+        assert!(out.contains("x_bucket{le=\"0.00005\"} 1"));
+        assert!(out.contains("x_bucket{le=\"0.005\"} 2"));
+        assert!(out.contains("x_bucket{le=\"+Inf\"} 3"));
+        assert!(out.contains("x_count 3"));
+    }
+
+    #[test]
+    fn test_render_requests_and_connections() {
+        let metrics = Metrics::new();
+        metrics.record_request(OpCode::SysPing, Duration::from_micros(5), None);
+        metrics.record_request(
+            OpCode::LineageGet,
+            Duration::from_micros(5),
+            Some(ErrorCode::LineageNotFound),
+        );
+        let _guard = metrics.connection(Transport::Http);
+
+        let db = MindFry::new();
+        let text = metrics.render(&db, WarmupState::Ready);
+
+        assert!(text.contains("mindfry_requests_total{opcode=\"SysPing\"} 1"));
+        assert!(text.contains("mindfry_errors_total{code=\"LineageNotFound\"} 1"));
+        assert!(text.contains("mindfry_active_connections{transport=\"http\"} 1"));
+        assert!(text.contains("mindfry_warmup_state{state=\"Ready\"} 1"));
+        assert!(text.contains("mindfry_lineages 0"));
+    }
+
+    #[test]
+    fn test_connection_guard_releases() {
+        let metrics = Metrics::new();
+        {
+            let _guard = metrics.connection(Transport::Mfbp);
+            assert_eq!(metrics.active_connections(Transport::Mfbp), 1);
+        }
+        assert_eq!(metrics.active_connections(Transport::Mfbp), 0);
+    }
+}
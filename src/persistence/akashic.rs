@@ -2,18 +2,36 @@
 //!
 //! Uses sled for persistent key-value storage with multiple trees:
 //! - `meta`: Configuration and version info
-//! - `snapshots`: Full state backups
+//! - `snapshots`: Full and delta state backups
 //! - `snapshot_meta`: Snapshot metadata index
+//! - `snapshot_parent`: Delta snapshot → parent links ([`ChainLink`])
+//! - `snapshot_keys`: Lineage key table of each snapshot
+//! - `lineage_index`: Lineage key → slot ID ([`super::LineageIndexer`])
+//! - `evicted_lineages`: Lineages moved out of RAM ([`super::ColdTier`])
+//!
+//! A store opened with [`AkashicConfig::read_only`] refuses every write,
+//! for offline inspection of a data directory.
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use sled::{Db, Tree};
 
-use super::snapshot::{PhysicsSnapshot, Snapshot, SnapshotMeta};
-use crate::arena::{Engram, Lineage, PsycheArena, StrataArena};
+use super::retention::RetentionPolicy;
+use super::snapshot::{
+    decode_meta, decode_snapshot, ChainLink, Checkpoint, DeltaPsyche, PhysicsSnapshot,
+    SectionChecksums, Snapshot, SnapshotContents, SnapshotHealth, SnapshotMeta, SnapshotSection,
+    VerifyReport,
+};
+use crate::arena::{Engram, Lineage, LineageId, PsycheArena, StrataArena};
 use crate::graph::{Bond, BondGraph};
 
+/// Minimum time between the writes of [`AkashicStore::probe_throttled`]
+pub const PROBE_INTERVAL: Duration = Duration::from_secs(5);
+
 /// Akashic Store error types
 #[derive(Debug)]
 pub enum AkashicError {
@@ -27,6 +45,10 @@ pub enum AkashicError {
     InvalidData(String),
     /// IO error
     Io(std::io::Error),
+    /// Snapshot sections fail their checksums
+    Corrupted(u64, Vec<SnapshotSection>),
+    /// Write attempted on a store opened read-only
+    ReadOnly,
 }
 
 impl From<sled::Error> for AkashicError {
@@ -55,6 +77,11 @@ impl std::fmt::Display for AkashicError {
             Self::SnapshotNotFound(name) => write!(f, "Snapshot not found: {}", name),
             Self::InvalidData(msg) => write!(f, "Invalid data: {}", msg),
             Self::Io(e) => write!(f, "IO error: {}", e),
+            Self::Corrupted(id, sections) => {
+                write!(f, "{}", SnapshotHealth::Damaged(sections.clone()))?;
+                write!(f, " in snapshot {}", id)
+            }
+            Self::ReadOnly => write!(f, "Store is read-only"),
         }
     }
 }
@@ -73,6 +100,10 @@ pub struct AkashicConfig {
     pub sync_writes: bool,
     /// Cache size in bytes
     pub cache_size: u64,
+    /// Which snapshots survive garbage collection
+    pub retention: RetentionPolicy,
+    /// Refuse all writes; the store must already exist
+    pub read_only: bool,
 }
 
 impl Default for AkashicConfig {
@@ -81,6 +112,8 @@ impl Default for AkashicConfig {
             path: "./mindfry_data".into(),
             sync_writes: false,
             cache_size: 64 * 1024 * 1024, // 64MB cache
+            retention: RetentionPolicy::default(),
+            read_only: false,
         }
     }
 }
@@ -101,15 +134,34 @@ pub struct AkashicStore {
     snapshots: Tree,
     /// Snapshot metadata index
     snapshot_meta: Tree,
+    /// Delta chain links
+    snapshot_parent: Tree,
+    /// Lineage key tables
+    snapshot_keys: Tree,
     /// Lineage key-to-id index for O(1) lookups
     indexer: super::indexer::LineageIndexer,
+    /// Evicted lineages by key
+    cold_tier: super::tiering::ColdTier,
+    /// Retention suspended until an operator restores
+    held: AtomicBool,
+    /// Time and verdict of the last write probe
+    last_probe: Mutex<Option<(Instant, bool)>>,
     /// Configuration
-    _config: AkashicConfig,
+    config: AkashicConfig,
 }
 
 impl AkashicStore {
     /// Open or create an Akashic Store
+    ///
+    /// Read-only stores are never created: a missing path is an error.
     pub fn open(config: AkashicConfig) -> Result<Self> {
+        if config.read_only && !std::path::Path::new(&config.path).exists() {
+            return Err(AkashicError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("no store at {}", config.path),
+            )));
+        }
+
         let db = sled::Config::new()
             .path(&config.path)
             .cache_capacity(config.cache_size)
@@ -118,10 +170,19 @@ impl AkashicStore {
 
         let snapshots = db.open_tree("snapshots")?;
         let snapshot_meta = db.open_tree("snapshot_meta")?;
+        let snapshot_parent = db.open_tree("snapshot_parent")?;
+        let snapshot_keys = db.open_tree("snapshot_keys")?;
         let lineage_index = db.open_tree("lineage_index")?;
+        let evicted_lineages = db.open_tree("evicted_lineages")?;
 
         // Initialize meta if first run
         let meta_tree = db.open_tree("meta")?;
+        if meta_tree.is_empty() && config.read_only {
+            return Err(AkashicError::InvalidData(format!(
+                "{} is not a MindFry store",
+                config.path
+            )));
+        }
         if meta_tree.is_empty() {
             let meta = StoreMeta {
                 version: env!("CARGO_PKG_VERSION").to_string(),
@@ -135,11 +196,34 @@ impl AkashicStore {
             db,
             snapshots,
             snapshot_meta,
+            snapshot_parent,
+            snapshot_keys,
             indexer: super::indexer::LineageIndexer::new(lineage_index),
-            _config: config,
+            cold_tier: super::tiering::ColdTier::new(evicted_lineages),
+            held: AtomicBool::new(false),
+            last_probe: Mutex::new(None),
+            config,
         })
     }
 
+    /// Suspend retention, e.g. after a failed resurrection
+    ///
+    /// The running state no longer reflects what is on disk, so pruning
+    /// by age would delete the snapshots an operator needs to restore.
+    pub fn hold(&self) {
+        self.held.store(true, Ordering::Release);
+    }
+
+    /// Resume retention after a successful restore
+    pub fn release(&self) {
+        self.held.store(false, Ordering::Release);
+    }
+
+    /// Whether retention (and automatic snapshots) are suspended
+    pub fn is_held(&self) -> bool {
+        self.held.load(Ordering::Acquire)
+    }
+
     /// Take a snapshot of the current MindFry state
     pub fn take_snapshot(
         &self,
@@ -150,16 +234,45 @@ impl AkashicStore {
         cortex: Option<&crate::Cortex>,
         physics: PhysicsSnapshot,
     ) -> Result<SnapshotMeta> {
-        let snapshot_id = now_nanos();
+        let checkpoint = Checkpoint::capture(psyche, strata, bonds, cortex);
+        self.write_checkpoint(name, &checkpoint, physics)
+    }
 
-        // Serialize arena data
-        let psyche_data = self.serialize_psyche(psyche)?;
-        let strata_data = self.serialize_strata(strata)?;
-        let bond_data = self.serialize_bonds(bonds)?;
+    /// Persist a previously captured checkpoint as a snapshot
+    ///
+    /// Does not need access to the live arenas, so callers can release
+    /// the database lock before serialization starts.
+    pub fn write_checkpoint(
+        &self,
+        name: Option<&str>,
+        checkpoint: &Checkpoint,
+        physics: PhysicsSnapshot,
+    ) -> Result<SnapshotMeta> {
+        self.writable()?;
+        let snapshot_id = checkpoint.id;
+
+        // A delta is useless without its base
+        if let Some(parent) = checkpoint.parent {
+            if !self.snapshot_meta.contains_key(parent.to_be_bytes())? {
+                return Err(AkashicError::SnapshotNotFound(parent.to_string()));
+            }
+        }
+
+        // Serialize arena data (deltas also carry tombstones)
+        let psyche_data = if checkpoint.is_delta() {
+            bincode::serialize(&DeltaPsyche {
+                lineages: checkpoint.lineages.clone(),
+                freed: checkpoint.freed.clone(),
+            })?
+        } else {
+            self.serialize_psyche(&checkpoint.lineages)?
+        };
+        let strata_data = self.serialize_strata(&checkpoint.engrams)?;
+        let bond_data = self.serialize_bonds(&checkpoint.bonds)?;
 
         // Serialize Cortex if provided
-        let cortex_data = match cortex {
-            Some(c) => Some(bincode::serialize(c)?),
+        let cortex_data = match checkpoint.cortex {
+            Some(ref c) => Some(bincode::serialize(c)?),
             None => None,
         };
 
@@ -167,10 +280,16 @@ impl AkashicStore {
             id: snapshot_id,
             name: name.map(|s| s.to_string()),
             created_at: now_secs(),
-            lineage_count: psyche.len() as u32,
-            bond_count: bonds.len() as u32,
+            lineage_count: checkpoint.lineage_count() as u32,
+            bond_count: checkpoint.bond_count() as u32,
             size_bytes: (psyche_data.len() + strata_data.len() + bond_data.len()) as u64,
             version: env!("CARGO_PKG_VERSION").to_string(),
+            checksums: Some(SectionChecksums::compute(
+                &psyche_data,
+                &strata_data,
+                &bond_data,
+                cortex_data.as_deref(),
+            )),
         };
 
         let snapshot = Snapshot {
@@ -193,23 +312,84 @@ impl AkashicStore {
         let meta_encoded = bincode::serialize(&meta)?;
         self.snapshot_meta.insert(key, meta_encoded)?;
 
+        if let Some(parent) = checkpoint.parent {
+            let link = ChainLink {
+                parent,
+                strata_depth: checkpoint.strata_depth as u32,
+            };
+            self.snapshot_parent
+                .insert(key, bincode::serialize(&link)?)?;
+        }
+        self.snapshot_keys
+            .insert(key, self.serialize_keys(&checkpoint.keys)?)?;
+
         // Update last snapshot ID
         self.update_last_snapshot(snapshot_id)?;
 
         // Flush to disk
         self.db.flush()?;
 
+        // The snapshot is durable; a failed cleanup must not undo that
+        if let Err(e) = self.apply_retention() {
+            tracing::warn!("⚠️ Snapshot retention failed: {}", e);
+        }
+
         Ok(meta)
     }
 
+    /// Delete snapshots the retention policy no longer keeps
+    ///
+    /// Returns the metadata of every pruned snapshot.
+    pub fn apply_retention(&self) -> Result<Vec<SnapshotMeta>> {
+        self.writable()?;
+        if self.is_held() {
+            return Ok(Vec::new());
+        }
+        let snapshots = self.list_snapshots()?;
+        let mut doomed: HashSet<u64> = self.config.retention.plan(&snapshots).into_iter().collect();
+        if doomed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // A surviving delta needs every ancestor down to its base
+        let links = self.chain_links()?;
+        for meta in &snapshots {
+            if doomed.contains(&meta.id) {
+                continue;
+            }
+            let mut current = meta.id;
+            while let Some(link) = links.get(&current) {
+                doomed.remove(&link.parent);
+                current = link.parent;
+            }
+        }
+
+        let mut pruned = Vec::with_capacity(doomed.len());
+        for meta in snapshots.into_iter().filter(|m| doomed.contains(&m.id)) {
+            let key = meta.id.to_be_bytes();
+            self.snapshots.remove(key)?;
+            self.snapshot_meta.remove(key)?;
+            self.snapshot_parent.remove(key)?;
+            self.snapshot_keys.remove(key)?;
+            pruned.push(meta);
+        }
+        self.db.flush()?;
+
+        Ok(pruned)
+    }
+
+    /// Active retention policy
+    pub fn retention(&self) -> &RetentionPolicy {
+        &self.config.retention
+    }
+
     /// List all available snapshots (newest first)
     pub fn list_snapshots(&self) -> Result<Vec<SnapshotMeta>> {
         let mut snapshots = Vec::new();
 
         for result in self.snapshot_meta.iter().rev() {
             let (_, value) = result?;
-            let meta: SnapshotMeta = bincode::deserialize(&value)?;
-            snapshots.push(meta);
+            snapshots.push(decode_meta(&value)?);
         }
 
         Ok(snapshots)
@@ -223,24 +403,34 @@ impl AkashicStore {
         }
 
         // Get latest metadata (fast - small data)
-        if let Some((key, _)) = self.snapshot_meta.last()? {
-            // Then load the full snapshot data using the key
-            if let Some(value) = self.snapshots.get(&key)? {
-                let snapshot: Snapshot = bincode::deserialize(&value)?;
-                return Ok(Some(snapshot));
-            }
+        match self.snapshot_meta.last()? {
+            Some((key, value)) => self.load(&key, Some(decode_meta(&value)?)),
+            None => Ok(None),
         }
-        Ok(None)
     }
 
     /// Get a snapshot by ID
     pub fn get_snapshot(&self, id: u64) -> Result<Option<Snapshot>> {
         let key = id.to_be_bytes();
-        if let Some(value) = self.snapshots.get(key)? {
-            let snapshot: Snapshot = bincode::deserialize(&value)?;
-            return Ok(Some(snapshot));
-        }
-        Ok(None)
+        let meta = match self.snapshot_meta.get(key)? {
+            Some(value) => Some(decode_meta(&value)?),
+            None => None,
+        };
+        self.load(&key, meta)
+    }
+
+    /// Decode a snapshot blob, picking the layout from its meta entry
+    fn load(&self, key: &[u8], meta: Option<SnapshotMeta>) -> Result<Option<Snapshot>> {
+        let value = match self.snapshots.get(key)? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        let snapshot = match meta {
+            Some(meta) => decode_snapshot(&value, meta.checksums.is_none())?,
+            // Orphaned blob: try both layouts
+            None => decode_snapshot(&value, false).or_else(|_| decode_snapshot(&value, true))?,
+        };
+        Ok(Some(snapshot))
     }
 
     /// Get a snapshot by name
@@ -248,7 +438,7 @@ impl AkashicStore {
         // Search through metadata
         for result in self.snapshot_meta.iter() {
             let (_key, value) = result?;
-            let meta: SnapshotMeta = bincode::deserialize(&value)?;
+            let meta = decode_meta(&value)?;
             if meta.name.as_deref() == Some(name) {
                 return self.get_snapshot(meta.id);
             }
@@ -257,16 +447,230 @@ impl AkashicStore {
     }
 
     /// Delete a snapshot by ID
+    ///
+    /// Deltas built on top of it are compacted first so they stay restorable.
     pub fn delete_snapshot(&self, id: u64) -> Result<bool> {
+        self.writable()?;
+        for child in self.children_of(id)? {
+            self.compact(child)?;
+        }
+
         let key = id.to_be_bytes();
         let removed_data = self.snapshots.remove(key)?;
         let removed_meta = self.snapshot_meta.remove(key)?;
+        self.snapshot_parent.remove(key)?;
+        self.snapshot_keys.remove(key)?;
         self.db.flush()?;
         Ok(removed_data.is_some() || removed_meta.is_some())
     }
 
+    // ═══════════════════════════════════════════════════════════════
+    // DELTA CHAINS
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Parent link of a delta snapshot (None for full snapshots)
+    pub fn parent_of(&self, id: u64) -> Result<Option<ChainLink>> {
+        match self.snapshot_parent.get(id.to_be_bytes())? {
+            Some(value) => Ok(Some(bincode::deserialize(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Number of deltas between a snapshot and its full base
+    pub fn chain_depth(&self, id: u64) -> Result<usize> {
+        let mut depth = 0;
+        let mut current = id;
+        while let Some(link) = self.parent_of(current)? {
+            depth += 1;
+            current = link.parent;
+        }
+        Ok(depth)
+    }
+
+    /// Deltas whose parent is `id`
+    fn children_of(&self, id: u64) -> Result<Vec<u64>> {
+        let mut children = Vec::new();
+        for (key, link) in self.chain_links()? {
+            if link.parent == id {
+                children.push(key);
+            }
+        }
+        Ok(children)
+    }
+
+    /// All delta links, keyed by delta ID
+    fn chain_links(&self) -> Result<HashMap<u64, ChainLink>> {
+        let mut links = HashMap::new();
+        for result in self.snapshot_parent.iter() {
+            let (key, value) = result?;
+            let mut id = [0u8; 8];
+            id.copy_from_slice(&key);
+            links.insert(u64::from_be_bytes(id), bincode::deserialize(&value)?);
+        }
+        Ok(links)
+    }
+
+    /// Fold a delta and its ancestors into a full snapshot under the same ID
+    ///
+    /// Returns the rewritten metadata, or None if `id` is already full.
+    /// Ancestors are left in place for retention to collect.
+    pub fn compact(&self, id: u64) -> Result<Option<SnapshotMeta>> {
+        self.writable()?;
+        if self.parent_of(id)?.is_none() {
+            return Ok(None);
+        }
+        let snapshot = self
+            .get_snapshot(id)?
+            .ok_or_else(|| AkashicError::SnapshotNotFound(id.to_string()))?;
+
+        let (full, keys) = self.flatten(snapshot)?;
+        self.put_full(&full, &keys)?;
+        self.db.flush()?;
+
+        Ok(Some(full.meta))
+    }
+
+    /// Copy one snapshot into another store as a self-contained full snapshot
+    ///
+    /// Deltas are resolved against their chain first; the copy keeps the
+    /// original ID and name.
+    pub fn extract_to(&self, id: u64, dest: &AkashicStore) -> Result<SnapshotMeta> {
+        dest.writable()?;
+        let snapshot = self
+            .get_snapshot(id)?
+            .ok_or_else(|| AkashicError::SnapshotNotFound(id.to_string()))?;
+
+        let (full, keys) = self.flatten(snapshot)?;
+        dest.put_full(&full, &keys)?;
+        dest.update_last_snapshot(id)?;
+        dest.db.flush()?;
+
+        Ok(full.meta)
+    }
+
+    /// Resolved lineages, engrams, bonds and keys of a snapshot
+    pub fn contents(&self, id: u64) -> Result<SnapshotContents> {
+        let snapshot = self
+            .get_snapshot(id)?
+            .ok_or_else(|| AkashicError::SnapshotNotFound(id.to_string()))?;
+        self.resolve(&snapshot)
+    }
+
+    /// Resolved contents of a loaded snapshot, following its delta chain
+    pub fn resolve(&self, snapshot: &Snapshot) -> Result<SnapshotContents> {
+        self.check(snapshot)?;
+
+        let state = self.materialize(snapshot)?;
+        Ok(SnapshotContents {
+            lineages: state.lineages.into_iter().collect(),
+            engrams: state.engrams.into_iter().collect(),
+            bonds: state.bonds,
+            keys: state.keys.into_iter().collect(),
+        })
+    }
+
+    /// Re-encode a (possibly delta) snapshot as a full one with its key table
+    fn flatten(&self, snapshot: Snapshot) -> Result<(Snapshot, Vec<(u32, String)>)> {
+        self.check(&snapshot)?;
+        let state = self.materialize(&snapshot)?;
+        let lineages: Vec<(u32, Lineage)> = state.lineages.into_iter().collect();
+        let engrams: Vec<(u32, Engram)> = state.engrams.into_iter().collect();
+        let psyche_data = self.serialize_psyche(&lineages)?;
+        let strata_data = self.serialize_strata(&engrams)?;
+        let bond_data = self.serialize_bonds(&state.bonds)?;
+
+        let meta = SnapshotMeta {
+            lineage_count: lineages.len() as u32,
+            bond_count: state.bonds.len() as u32,
+            size_bytes: (psyche_data.len() + strata_data.len() + bond_data.len()) as u64,
+            checksums: Some(SectionChecksums::compute(
+                &psyche_data,
+                &strata_data,
+                &bond_data,
+                snapshot.cortex_data.as_deref(),
+            )),
+            ..snapshot.meta
+        };
+        let full = Snapshot {
+            meta,
+            psyche_data,
+            strata_data,
+            bond_data,
+            cortex_data: snapshot.cortex_data,
+            physics_config: snapshot.physics_config,
+        };
+
+        Ok((full, state.keys.into_iter().collect()))
+    }
+
+    /// Store a full snapshot under its own ID, replacing any chain link
+    fn put_full(&self, snapshot: &Snapshot, keys: &[(u32, String)]) -> Result<()> {
+        let key = snapshot.meta.id.to_be_bytes();
+        self.snapshots.insert(key, bincode::serialize(snapshot)?)?;
+        self.snapshot_meta
+            .insert(key, bincode::serialize(&snapshot.meta)?)?;
+        self.snapshot_keys.insert(key, self.serialize_keys(keys)?)?;
+        self.snapshot_parent.remove(key)?;
+        Ok(())
+    }
+
+    /// Resolve a delta chain into the full state it describes
+    fn materialize(&self, head: &Snapshot) -> Result<Materialized> {
+        // Walk back to the full base
+        let mut ancestors = Vec::new();
+        let mut links = Vec::new();
+        let mut current = head.meta.id;
+        while let Some(link) = self.parent_of(current)? {
+            // IDs grow along the chain, so this also rules out cycles
+            if link.parent >= current {
+                return Err(AkashicError::InvalidData(format!(
+                    "delta {} points forward to {}",
+                    current, link.parent
+                )));
+            }
+            let parent = self.get_snapshot(link.parent)?.ok_or_else(|| {
+                AkashicError::InvalidData(format!(
+                    "delta {} is missing parent {}",
+                    current, link.parent
+                ))
+            })?;
+            self.check(&parent)?;
+            links.push(link);
+            ancestors.push(parent);
+            current = link.parent;
+        }
+
+        // Oldest first: the base, then every delta up to `head`
+        let layers: Vec<&Snapshot> = ancestors
+            .iter()
+            .rev()
+            .chain(std::iter::once(head))
+            .collect();
+
+        let base = layers[0];
+        let lineages: Vec<(u32, Lineage)> = bincode::deserialize(&base.psyche_data)?;
+        let bonds: Vec<Bond> = bincode::deserialize(&base.bond_data)?;
+        let mut state = Materialized {
+            lineages: lineages.into_iter().collect(),
+            engrams: self.decode_sparse(&base.strata_data)?.into_iter().collect(),
+            bonds,
+            keys: self.keys_of(base.meta.id)?.into_iter().collect(),
+        };
+
+        for (delta, link) in layers[1..].iter().zip(links.iter().rev()) {
+            let psyche: DeltaPsyche = bincode::deserialize(&delta.psyche_data)?;
+            let engrams = self.decode_sparse(&delta.strata_data)?;
+            let bonds: Vec<Bond> = bincode::deserialize(&delta.bond_data)?;
+            let keys = self.keys_of(delta.meta.id)?;
+            state.apply(psyche, engrams, bonds, keys, link.strata_depth);
+        }
+
+        Ok(state)
+    }
+
     /// Restore MindFry state from a snapshot
     ///
+    /// Delta snapshots are resolved against their chain first.
     /// Returns (PsycheArena, StrataArena, BondGraph, PhysicsSnapshot)
     pub fn restore_snapshot(
         &self,
@@ -275,30 +679,135 @@ impl AkashicStore {
         max_bonds: usize,
         strata_depth: usize,
     ) -> Result<(PsycheArena, StrataArena, BondGraph, PhysicsSnapshot)> {
+        self.check(snapshot)?;
+
+        if self.parent_of(snapshot.meta.id)?.is_some() {
+            let state = self.materialize(snapshot)?;
+            let mut psyche = PsycheArena::from_slots(max_lineages, state.lineages);
+            for (id, key) in &state.keys {
+                psyche.set_key(LineageId(*id), key);
+            }
+            let mut strata = StrataArena::with_capacity(max_lineages, strata_depth);
+            strata.restore_from_sparse(state.engrams.into_iter().collect());
+            let mut bonds = BondGraph::with_capacity(max_lineages, max_bonds);
+            for bond in state.bonds {
+                bonds.connect(bond);
+            }
+            return Ok((psyche, strata, bonds, snapshot.physics_config.clone()));
+        }
+
         // Deserialize arenas
-        let psyche = self.deserialize_psyche(&snapshot.psyche_data, max_lineages)?;
+        let mut psyche = self.deserialize_psyche(&snapshot.psyche_data, max_lineages)?;
+        for (id, key) in self.keys_of(snapshot.meta.id)? {
+            psyche.set_key(LineageId(id), &key);
+        }
         let strata = self.deserialize_strata(&snapshot.strata_data, max_lineages, strata_depth)?;
         let bonds = self.deserialize_bonds(&snapshot.bond_data, max_lineages, max_bonds)?;
 
         Ok((psyche, strata, bonds, snapshot.physics_config.clone()))
     }
 
+    // ═══════════════════════════════════════════════════════════════
+    // INTEGRITY
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Fail with `Corrupted` if any section misses its stored checksum
+    fn check(&self, snapshot: &Snapshot) -> Result<()> {
+        // Prefer the separately stored meta: it survives damage to the blob header
+        let expected = match self.snapshot_meta.get(snapshot.meta.id.to_be_bytes())? {
+            Some(value) => decode_meta(&value)?.checksums,
+            None => snapshot.meta.checksums,
+        };
+        if let Some(expected) = expected {
+            let damaged = snapshot.damaged_sections(&expected);
+            if !damaged.is_empty() {
+                return Err(AkashicError::Corrupted(snapshot.meta.id, damaged));
+            }
+        }
+        Ok(())
+    }
+
+    /// Integrity of one snapshot's own sections, ignoring its chain
+    fn section_health(&self, id: u64) -> Result<SnapshotHealth> {
+        let snapshot = match self.get_snapshot(id) {
+            Ok(Some(snapshot)) => snapshot,
+            Ok(None) => return Ok(SnapshotHealth::Unreadable("data missing".into())),
+            Err(AkashicError::Sled(e)) => return Err(AkashicError::Sled(e)),
+            Err(e) => return Ok(SnapshotHealth::Unreadable(e.to_string())),
+        };
+        Ok(match self.check(&snapshot) {
+            Ok(()) if snapshot.meta.checksums.is_none() => SnapshotHealth::Unverified,
+            Ok(()) => SnapshotHealth::Intact,
+            Err(AkashicError::Corrupted(_, sections)) => SnapshotHealth::Damaged(sections),
+            Err(e) => return Err(e),
+        })
+    }
+
+    /// Integrity of a snapshot including every ancestor of a delta
+    pub fn verify_snapshot(&self, id: u64) -> Result<SnapshotHealth> {
+        let health = self.section_health(id)?;
+        if !health.is_restorable() {
+            return Ok(health);
+        }
+
+        let mut current = id;
+        while let Some(link) = self.parent_of(current)? {
+            if link.parent >= current || !self.section_health(link.parent)?.is_restorable() {
+                return Ok(SnapshotHealth::BrokenChain(link.parent));
+            }
+            current = link.parent;
+        }
+        Ok(health)
+    }
+
+    /// Integrity of every stored snapshot (newest first)
+    pub fn verify(&self) -> Result<Vec<VerifyReport>> {
+        self.list_snapshots()?
+            .into_iter()
+            .map(|meta| {
+                Ok(VerifyReport {
+                    health: self.verify_snapshot(meta.id)?,
+                    id: meta.id,
+                    name: meta.name,
+                })
+            })
+            .collect()
+    }
+
     /// Get database size on disk
     pub fn disk_size(&self) -> Result<u64> {
         Ok(self.db.size_on_disk()?)
     }
 
+    /// Whether the store was opened read-only
+    pub fn is_read_only(&self) -> bool {
+        self.config.read_only
+    }
+
+    fn writable(&self) -> Result<()> {
+        if self.config.read_only {
+            return Err(AkashicError::ReadOnly);
+        }
+        Ok(())
+    }
+
     /// Get a reference to the lineage indexer for O(1) key lookups
     pub fn indexer(&self) -> &super::indexer::LineageIndexer {
         &self.indexer
     }
 
+    /// Get a reference to the cold tier of evicted lineages
+    pub fn cold_tier(&self) -> &super::tiering::ColdTier {
+        &self.cold_tier
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // SHUTDOWN MARKER (for crash recovery)
     // ═══════════════════════════════════════════════════════════════
 
     /// Write a graceful shutdown marker
     pub fn write_shutdown_marker(&self, marker: &crate::stability::ShutdownMarker) -> Result<()> {
+        self.writable()?;
         let meta_tree = self.db.open_tree("meta")?;
         let data = bincode::serialize(marker)?;
         meta_tree.insert("shutdown_marker", data)?;
@@ -309,6 +818,7 @@ impl AkashicStore {
     /// Read and clear the shutdown marker
     /// Returns None if no marker exists (first run or clean startup)
     pub fn read_shutdown_marker(&self) -> Result<Option<crate::stability::ShutdownMarker>> {
+        self.writable()?;
         let meta_tree = self.db.open_tree("meta")?;
         match meta_tree.get("shutdown_marker")? {
             Some(data) => {
@@ -321,30 +831,78 @@ impl AkashicStore {
         }
     }
 
+    /// Read the shutdown marker without clearing it
+    pub fn peek_shutdown_marker(&self) -> Result<Option<crate::stability::ShutdownMarker>> {
+        let meta_tree = self.db.open_tree("meta")?;
+        match meta_tree.get("shutdown_marker")? {
+            Some(data) => Ok(Some(bincode::deserialize(&data)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Persist the shutdown history (kept across restarts)
+    pub fn write_shutdown_tracker(
+        &self,
+        tracker: &crate::stability::ShutdownTracker,
+    ) -> Result<()> {
+        self.writable()?;
+        let meta_tree = self.db.open_tree("meta")?;
+        let data = bincode::serialize(tracker)?;
+        meta_tree.insert("shutdown_tracker", data)?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    /// Read the shutdown history (empty on first run)
+    pub fn read_shutdown_tracker(&self) -> Result<crate::stability::ShutdownTracker> {
+        let meta_tree = self.db.open_tree("meta")?;
+        match meta_tree.get("shutdown_tracker")? {
+            Some(data) => Ok(bincode::deserialize(&data)?),
+            None => Ok(Default::default()),
+        }
+    }
+
+    /// Verify the store accepts writes (used by readiness probes)
+    ///
+    /// Writes a heartbeat timestamp to the meta tree and flushes it.
+    pub fn probe(&self) -> Result<()> {
+        self.writable()?;
+        let meta_tree = self.db.open_tree("meta")?;
+        meta_tree.insert("probe", &now_secs().to_le_bytes())?;
+        meta_tree.flush()?;
+        Ok(())
+    }
+
+    /// Whether the store accepts writes, probing at most once per [`PROBE_INTERVAL`]
+    ///
+    /// Readiness checks may poll every second; in between probes the last
+    /// verdict is returned without touching the disk.
+    pub fn probe_throttled(&self) -> bool {
+        let mut last = self.last_probe.lock().unwrap();
+        match *last {
+            Some((at, ok)) if at.elapsed() < PROBE_INTERVAL => ok,
+            _ => {
+                let ok = self.probe().is_ok();
+                *last = Some((Instant::now(), ok));
+                ok
+            }
+        }
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // SERIALIZATION HELPERS
     // ═══════════════════════════════════════════════════════════════
 
-    fn serialize_psyche(&self, psyche: &PsycheArena) -> Result<Vec<u8>> {
-        // Collect active lineages with their IDs
-        let lineages: Vec<(u32, Lineage)> = psyche.iter().map(|(id, l)| (id.0, *l)).collect();
-
-        Ok(bincode::serialize(&lineages)?)
+    fn serialize_psyche(&self, lineages: &[(u32, Lineage)]) -> Result<Vec<u8>> {
+        // Active lineages with their IDs
+        Ok(bincode::serialize(lineages)?)
     }
 
-    fn serialize_strata(&self, strata: &StrataArena) -> Result<Vec<u8>> {
+    fn serialize_strata(&self, sparse: &[(u32, Engram)]) -> Result<Vec<u8>> {
         // SPARSE SERIALIZATION (v2 format)
-        // Only serialize engrams where timestamp != 0 (non-empty slots)
+        // Only engrams where timestamp != 0 (non-empty slots)
         // INVARIANT: timestamp == 0 means "empty slot" — this is ABI.
-        let sparse: Vec<(u32, Engram)> = strata
-            .as_slice()
-            .iter()
-            .enumerate()
-            .filter(|(_, e)| e.timestamp != 0)
-            .map(|(i, e)| (i as u32, *e))
-            .collect();
-
-        let raw = bincode::serialize(&sparse)?;
+        let raw = bincode::serialize(sparse)?;
 
         // Compress with zstd level 3 (fast)
         let compressed = zstd::encode_all(&raw[..], 3).map_err(AkashicError::Io)?;
@@ -359,22 +917,39 @@ impl AkashicStore {
         Ok(bincode::serialize(&engrams)?)
     }
 
-    fn serialize_bonds(&self, bonds: &BondGraph) -> Result<Vec<u8>> {
-        // Collect active bonds
-        let bond_list: Vec<Bond> = bonds.iter().map(|(_, b)| *b).collect();
+    fn serialize_keys(&self, keys: &[(u32, String)]) -> Result<Vec<u8>> {
+        let raw = bincode::serialize(keys)?;
+        zstd::encode_all(&raw[..], 3).map_err(AkashicError::Io)
+    }
 
-        Ok(bincode::serialize(&bond_list)?)
+    /// Key table of one snapshot (empty if written before key tables)
+    fn keys_of(&self, id: u64) -> Result<Vec<(u32, String)>> {
+        match self.snapshot_keys.get(id.to_be_bytes())? {
+            Some(value) => {
+                let raw = zstd::decode_all(&value[..]).map_err(AkashicError::Io)?;
+                Ok(bincode::deserialize(&raw)?)
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn serialize_bonds(&self, bonds: &[Bond]) -> Result<Vec<u8>> {
+        // Active bonds
+        Ok(bincode::serialize(bonds)?)
     }
 
     fn deserialize_psyche(&self, data: &[u8], capacity: usize) -> Result<PsycheArena> {
         let lineages: Vec<(u32, Lineage)> = bincode::deserialize(data)?;
 
-        let mut arena = PsycheArena::with_capacity(capacity);
-        for (_id, lineage) in lineages {
-            arena.alloc(lineage);
-        }
+        // Lineages go back to their original slots so bonds and engram
+        // windows keep pointing at the right place
+        Ok(PsycheArena::from_slots(capacity, lineages))
+    }
 
-        Ok(arena)
+    /// Decode a v2 sparse strata payload without building an arena
+    fn decode_sparse(&self, data: &[u8]) -> Result<Vec<(u32, Engram)>> {
+        let decompressed = zstd::decode_all(data).map_err(AkashicError::Io)?;
+        Ok(bincode::deserialize(&decompressed)?)
     }
 
     fn deserialize_strata(
@@ -384,10 +959,7 @@ impl AkashicStore {
         depth: usize,
     ) -> Result<StrataArena> {
         // v2 format: zstd compressed sparse Vec<(u32, Engram)>
-        // Decompress first
-        let decompressed = zstd::decode_all(data).map_err(AkashicError::Io)?;
-
-        let sparse: Vec<(u32, Engram)> = bincode::deserialize(&decompressed)?;
+        let sparse = self.decode_sparse(data)?;
 
         // Create arena with empty slots
         let mut arena = StrataArena::with_capacity(max_lineages, depth);
@@ -447,24 +1019,66 @@ impl AkashicStore {
     }
 }
 
+/// Fully resolved state of a delta chain
+struct Materialized {
+    lineages: BTreeMap<u32, Lineage>,
+    engrams: BTreeMap<u32, Engram>,
+    bonds: Vec<Bond>,
+    keys: BTreeMap<u32, String>,
+}
+
+impl Materialized {
+    /// Layer one delta on top of the state below it
+    fn apply(
+        &mut self,
+        psyche: DeltaPsyche,
+        engrams: Vec<(u32, Engram)>,
+        bonds: Vec<Bond>,
+        keys: Vec<(u32, String)>,
+        strata_depth: u32,
+    ) {
+        let touched: HashSet<u32> = psyche
+            .lineages
+            .iter()
+            .map(|(id, _)| *id)
+            .chain(psyche.freed.iter().copied())
+            .collect();
+
+        for id in &touched {
+            self.keys.remove(id);
+        }
+        self.keys.extend(keys);
+        for id in &psyche.freed {
+            self.lineages.remove(id);
+        }
+        self.lineages.extend(psyche.lineages);
+
+        // The delta carries the complete window of every touched lineage
+        for &id in &touched {
+            let start = id.saturating_mul(strata_depth);
+            let end = id.saturating_add(1).saturating_mul(strata_depth);
+            let stale: Vec<u32> = self.engrams.range(start..end).map(|(k, _)| *k).collect();
+            for key in stale {
+                self.engrams.remove(&key);
+            }
+        }
+        self.engrams.extend(engrams);
+
+        // ...and every bond with a touched endpoint
+        self.bonds
+            .retain(|b| !touched.contains(&b.source.0) && !touched.contains(&b.target.0));
+        self.bonds.extend(bonds);
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════
 // TIME UTILITIES
 // ═══════════════════════════════════════════════════════════════
 
+/// Calendar time for `created_at`, so retention follows the injected clock
 #[inline]
 fn now_secs() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_secs())
-        .unwrap_or(0)
-}
-
-#[inline]
-fn now_nanos() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_nanos() as u64)
-        .unwrap_or(0)
+    crate::clock::now_secs()
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -485,6 +1099,8 @@ mod tests {
             path: dir.path().to_string_lossy().to_string(),
             sync_writes: true,
             cache_size: 1024 * 1024,
+            retention: RetentionPolicy::keep_all(),
+            read_only: false,
         }
     }
 
@@ -623,6 +1239,87 @@ mod tests {
         assert!(store.get_snapshot(meta.id).unwrap().is_none());
     }
 
+    #[test]
+    fn test_retention_after_snapshot() {
+        let store = AkashicStore::open(AkashicConfig {
+            retention: RetentionPolicy {
+                keep_last: 2,
+                keep_daily: 0,
+                keep_weekly: 0,
+                max_total_bytes: None,
+            },
+            ..temp_config()
+        })
+        .unwrap();
+
+        let psyche = PsycheArena::with_capacity(10);
+        let strata = StrataArena::with_capacity(10, 4);
+        let bonds = BondGraph::with_capacity(10, 100);
+
+        for name in [None, Some("keeper"), None, Some("pre-shutdown"), None] {
+            store
+                .take_snapshot(
+                    name,
+                    &psyche,
+                    &strata,
+                    &bonds,
+                    None,
+                    PhysicsSnapshot::default(),
+                )
+                .unwrap();
+        }
+
+        // Two newest automatic snapshots plus the named one
+        let list = store.list_snapshots().unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0].name, None);
+        assert_eq!(list[1].name.as_deref(), Some("pre-shutdown"));
+        assert_eq!(list[2].name.as_deref(), Some("keeper"));
+
+        // A held store keeps everything until released
+        store.hold();
+        for _ in 0..3 {
+            store
+                .take_snapshot(
+                    None,
+                    &psyche,
+                    &strata,
+                    &bonds,
+                    None,
+                    PhysicsSnapshot::default(),
+                )
+                .unwrap();
+        }
+        assert_eq!(store.list_snapshots().unwrap().len(), 6);
+        store.release();
+        store.apply_retention().unwrap();
+        assert_eq!(store.list_snapshots().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_shutdown_tracker_roundtrip() {
+        use crate::stability::{ShutdownReason, ShutdownTracker};
+
+        let store = AkashicStore::open(temp_config()).unwrap();
+        assert!(store.read_shutdown_tracker().unwrap().last_reason.is_none());
+
+        let mut tracker = ShutdownTracker::default();
+        tracker.record(
+            ShutdownReason::Crash {
+                message: "boom".into(),
+            },
+            42,
+        );
+        store.write_shutdown_tracker(&tracker).unwrap();
+
+        // History survives reads (unlike the shutdown marker)
+        for _ in 0..2 {
+            let read = store.read_shutdown_tracker().unwrap();
+            assert!(read.was_traumatic());
+            assert_eq!(read.last_timestamp, Some(42));
+        }
+    }
+
     #[test]
     fn test_sparse_strata_size_reduction() {
         // Create a large empty strata arena (simulates 1M lineage capacity)
@@ -635,7 +1332,7 @@ mod tests {
         let config = temp_config();
         let store = AkashicStore::open(config).unwrap();
 
-        let serialized = store.serialize_strata(&strata).unwrap();
+        let serialized = store.serialize_strata(&strata.to_sparse()).unwrap();
 
         // Assert massive reduction: v2 should be < 1% of v1
         assert!(
@@ -649,4 +1346,446 @@ mod tests {
         let restored = store.deserialize_strata(&serialized, 10_000, 64).unwrap();
         assert_eq!(restored.as_slice().len(), strata.as_slice().len());
     }
+
+    /// Assert a restored state matches the live one slot by slot
+    fn assert_same_state(
+        live: (&PsycheArena, &StrataArena, &BondGraph),
+        restored: (&PsycheArena, &StrataArena, &BondGraph),
+    ) {
+        assert_eq!(live.0.len(), restored.0.len());
+        assert_eq!(live.2.len(), restored.2.len());
+        for i in 0..8 {
+            let id = LineageId(i);
+            assert_eq!(
+                live.0.get(id).map(|l| l.energy),
+                restored.0.get(id).map(|l| l.energy),
+                "lineage {}",
+                i
+            );
+            let window = |s: &StrataArena| -> Vec<(u32, u64)> {
+                s.window_sparse(id)
+                    .into_iter()
+                    .map(|(k, e)| (k, e.timestamp))
+                    .collect()
+            };
+            assert_eq!(window(live.1), window(restored.1), "engrams of {}", i);
+        }
+        for (_, bond) in live.2.iter() {
+            let found = restored.2.find_bond(bond.source, bond.target);
+            assert!(found.is_some(), "bond {:?}", (bond.source, bond.target));
+        }
+    }
+
+    #[test]
+    fn test_delta_chain_restore_and_compact() {
+        let store = AkashicStore::open(temp_config()).unwrap();
+
+        let mut psyche = PsycheArena::with_capacity(16);
+        let mut strata = StrataArena::with_capacity(16, 4);
+        let mut bonds = BondGraph::with_capacity(16, 100);
+        let a = psyche.alloc(Lineage::new(0.9));
+        let b = psyche.alloc(Lineage::new(0.7));
+        let c = psyche.alloc(Lineage::new(0.5));
+        strata.record(a, u32::MAX, Engram::new(1, 0.1));
+        let head = strata.record(c, u32::MAX, Engram::new(2, 0.2));
+        bonds.connect(Bond::new(a, b, 0.8));
+        bonds.connect(Bond::new(b, c, 0.6));
+
+        let base = Checkpoint::capture(&psyche, &strata, &bonds, None);
+        psyche.clear_dirty();
+        let base = store
+            .write_checkpoint(None, &base, PhysicsSnapshot::default())
+            .unwrap();
+
+        // Free b (with its bonds), recycle its slot, touch c
+        for id in bonds.neighbors(b).to_vec() {
+            bonds.disconnect(id);
+        }
+        psyche.free(b);
+        let b2 = psyche.alloc(Lineage::new(0.3));
+        let d = psyche.alloc(Lineage::new(0.4));
+        psyche.get_mut(c).unwrap().stimulate(0.2);
+        strata.record(c, head, Engram::new(3, 0.3));
+        bonds.connect(Bond::new(b2, d, 0.5));
+        psyche.mark_dirty(b2);
+        psyche.mark_dirty(d);
+
+        let delta = Checkpoint::capture_delta(base.id, &psyche, &strata, &bonds, None);
+        psyche.clear_dirty();
+        assert!(delta.is_delta());
+        // Untouched a is not part of the delta
+        assert!(delta.lineage_count() < psyche.len());
+        let delta = store
+            .write_checkpoint(None, &delta, PhysicsSnapshot::default())
+            .unwrap();
+        assert_eq!(store.parent_of(delta.id).unwrap().unwrap().parent, base.id);
+        assert_eq!(store.chain_depth(delta.id).unwrap(), 1);
+
+        let snapshot = store.get_snapshot(delta.id).unwrap().unwrap();
+        let (p, s, g, _) = store.restore_snapshot(&snapshot, 16, 100, 4).unwrap();
+        assert_same_state((&psyche, &strata, &bonds), (&p, &s, &g));
+
+        // Compaction rewrites the delta as a full base under the same ID
+        let compacted = store.compact(delta.id).unwrap().unwrap();
+        assert_eq!(compacted.id, delta.id);
+        assert_eq!(compacted.lineage_count as usize, psyche.len());
+        assert_eq!(store.chain_depth(delta.id).unwrap(), 0);
+        assert!(store.compact(delta.id).unwrap().is_none());
+        let snapshot = store.get_snapshot(delta.id).unwrap().unwrap();
+        let (p, s, g, _) = store.restore_snapshot(&snapshot, 16, 100, 4).unwrap();
+        assert_same_state((&psyche, &strata, &bonds), (&p, &s, &g));
+
+        // Deleting a parent compacts its children first
+        psyche.get_mut(a).unwrap().stimulate(0.05);
+        let next = Checkpoint::capture_delta(delta.id, &psyche, &strata, &bonds, None);
+        let next = store
+            .write_checkpoint(None, &next, PhysicsSnapshot::default())
+            .unwrap();
+        assert!(store.delete_snapshot(delta.id).unwrap());
+        assert!(store.parent_of(next.id).unwrap().is_none());
+        let snapshot = store.get_snapshot(next.id).unwrap().unwrap();
+        let (p, s, g, _) = store.restore_snapshot(&snapshot, 16, 100, 4).unwrap();
+        assert_same_state((&psyche, &strata, &bonds), (&p, &s, &g));
+
+        // A delta on a missing base is refused
+        let orphan = Checkpoint::capture_delta(delta.id, &psyche, &strata, &bonds, None);
+        assert!(store
+            .write_checkpoint(None, &orphan, PhysicsSnapshot::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_retention_keeps_delta_ancestors() {
+        let store = AkashicStore::open(AkashicConfig {
+            retention: RetentionPolicy {
+                keep_last: 1,
+                keep_daily: 0,
+                keep_weekly: 0,
+                max_total_bytes: None,
+            },
+            ..temp_config()
+        })
+        .unwrap();
+
+        let mut psyche = PsycheArena::with_capacity(10);
+        let strata = StrataArena::with_capacity(10, 4);
+        let bonds = BondGraph::with_capacity(10, 100);
+        let id = psyche.alloc(Lineage::new(0.5));
+
+        let base = Checkpoint::capture(&psyche, &strata, &bonds, None);
+        let base = store
+            .write_checkpoint(None, &base, PhysicsSnapshot::default())
+            .unwrap();
+        psyche.mark_dirty(id);
+        let delta = Checkpoint::capture_delta(base.id, &psyche, &strata, &bonds, None);
+        let delta = store
+            .write_checkpoint(None, &delta, PhysicsSnapshot::default())
+            .unwrap();
+
+        // keep_last = 1 would drop the base, but the delta still needs it
+        let ids: Vec<u64> = store
+            .list_snapshots()
+            .unwrap()
+            .iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, vec![delta.id, base.id]);
+
+        // Once compacted, the old base is fair game
+        store.compact(delta.id).unwrap();
+        store.apply_retention().unwrap();
+        let ids: Vec<u64> = store
+            .list_snapshots()
+            .unwrap()
+            .iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, vec![delta.id]);
+    }
+
+    /// Rewrite a stored blob in place, bypassing checksums
+    fn tamper(store: &AkashicStore, id: u64, f: impl FnOnce(&mut Snapshot)) {
+        let mut snapshot = store.get_snapshot(id).unwrap().unwrap();
+        f(&mut snapshot);
+        store
+            .snapshots
+            .insert(id.to_be_bytes(), bincode::serialize(&snapshot).unwrap())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_verify_reports_damage() {
+        let store = AkashicStore::open(temp_config()).unwrap();
+
+        let mut psyche = PsycheArena::with_capacity(10);
+        let strata = StrataArena::with_capacity(10, 4);
+        let mut bonds = BondGraph::with_capacity(10, 100);
+        let a = psyche.alloc(Lineage::new(0.5));
+        let b = psyche.alloc(Lineage::new(0.6));
+        bonds.connect(Bond::new(a, b, 0.7));
+
+        let good = store
+            .write_checkpoint(
+                Some("good"),
+                &Checkpoint::capture(&psyche, &strata, &bonds, None),
+                PhysicsSnapshot::default(),
+            )
+            .unwrap();
+        let bad = store
+            .write_checkpoint(
+                Some("bad"),
+                &Checkpoint::capture(&psyche, &strata, &bonds, None),
+                PhysicsSnapshot::default(),
+            )
+            .unwrap();
+        psyche.mark_dirty(a);
+        let delta = store
+            .write_checkpoint(
+                None,
+                &Checkpoint::capture_delta(bad.id, &psyche, &strata, &bonds, None),
+                PhysicsSnapshot::default(),
+            )
+            .unwrap();
+
+        tamper(&store, bad.id, |s| s.bond_data[0] ^= 0xFF);
+
+        let reports = store.verify().unwrap();
+        let health: Vec<(u64, SnapshotHealth)> =
+            reports.into_iter().map(|r| (r.id, r.health)).collect();
+        assert_eq!(
+            health,
+            vec![
+                (delta.id, SnapshotHealth::BrokenChain(bad.id)),
+                (
+                    bad.id,
+                    SnapshotHealth::Damaged(vec![SnapshotSection::Bonds])
+                ),
+                (good.id, SnapshotHealth::Intact),
+            ]
+        );
+
+        // Restoring damaged data fails loudly instead of decoding garbage
+        let snapshot = store.get_snapshot(bad.id).unwrap().unwrap();
+        assert!(matches!(
+            store.restore_snapshot(&snapshot, 10, 100, 4),
+            Err(AkashicError::Corrupted(id, _)) if id == bad.id
+        ));
+        let snapshot = store.get_snapshot(delta.id).unwrap().unwrap();
+        assert!(store.restore_snapshot(&snapshot, 10, 100, 4).is_err());
+    }
+
+    #[test]
+    fn test_resurrect_walks_back_past_damage() {
+        use std::sync::Arc;
+
+        let store = Arc::new(AkashicStore::open(temp_config()).unwrap());
+        let mut db = crate::MindFry::with_config(crate::MindFryConfig {
+            max_lineages: 16,
+            max_bonds: 16,
+            strata_depth: 64,
+            ..Default::default()
+        })
+        .with_store(Arc::clone(&store));
+
+        db.psyche.alloc(Lineage::new(0.5));
+        let older = db.checkpoint();
+        store
+            .write_checkpoint(None, &older, PhysicsSnapshot::default())
+            .unwrap();
+        db.psyche.alloc(Lineage::new(0.6));
+        let newer = db.checkpoint();
+        store
+            .write_checkpoint(None, &newer, PhysicsSnapshot::default())
+            .unwrap();
+
+        tamper(&store, newer.id(), |s| s.psyche_data.push(0));
+
+        assert!(db.resurrect().unwrap());
+        assert_eq!(db.psyche.len(), 1);
+        assert_eq!(db.last_checkpoint(), Some(older.id()));
+
+        // Nothing intact left: genesis instead of an error
+        tamper(&store, older.id(), |s| s.strata_data.clear());
+        assert!(!db.resurrect().unwrap());
+    }
+
+    #[test]
+    fn test_legacy_snapshot_without_checksums() {
+        /// Meta layout written by 1.9.0
+        #[derive(Serialize)]
+        struct LegacyMeta {
+            id: u64,
+            name: Option<String>,
+            created_at: u64,
+            lineage_count: u32,
+            bond_count: u32,
+            size_bytes: u64,
+            version: String,
+        }
+        #[derive(Serialize)]
+        struct LegacySnapshot {
+            meta: LegacyMeta,
+            psyche_data: Vec<u8>,
+            strata_data: Vec<u8>,
+            bond_data: Vec<u8>,
+            cortex_data: Option<Vec<u8>>,
+            physics_config: PhysicsSnapshot,
+        }
+
+        let store = AkashicStore::open(temp_config()).unwrap();
+        let meta = || LegacyMeta {
+            id: 7,
+            name: Some("old".into()),
+            created_at: 1,
+            lineage_count: 1,
+            bond_count: 0,
+            size_bytes: 0,
+            version: "1.9.0".into(),
+        };
+        let legacy = LegacySnapshot {
+            meta: meta(),
+            psyche_data: bincode::serialize(&vec![(3u32, Lineage::new(0.4))]).unwrap(),
+            strata_data: store.serialize_strata(&[]).unwrap(),
+            bond_data: bincode::serialize(&Vec::<Bond>::new()).unwrap(),
+            cortex_data: None,
+            physics_config: PhysicsSnapshot::default(),
+        };
+        let key = 7u64.to_be_bytes();
+        store
+            .snapshots
+            .insert(key, bincode::serialize(&legacy).unwrap())
+            .unwrap();
+        store
+            .snapshot_meta
+            .insert(key, bincode::serialize(&meta()).unwrap())
+            .unwrap();
+
+        assert_eq!(store.list_snapshots().unwrap()[0].checksums, None);
+        assert_eq!(
+            store.verify_snapshot(7).unwrap(),
+            SnapshotHealth::Unverified
+        );
+        let snapshot = store.get_snapshot_by_name("old").unwrap().unwrap();
+        let (psyche, _, _, _) = store.restore_snapshot(&snapshot, 10, 100, 4).unwrap();
+        assert!(psyche.get(LineageId(3)).is_some());
+    }
+
+    #[test]
+    fn test_key_tables_follow_chain_and_extract() {
+        let store = AkashicStore::open(temp_config()).unwrap();
+
+        let mut psyche = PsycheArena::with_capacity(16);
+        let strata = StrataArena::with_capacity(16, 4);
+        let bonds = BondGraph::with_capacity(16, 100);
+        let a = psyche.alloc_named("alpha", Lineage::new(0.9));
+        let b = psyche.alloc_named("beta", Lineage::new(0.7));
+
+        let base = Checkpoint::capture(&psyche, &strata, &bonds, None);
+        psyche.clear_dirty();
+        let base = store
+            .write_checkpoint(None, &base, PhysicsSnapshot::default())
+            .unwrap();
+
+        // Recycle beta's slot under a new key
+        psyche.free(b);
+        let c = psyche.alloc_named("gamma", Lineage::new(0.5));
+        assert_eq!(b, c);
+        let delta = Checkpoint::capture_delta(base.id, &psyche, &strata, &bonds, None);
+        let delta = store
+            .write_checkpoint(None, &delta, PhysicsSnapshot::default())
+            .unwrap();
+
+        let snapshot = store.get_snapshot(delta.id).unwrap().unwrap();
+        let (p, _, _, _) = store.restore_snapshot(&snapshot, 16, 100, 4).unwrap();
+        assert_eq!(p.lookup(crate::arena::key_hash("alpha")), Some(a));
+        assert_eq!(p.lookup(crate::arena::key_hash("gamma")), Some(c));
+        assert_eq!(p.lookup(crate::arena::key_hash("beta")), None);
+        assert_eq!(store.contents(delta.id).unwrap().keys.len(), 2);
+
+        // Extraction resolves the chain into a standalone full snapshot
+        let dest = AkashicStore::open(temp_config()).unwrap();
+        let meta = store.extract_to(delta.id, &dest).unwrap();
+        assert_eq!(meta.id, delta.id);
+        assert_eq!(dest.list_snapshots().unwrap().len(), 1);
+        assert!(dest.parent_of(delta.id).unwrap().is_none());
+        let snapshot = dest.latest_snapshot().unwrap().unwrap();
+        let (p, _, _, _) = dest.restore_snapshot(&snapshot, 16, 100, 4).unwrap();
+        assert_eq!(p.key_of(c), Some("gamma"));
+        assert_eq!(p.len(), 2);
+    }
+
+    #[test]
+    fn test_read_only_store_refuses_writes() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("store").to_string_lossy().into_owned();
+        let read_only = |path: &str| {
+            AkashicStore::open(AkashicConfig {
+                path: path.to_string(),
+                read_only: true,
+                ..Default::default()
+            })
+        };
+
+        // Never created on demand
+        assert!(read_only(&path).is_err());
+
+        {
+            let store = AkashicStore::open(AkashicConfig {
+                path: path.clone(),
+                ..Default::default()
+            })
+            .unwrap();
+            let psyche = PsycheArena::with_capacity(4);
+            let strata = StrataArena::with_capacity(4, 2);
+            let bonds = BondGraph::with_capacity(4, 4);
+            store
+                .take_snapshot(
+                    Some("kept"),
+                    &psyche,
+                    &strata,
+                    &bonds,
+                    None,
+                    Default::default(),
+                )
+                .unwrap();
+            store
+                .write_shutdown_marker(&crate::stability::ShutdownMarker::graceful())
+                .unwrap();
+        }
+
+        let store = read_only(&path).unwrap();
+        assert!(store.is_read_only());
+        assert_eq!(store.list_snapshots().unwrap().len(), 1);
+        let id = store.list_snapshots().unwrap()[0].id;
+        assert!(matches!(
+            store.delete_snapshot(id),
+            Err(AkashicError::ReadOnly)
+        ));
+        assert!(matches!(
+            store.read_shutdown_marker(),
+            Err(AkashicError::ReadOnly)
+        ));
+        // Peeking leaves the marker for the server
+        assert!(store.peek_shutdown_marker().unwrap().unwrap().graceful);
+        assert!(store.peek_shutdown_marker().unwrap().is_some());
+        assert!(matches!(store.probe(), Err(AkashicError::ReadOnly)));
+    }
+
+    #[test]
+    fn test_probe_throttled_writes_once_per_interval() {
+        let dir = tempdir().unwrap();
+        let store = AkashicStore::open(AkashicConfig {
+            path: dir.path().to_string_lossy().into_owned(),
+            ..Default::default()
+        })
+        .unwrap();
+        let meta = store.db.open_tree("meta").unwrap();
+
+        assert!(store.probe_throttled());
+        assert!(meta.remove("probe").unwrap().is_some());
+
+        // Within the interval the verdict is reused, nothing is written
+        assert!(store.probe_throttled());
+        assert!(meta.get("probe").unwrap().is_none());
+    }
 }
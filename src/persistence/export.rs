@@ -0,0 +1,644 @@
+//! Portable Export - Versioned NDJSON dumps
+//!
+//! Snapshots are bincode blobs tied to the in-memory layout. An export is
+//! one JSON record per line instead, readable by any tool and stable
+//! across versions:
+//!
+//! ```text
+//! {"type":"header","format":"mindfry-ndjson","version":1,"mindfry":"1.9.0","strata_depth":64,"exported_at":1700000000}
+//! {"type":"lineage","id":0,"key":"alpha","energy":0.9,"threshold":0.5,"decay_rate":0.001,"rigidity":0.5,"last_access":0,"flags":1,"decay_lag":0}
+//! {"type":"bond","source":"alpha","target":3,"strength":0.8,"cost":0.1,"decay_rate":0.0005,"last_access":0,"flags":1,"polarity":1}
+//! {"type":"engram","lineage":"alpha","timestamp":1,"stimulation":0.3,"payload_id":null,"source_id":0}
+//! {"type":"cortex","state":{...}}
+//! ```
+//!
+//! Bonds and engrams refer to lineages by key when one is known and by
+//! slot ID otherwise. On import, lineages without an `id` take the lowest
+//! free slot, so data from other systems only needs keys. Engrams are
+//! listed oldest first and re-recorded in that order.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::arena::{Engram, Lineage, LineageFlags, LineageId, PsycheArena, StrataArena};
+use crate::graph::{Bond, BondFlags, BondGraph};
+use crate::setun::{Cortex, Trit};
+
+/// Value of the header's `format` field
+pub const EXPORT_FORMAT: &str = "mindfry-ndjson";
+
+/// Newest export version this build reads and the one it writes
+pub const EXPORT_VERSION: u32 = 1;
+
+/// A lineage reference: string key or slot ID
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum LineageRef {
+    Id(u32),
+    Key(String),
+}
+
+/// One NDJSON line
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Record {
+    Header {
+        format: String,
+        version: u32,
+        mindfry: String,
+        strata_depth: u32,
+        exported_at: u64,
+    },
+    Lineage {
+        #[serde(default)]
+        id: Option<u32>,
+        #[serde(default)]
+        key: Option<String>,
+        energy: f32,
+        threshold: f32,
+        decay_rate: f32,
+        rigidity: f32,
+        last_access: u64,
+        flags: u32,
+        #[serde(default)]
+        decay_lag: u16,
+    },
+    Bond {
+        source: LineageRef,
+        target: LineageRef,
+        strength: f32,
+        cost: f32,
+        decay_rate: f32,
+        last_access: u64,
+        flags: u32,
+        polarity: i8,
+    },
+    Engram {
+        lineage: LineageRef,
+        timestamp: u64,
+        stimulation: f32,
+        #[serde(default)]
+        payload_id: Option<u32>,
+        #[serde(default)]
+        source_id: u32,
+    },
+    Cortex {
+        state: Cortex,
+    },
+}
+
+/// Record counts of an export or import
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportSummary {
+    /// Lineages written or rebuilt
+    pub lineages: u32,
+    /// Bonds written or rebuilt
+    pub bonds: u32,
+    /// Engrams written or re-recorded
+    pub engrams: u32,
+}
+
+/// Why an import was rejected
+#[derive(Debug)]
+pub enum ImportError {
+    /// Reading the input failed
+    Io(io::Error),
+    /// A line is not a valid record
+    Parse {
+        /// 1-based line number
+        line: usize,
+        /// Parser message
+        message: String,
+    },
+    /// A record is well-formed but inconsistent
+    Invalid {
+        /// 1-based line number
+        line: usize,
+        /// What is wrong
+        message: String,
+    },
+    /// The first record is not a supported header
+    BadHeader(String),
+}
+
+impl std::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "IO error: {}", e),
+            Self::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            Self::Invalid { line, message } => write!(f, "line {}: {}", line, message),
+            Self::BadHeader(msg) => write!(f, "Bad header: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+impl From<io::Error> for ImportError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Arenas rebuilt by [`import_ndjson`]
+pub struct Imported {
+    /// Lineages, with their keys
+    pub psyche: PsycheArena,
+    /// Re-recorded engrams
+    pub strata: StrataArena,
+    /// Bonds
+    pub bonds: BondGraph,
+    /// Cortex state, if the export had one
+    pub cortex: Option<Cortex>,
+    /// What was imported
+    pub summary: ExportSummary,
+}
+
+// ═══════════════════════════════════════════════════════════════
+// EXPORT
+// ═══════════════════════════════════════════════════════════════
+
+/// Stream the given state as NDJSON
+pub fn export_ndjson<W: Write>(
+    mut out: W,
+    psyche: &PsycheArena,
+    strata: &StrataArena,
+    bonds: &BondGraph,
+    cortex: Option<&Cortex>,
+) -> io::Result<ExportSummary> {
+    let mut summary = ExportSummary::default();
+    let reference = |id: LineageId| match psyche.key_of(id) {
+        Some(key) => LineageRef::Key(key.to_string()),
+        None => LineageRef::Id(id.0),
+    };
+
+    write_record(
+        &mut out,
+        &Record::Header {
+            format: EXPORT_FORMAT.to_string(),
+            version: EXPORT_VERSION,
+            mindfry: env!("CARGO_PKG_VERSION").to_string(),
+            strata_depth: strata.depth() as u32,
+            exported_at: crate::clock::now_secs(),
+        },
+    )?;
+
+    for (id, lineage) in psyche.iter() {
+        write_record(
+            &mut out,
+            &Record::Lineage {
+                id: Some(id.0),
+                key: psyche.key_of(id).map(String::from),
+                energy: lineage.energy,
+                threshold: lineage.threshold,
+                decay_rate: lineage.decay_rate,
+                rigidity: lineage.rigidity,
+                last_access: lineage.last_access,
+                // Persistence bookkeeping is not part of the data
+                flags: (lineage.flags - LineageFlags::DIRTY).bits().into(),
+                decay_lag: lineage.decay_lag,
+            },
+        )?;
+        summary.lineages += 1;
+    }
+
+    for (_, bond) in bonds.iter() {
+        write_record(
+            &mut out,
+            &Record::Bond {
+                source: reference(bond.source),
+                target: reference(bond.target),
+                strength: bond.strength,
+                cost: bond.cost,
+                decay_rate: bond.decay_rate,
+                last_access: bond.last_access,
+                flags: bond.flags.bits(),
+                polarity: bond.polarity.weight(),
+            },
+        )?;
+        summary.bonds += 1;
+    }
+
+    for (id, _) in psyche.iter() {
+        let mut window = strata.window_sparse(id);
+        window.sort_by_key(|(_, engram)| engram.timestamp);
+        for (_, engram) in window {
+            write_record(
+                &mut out,
+                &Record::Engram {
+                    lineage: reference(id),
+                    timestamp: engram.timestamp,
+                    stimulation: engram.stimulation,
+                    payload_id: (engram.payload_id != u32::MAX).then_some(engram.payload_id),
+                    source_id: engram.source_id,
+                },
+            )?;
+            summary.engrams += 1;
+        }
+    }
+
+    if let Some(cortex) = cortex {
+        write_record(
+            &mut out,
+            &Record::Cortex {
+                state: cortex.clone(),
+            },
+        )?;
+    }
+
+    out.flush()?;
+    Ok(summary)
+}
+
+fn write_record<W: Write>(out: &mut W, record: &Record) -> io::Result<()> {
+    serde_json::to_writer(&mut *out, record)?;
+    out.write_all(b"\n")
+}
+
+// ═══════════════════════════════════════════════════════════════
+// IMPORT
+// ═══════════════════════════════════════════════════════════════
+
+/// Validate an NDJSON export and rebuild arenas from it
+///
+/// Nothing is returned unless the whole input is valid.
+pub fn import_ndjson<R: BufRead>(
+    input: R,
+    max_lineages: usize,
+    max_bonds: usize,
+    strata_depth: usize,
+) -> Result<Imported, ImportError> {
+    let mut header_seen = false;
+    let mut lineages = Vec::new();
+    let mut bond_records = Vec::new();
+    let mut engram_records = Vec::new();
+    let mut cortex = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let line_no = index + 1;
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: Record = serde_json::from_str(&line).map_err(|e| ImportError::Parse {
+            line: line_no,
+            message: e.to_string(),
+        })?;
+
+        match record {
+            Record::Header {
+                format, version, ..
+            } => {
+                if header_seen {
+                    return Err(invalid(line_no, "duplicate header"));
+                }
+                if format != EXPORT_FORMAT {
+                    return Err(ImportError::BadHeader(format!(
+                        "unknown format '{}'",
+                        format
+                    )));
+                }
+                if version > EXPORT_VERSION {
+                    return Err(ImportError::BadHeader(format!(
+                        "version {} is newer than supported {}",
+                        version, EXPORT_VERSION
+                    )));
+                }
+                header_seen = true;
+            }
+            _ if !header_seen => {
+                return Err(ImportError::BadHeader(
+                    "first record must be the header".into(),
+                ));
+            }
+            Record::Lineage { .. } => lineages.push((line_no, record)),
+            Record::Bond { .. } => bond_records.push((line_no, record)),
+            Record::Engram { .. } => engram_records.push((line_no, record)),
+            Record::Cortex { state } => {
+                if cortex.replace(state).is_some() {
+                    return Err(invalid(line_no, "duplicate cortex record"));
+                }
+            }
+        }
+    }
+    if !header_seen {
+        return Err(ImportError::BadHeader("empty input".into()));
+    }
+
+    // ── Lineages: explicit slots first, then the lowest free ones ──
+    let mut slots: HashMap<u32, Lineage> = HashMap::new();
+    let mut keyed: HashMap<String, u32> = HashMap::new();
+    let mut unplaced = Vec::new();
+    for (line_no, record) in lineages {
+        let Record::Lineage {
+            id,
+            key,
+            energy,
+            threshold,
+            decay_rate,
+            rigidity,
+            last_access,
+            flags,
+            decay_lag,
+        } = record
+        else {
+            unreachable!()
+        };
+        for (name, value) in [
+            ("energy", energy),
+            ("threshold", threshold),
+            ("rigidity", rigidity),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(invalid(
+                    line_no,
+                    &format!("{} {} outside 0..=1", name, value),
+                ));
+            }
+        }
+        if !decay_rate.is_finite() || decay_rate < 0.0 {
+            return Err(invalid(line_no, &format!("bad decay_rate {}", decay_rate)));
+        }
+
+        let lineage = Lineage {
+            energy,
+            threshold,
+            decay_rate,
+            rigidity,
+            last_access,
+            // Bits past the flag width are unknown flags
+            flags: LineageFlags::from_bits_truncate(flags as u16) | LineageFlags::ACTIVE,
+            decay_lag,
+            head_index: u32::MAX,
+        };
+        match id {
+            Some(id) => {
+                if id as usize >= max_lineages {
+                    return Err(invalid(line_no, &format!("id {} exceeds capacity", id)));
+                }
+                if slots.insert(id, lineage).is_some() {
+                    return Err(invalid(line_no, &format!("duplicate id {}", id)));
+                }
+                if let Some(key) = key {
+                    if keyed.insert(key.clone(), id).is_some() {
+                        return Err(invalid(line_no, &format!("duplicate key '{}'", key)));
+                    }
+                }
+            }
+            None => unplaced.push((line_no, key, lineage)),
+        }
+    }
+    let mut next_free = 0u32;
+    for (line_no, key, lineage) in unplaced {
+        while slots.contains_key(&next_free) {
+            next_free += 1;
+        }
+        if next_free as usize >= max_lineages {
+            return Err(invalid(line_no, "lineage capacity exceeded"));
+        }
+        slots.insert(next_free, lineage);
+        if let Some(key) = key {
+            if keyed.insert(key.clone(), next_free).is_some() {
+                return Err(invalid(line_no, &format!("duplicate key '{}'", key)));
+            }
+        }
+    }
+
+    let resolve = |line_no: usize, reference: &LineageRef| -> Result<LineageId, ImportError> {
+        let id = match reference {
+            LineageRef::Key(key) => keyed.get(key).copied(),
+            LineageRef::Id(id) => Some(*id).filter(|id| slots.contains_key(id)),
+        };
+        id.map(LineageId)
+            .ok_or_else(|| invalid(line_no, &format!("unknown lineage {:?}", reference)))
+    };
+
+    // ── Bonds ──
+    if bond_records.len() > max_bonds {
+        return Err(invalid(bond_records[max_bonds].0, "bond capacity exceeded"));
+    }
+    let mut bonds = Vec::with_capacity(bond_records.len());
+    for (line_no, record) in &bond_records {
+        let Record::Bond {
+            source,
+            target,
+            strength,
+            cost,
+            decay_rate,
+            last_access,
+            flags,
+            polarity,
+        } = record
+        else {
+            unreachable!()
+        };
+        if !strength.is_finite() || !cost.is_finite() || !decay_rate.is_finite() {
+            return Err(invalid(*line_no, "non-finite bond parameter"));
+        }
+        let polarity = match polarity {
+            -1 => Trit::False,
+            0 => Trit::Unknown,
+            1 => Trit::True,
+            p => return Err(invalid(*line_no, &format!("polarity {} not in -1..=1", p))),
+        };
+        bonds.push(Bond {
+            source: resolve(*line_no, source)?,
+            target: resolve(*line_no, target)?,
+            strength: *strength,
+            cost: *cost,
+            decay_rate: *decay_rate,
+            last_access: *last_access,
+            flags: BondFlags::from_bits_truncate(*flags) | BondFlags::ACTIVE,
+            polarity,
+        });
+    }
+
+    // ── Engrams, grouped per lineage in recording order ──
+    let mut engrams: Vec<(LineageId, Engram)> = Vec::with_capacity(engram_records.len());
+    for (line_no, record) in &engram_records {
+        let Record::Engram {
+            lineage,
+            timestamp,
+            stimulation,
+            payload_id,
+            source_id,
+        } = record
+        else {
+            unreachable!()
+        };
+        // INVARIANT: timestamp == 0 marks an empty strata slot
+        if *timestamp == 0 {
+            return Err(invalid(*line_no, "engram timestamp must be non-zero"));
+        }
+        engrams.push((
+            resolve(*line_no, lineage)?,
+            Engram {
+                payload_id: payload_id.unwrap_or(u32::MAX),
+                source_id: *source_id,
+                ..Engram::new(*timestamp, *stimulation)
+            },
+        ));
+    }
+    engrams.sort_by_key(|(id, engram)| (id.0, engram.timestamp));
+
+    // ── Rebuild ──
+    let summary = ExportSummary {
+        lineages: slots.len() as u32,
+        bonds: bonds.len() as u32,
+        engrams: engrams.len() as u32,
+    };
+
+    let mut psyche = PsycheArena::from_slots(max_lineages, slots);
+    for (key, id) in &keyed {
+        psyche.set_key(LineageId(*id), key);
+    }
+
+    let mut strata = StrataArena::with_capacity(max_lineages, strata_depth);
+    for (id, engram) in engrams {
+        if let Some(lineage) = psyche.get_mut(id) {
+            lineage.head_index = strata.record(id, lineage.head_index, engram);
+        }
+    }
+
+    let mut graph = BondGraph::with_capacity(max_lineages, max_bonds);
+    for bond in bonds {
+        graph.connect(bond);
+    }
+
+    Ok(Imported {
+        psyche,
+        strata,
+        bonds: graph,
+        cortex,
+        summary,
+    })
+}
+
+fn invalid(line: usize, message: &str) -> ImportError {
+    ImportError::Invalid {
+        line,
+        message: message.to_string(),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> (PsycheArena, StrataArena, BondGraph) {
+        let mut psyche = PsycheArena::with_capacity(16);
+        let mut strata = StrataArena::with_capacity(16, 4);
+        let mut bonds = BondGraph::with_capacity(16, 16);
+
+        let a = psyche.alloc_named("alpha", Lineage::with_config(0.9, 0.4, 0.01));
+        let b = psyche.alloc(Lineage::new(0.3));
+        let c = psyche.alloc_named("gamma", Lineage::new(0.6));
+        psyche.free(b);
+        let d = psyche.alloc(Lineage::new(0.2));
+
+        let mut head = u32::MAX;
+        for t in 1..=6 {
+            head = strata.record(a, head, Engram::new(t, 0.1 * t as f32));
+        }
+        bonds.connect(Bond::new(a, c, 0.8));
+        bonds.connect(Bond {
+            polarity: Trit::False,
+            ..Bond::learned(c, d, 0.4)
+        });
+
+        (psyche, strata, bonds)
+    }
+
+    fn export(psyche: &PsycheArena, strata: &StrataArena, bonds: &BondGraph) -> String {
+        let mut out = Vec::new();
+        export_ndjson(&mut out, psyche, strata, bonds, None).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let (psyche, strata, bonds) = sample();
+        let text = export(&psyche, &strata, &bonds);
+
+        assert!(text.starts_with("{\"type\":\"header\""));
+        assert!(text.contains("\"source\":\"alpha\""));
+
+        let imported = import_ndjson(text.as_bytes(), 16, 16, 4).unwrap();
+        assert_eq!(
+            imported.summary,
+            ExportSummary {
+                lineages: 3,
+                bonds: 2,
+                // Ring buffer of depth 4 kept the newest four
+                engrams: 4,
+            }
+        );
+
+        let a = imported
+            .psyche
+            .lookup(crate::arena::key_hash("alpha"))
+            .unwrap();
+        assert_eq!(imported.psyche.get(a).unwrap().threshold, 0.4);
+        assert_eq!(imported.psyche.key_of(a), Some("alpha"));
+        assert_eq!(imported.bonds.len(), 2);
+
+        // Exporting the import reproduces the same records
+        let again = export(&imported.psyche, &imported.strata, &imported.bonds);
+        let body = |s: &str| s.lines().skip(1).map(String::from).collect::<Vec<_>>();
+        assert_eq!(body(&text), body(&again));
+    }
+
+    #[test]
+    fn test_import_places_keyed_lineages() {
+        let text = r#"{"type":"header","format":"mindfry-ndjson","version":1,"mindfry":"x","strata_depth":8,"exported_at":0}
+{"type":"lineage","key":"x","energy":0.5,"threshold":0.5,"decay_rate":0.001,"rigidity":0.5,"last_access":0,"flags":1}
+{"type":"lineage","id":0,"energy":0.5,"threshold":0.5,"decay_rate":0.001,"rigidity":0.5,"last_access":0,"flags":1}
+{"type":"bond","source":"x","target":0,"strength":0.5,"cost":0.1,"decay_rate":0.0,"last_access":0,"flags":1,"polarity":0}
+{"type":"engram","lineage":"x","timestamp":5,"stimulation":0.2}
+"#;
+        let imported = import_ndjson(text.as_bytes(), 4, 4, 2).unwrap();
+        // "x" had no id and got the first slot not claimed explicitly
+        assert_eq!(imported.psyche.key_of(LineageId(1)), Some("x"));
+        let head = imported.psyche.get(LineageId(1)).unwrap().head_index;
+        assert_eq!(imported.strata.get(head).unwrap().timestamp, 5);
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_input() {
+        let header = r#"{"type":"header","format":"mindfry-ndjson","version":1,"mindfry":"x","strata_depth":8,"exported_at":0}"#;
+        let lineage = r#"{"type":"lineage","id":0,"key":"a","energy":0.5,"threshold":0.5,"decay_rate":0.001,"rigidity":0.5,"last_access":0,"flags":1}"#;
+        let import = |lines: &[&str]| import_ndjson(lines.join("\n").as_bytes(), 4, 4, 2);
+
+        assert!(matches!(import(&[lineage]), Err(ImportError::BadHeader(_))));
+        assert!(matches!(
+            import(&[&header.replace("\"version\":1", "\"version\":99")]),
+            Err(ImportError::BadHeader(_))
+        ));
+        assert!(matches!(
+            import(&[header, "{not json"]),
+            Err(ImportError::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            import(&[header, lineage, lineage]),
+            Err(ImportError::Invalid { line: 3, .. })
+        ));
+        assert!(matches!(
+            import(&[
+                header,
+                &lineage.replace("0.5,\"threshold", "1.5,\"threshold")
+            ]),
+            Err(ImportError::Invalid { line: 2, .. })
+        ));
+        let dangling = r#"{"type":"bond","source":"a","target":"nope","strength":0.5,"cost":0.1,"decay_rate":0.0,"last_access":0,"flags":1,"polarity":1}"#;
+        assert!(matches!(
+            import(&[header, lineage, dangling]),
+            Err(ImportError::Invalid { line: 3, .. })
+        ));
+    }
+}
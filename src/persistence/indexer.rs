@@ -5,12 +5,13 @@
 //!
 //! # Design
 //!
-//! This indexer is **Snapshot-Based**: it rebuilds from persisted data on restore.
-//! For in-memory operations, the PsycheArena's HashMap provides O(1) access.
+//! Every create and forget updates the index, and it is rebuilt from the
+//! restored key table after resurrection, restore and import. For
+//! in-memory operations, the PsycheArena's HashMap provides O(1) access.
 //!
 //! The disk index is used for:
-//! - Server startup (restore from cold storage)
-//! - Single-key lookups without loading full arena
+//! - Single-key lookups while resurrection still holds the arena
+//! - Offline repair (`mfadmin reindex`)
 
 use sled::Tree;
 
@@ -24,12 +24,33 @@
 //! ## Persistence Strategy
 //!
 //! - **Snapshots**: Full arena dumps at key moments (manual or scheduled)
+//! - **Schedule**: Automatic snapshots on an interval or after N dirty lineages
+//! - **Retention**: Automatic snapshots are pruned after each new one
+//! - **Resurrection**: Hottest lineages restored first, the rest streamed in
+//! - **Cold tier**: Idle, low-energy lineages evicted from RAM and faulted back in on access
+//! - **Export**: Versioned NDJSON dumps for moving data between versions and systems
 //! - **WAL (Phase 3.5)**: Write-ahead log for crash recovery
 
 mod akashic;
+mod export;
 mod indexer;
+mod resurrection;
+mod retention;
+mod schedule;
 pub mod snapshot;
+mod tiering;
 
 pub use akashic::{AkashicConfig, AkashicError, AkashicStore};
+pub use export::{
+    export_ndjson, import_ndjson, ExportSummary, ImportError, Imported, EXPORT_FORMAT,
+    EXPORT_VERSION,
+};
 pub use indexer::LineageIndexer;
-pub use snapshot::{PhysicsSnapshot, Snapshot, SnapshotMeta};
+pub use resurrection::Resurrection;
+pub use retention::RetentionPolicy;
+pub use schedule::SnapshotSchedule;
+pub use snapshot::{
+    Checkpoint, PhysicsSnapshot, SectionChecksums, Snapshot, SnapshotContents, SnapshotHealth,
+    SnapshotMeta, SnapshotSection, VerifyReport,
+};
+pub use tiering::{ColdTier, EvictedBond, EvictedLineage, EvictionPolicy};
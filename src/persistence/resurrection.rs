@@ -0,0 +1,288 @@
+//! Progressive Resurrection - Hot set first, cold remainder streamed
+//!
+//! A [`Resurrection`] holds a decoded snapshot outside the database lock.
+//! Lineages are installed hottest first in small batches, so the server can
+//! answer reads for the hot set while the cold remainder streams in. A
+//! lineage that is asked for before its turn can be faulted in on demand.
+//!
+//! Bonds are connected as soon as both of their endpoints are installed.
+
+use rustc_hash::FxHashMap;
+
+use super::akashic::{AkashicStore, Result};
+use super::snapshot::SnapshotMeta;
+use crate::arena::{Engram, Lineage, LineageId, PsycheArena, StrataArena};
+use crate::graph::{Bond, BondGraph};
+use crate::setun::Cortex;
+
+/// A decoded snapshot waiting to be installed into the arenas
+pub struct Resurrection {
+    /// Snapshot being restored
+    meta: SnapshotMeta,
+    /// Restored decision state, taken once by the caller
+    cortex: Option<Cortex>,
+    /// Slot IDs, hottest first
+    order: Vec<u32>,
+    /// Next position in `order`
+    cursor: usize,
+    /// Lineages not yet installed, by slot
+    pending: FxHashMap<u32, Lineage>,
+    /// Engrams by owning slot
+    engrams: FxHashMap<u32, Vec<(u32, Engram)>>,
+    /// String keys by slot
+    keys: FxHashMap<u32, String>,
+    /// Slot of each key, for on-demand loads
+    slots: FxHashMap<String, u32>,
+    /// Bonds not yet connected
+    bonds: Vec<Option<Bond>>,
+    /// Unconnected bond indices by endpoint slot
+    waiting: FxHashMap<u32, Vec<usize>>,
+    /// One past the highest persisted slot
+    span: usize,
+}
+
+impl Resurrection {
+    /// Decode the newest restorable snapshot without touching any arena
+    ///
+    /// Snapshots that fail their checksums, cannot be decoded or sit on a
+    /// broken delta chain are skipped with a warning, walking back until
+    /// one resolves. `now` ranks lineages by their decayed energy.
+    pub fn stage(store: &AkashicStore, strata_depth: usize, now: u64) -> Result<Option<Self>> {
+        use std::time::Instant;
+
+        // Newest first; a damaged snapshot costs only its own history
+        let candidates = store.list_snapshots()?;
+        for (skipped, meta) in candidates.iter().enumerate() {
+            let label = meta.name.as_deref().unwrap_or("unnamed");
+            let t0 = Instant::now();
+            let snapshot = match store.get_snapshot(meta.id) {
+                Ok(Some(s)) => s,
+                Ok(None) => {
+                    tracing::warn!(
+                        "⚠️ Skipping snapshot {} ('{}'): data missing",
+                        meta.id,
+                        label
+                    );
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!("⚠️ Skipping snapshot {} ('{}'): {}", meta.id, label, e);
+                    continue;
+                }
+            };
+            tracing::debug!("Snapshot loaded in {:?}", t0.elapsed());
+
+            tracing::info!(
+                "Restoring '{}' ({} lineages, {} KB)",
+                label,
+                meta.lineage_count,
+                meta.size_bytes / 1024
+            );
+
+            // Verifies checksums along the delta chain
+            let t1 = Instant::now();
+            let contents = match store.resolve(&snapshot) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    tracing::warn!("⚠️ Skipping snapshot {} ('{}'): {}", meta.id, label, e);
+                    continue;
+                }
+            };
+            tracing::debug!("Snapshot resolved in {:?}", t1.elapsed());
+
+            if skipped > 0 {
+                tracing::warn!("⏪ Walked back past {} damaged snapshot(s)", skipped);
+            }
+
+            let cortex = snapshot.cortex_data.as_ref().and_then(|data| {
+                bincode::deserialize::<Cortex>(data)
+                    .map_err(|e| {
+                        tracing::warn!("⚠️ Failed to restore Cortex: {}, using default", e)
+                    })
+                    .ok()
+            });
+
+            let mut order: Vec<(u32, f32)> = contents
+                .lineages
+                .iter()
+                .map(|(id, l)| (*id, l.current_energy_at(now)))
+                .collect();
+            order.sort_unstable_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+            let mut engrams: FxHashMap<u32, Vec<(u32, Engram)>> = FxHashMap::default();
+            for (index, engram) in contents.engrams {
+                engrams
+                    .entry(index / strata_depth.max(1) as u32)
+                    .or_default()
+                    .push((index, engram));
+            }
+
+            let mut waiting: FxHashMap<u32, Vec<usize>> = FxHashMap::default();
+            for (i, bond) in contents.bonds.iter().enumerate() {
+                waiting.entry(bond.source.0).or_default().push(i);
+                waiting.entry(bond.target.0).or_default().push(i);
+            }
+
+            let span = contents
+                .lineages
+                .iter()
+                .map(|(id, _)| *id as usize + 1)
+                .max()
+                .unwrap_or(0);
+
+            return Ok(Some(Self {
+                meta: snapshot.meta,
+                cortex,
+                order: order.into_iter().map(|(id, _)| id).collect(),
+                cursor: 0,
+                pending: contents.lineages.into_iter().collect(),
+                engrams,
+                slots: contents
+                    .keys
+                    .iter()
+                    .map(|(id, key)| (key.clone(), *id))
+                    .collect(),
+                keys: contents.keys.into_iter().collect(),
+                bonds: contents.bonds.into_iter().map(Some).collect(),
+                waiting,
+                span,
+            }));
+        }
+
+        if !candidates.is_empty() {
+            tracing::error!(
+                "💀 None of {} snapshots could be restored",
+                candidates.len()
+            );
+        }
+        Ok(None)
+    }
+
+    /// Metadata of the snapshot being restored
+    pub fn meta(&self) -> &SnapshotMeta {
+        &self.meta
+    }
+
+    /// Take the restored Cortex (None if absent or undecodable)
+    pub fn take_cortex(&mut self) -> Option<Cortex> {
+        self.cortex.take()
+    }
+
+    /// One past the highest persisted slot ID
+    pub fn span(&self) -> usize {
+        self.span
+    }
+
+    /// Lineages still waiting to be installed
+    pub fn remaining(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// True once every lineage has been installed
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Install up to `n` lineages, hottest first
+    ///
+    /// Returns the number installed (lineages already faulted in are skipped).
+    pub fn restore_next(
+        &mut self,
+        n: usize,
+        psyche: &mut PsycheArena,
+        strata: &mut StrataArena,
+        bonds: &mut BondGraph,
+    ) -> usize {
+        let mut installed = 0;
+        while installed < n && self.cursor < self.order.len() {
+            let slot = self.order[self.cursor];
+            self.cursor += 1;
+            if self.install(slot, psyche, strata, bonds) {
+                installed += 1;
+            }
+        }
+        installed
+    }
+
+    /// Install a keyed lineage now, along with every lineage it bonds to
+    ///
+    /// Its neighborhood is complete afterwards. Returns false if the key
+    /// is not part of the snapshot.
+    pub fn fault_in(
+        &mut self,
+        key: &str,
+        psyche: &mut PsycheArena,
+        strata: &mut StrataArena,
+        bonds: &mut BondGraph,
+    ) -> bool {
+        let Some(&slot) = self.slots.get(key) else {
+            return false;
+        };
+        self.install(slot, psyche, strata, bonds);
+
+        let partners: Vec<u32> = self
+            .waiting
+            .get(&slot)
+            .into_iter()
+            .flatten()
+            .filter_map(|&i| self.bonds[i].as_ref())
+            .map(|b| {
+                if b.source.0 == slot {
+                    b.target.0
+                } else {
+                    b.source.0
+                }
+            })
+            .collect();
+        for partner in partners {
+            self.install(partner, psyche, strata, bonds);
+        }
+        true
+    }
+
+    /// Install one pending lineage with its engrams, key and ready bonds
+    fn install(
+        &mut self,
+        slot: u32,
+        psyche: &mut PsycheArena,
+        strata: &mut StrataArena,
+        bonds: &mut BondGraph,
+    ) -> bool {
+        let Some(lineage) = self.pending.remove(&slot) else {
+            return false;
+        };
+        psyche.restore_slot(slot, lineage);
+        if let Some(key) = self.keys.get(&slot) {
+            psyche.set_key(LineageId(slot), key);
+        }
+        if let Some(engrams) = self.engrams.remove(&slot) {
+            strata.restore_from_sparse(engrams);
+        }
+
+        // Connect bonds whose other endpoint is already in place; the rest
+        // stay listed under this slot until their partner arrives
+        let Some(mut indices) = self.waiting.remove(&slot) else {
+            return true;
+        };
+        indices.retain(|&i| match self.bonds[i] {
+            Some(bond) => {
+                let partner = if bond.source.0 == slot {
+                    bond.target.0
+                } else {
+                    bond.source.0
+                };
+                if self.pending.contains_key(&partner) {
+                    return true;
+                }
+                bonds.connect(bond);
+                self.bonds[i] = None;
+                false
+            }
+            None => false,
+        });
+        if !indices.is_empty() {
+            self.waiting.insert(slot, indices);
+        }
+        true
+    }
+}
@@ -0,0 +1,196 @@
+//! Snapshot Retention - Forgetting old checkpoints
+//!
+//! Automatic snapshots (unnamed, `auto-*` and `pre-shutdown`) are pruned
+//! by a [`RetentionPolicy`]:
+//!
+//! - the newest `keep_last` are kept
+//! - the newest snapshot of each of the last `keep_daily` days is kept
+//! - the newest snapshot of each of the last `keep_weekly` weeks is kept
+//! - the oldest survivors are dropped until `max_total_bytes` fits
+//!
+//! Named snapshots and the newest snapshot overall are never pruned.
+
+use std::collections::HashSet;
+
+use super::snapshot::SnapshotMeta;
+
+const SECS_PER_DAY: u64 = 86_400;
+const SECS_PER_WEEK: u64 = 7 * SECS_PER_DAY;
+
+/// Which snapshots survive garbage collection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Most recent automatic snapshots to keep
+    pub keep_last: usize,
+    /// Days to keep one automatic snapshot for
+    pub keep_daily: usize,
+    /// Weeks to keep one automatic snapshot for
+    pub keep_weekly: usize,
+    /// Disk budget across all snapshots (None = unlimited)
+    pub max_total_bytes: Option<u64>,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            keep_last: 10,
+            keep_daily: 7,
+            keep_weekly: 4,
+            max_total_bytes: None,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// Never delete anything
+    pub fn keep_all() -> Self {
+        Self {
+            keep_last: usize::MAX,
+            keep_daily: 0,
+            keep_weekly: 0,
+            max_total_bytes: None,
+        }
+    }
+
+    /// Snapshot IDs to delete, given metas sorted newest first
+    pub fn plan(&self, snapshots: &[SnapshotMeta]) -> Vec<u64> {
+        let newest = snapshots.first().map(|m| m.id);
+        let automatic: Vec<&SnapshotMeta> = snapshots.iter().filter(|m| m.is_automatic()).collect();
+
+        let mut keep: HashSet<u64> = automatic
+            .iter()
+            .take(self.keep_last)
+            .map(|m| m.id)
+            .chain(newest)
+            .collect();
+        keep_per_bucket(&automatic, SECS_PER_DAY, self.keep_daily, &mut keep);
+        keep_per_bucket(&automatic, SECS_PER_WEEK, self.keep_weekly, &mut keep);
+
+        let mut delete: Vec<u64> = automatic
+            .iter()
+            .filter(|m| !keep.contains(&m.id))
+            .map(|m| m.id)
+            .collect();
+
+        if let Some(budget) = self.max_total_bytes {
+            let mut total: u64 = snapshots
+                .iter()
+                .filter(|m| !delete.contains(&m.id))
+                .map(|m| m.size_bytes)
+                .sum();
+            // Oldest survivors go first
+            for meta in automatic.iter().rev() {
+                if total <= budget {
+                    break;
+                }
+                if Some(meta.id) != newest && keep.remove(&meta.id) {
+                    total = total.saturating_sub(meta.size_bytes);
+                    delete.push(meta.id);
+                }
+            }
+        }
+
+        delete
+    }
+}
+
+/// Keep the newest snapshot in each of the `count` most recent buckets
+fn keep_per_bucket(
+    snapshots: &[&SnapshotMeta],
+    bucket_secs: u64,
+    count: usize,
+    keep: &mut HashSet<u64>,
+) {
+    let mut seen = HashSet::new();
+    for meta in snapshots {
+        if seen.len() >= count {
+            break;
+        }
+        if seen.insert(meta.created_at / bucket_secs) {
+            keep.insert(meta.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(id: u64, name: Option<&str>, created_at: u64) -> SnapshotMeta {
+        SnapshotMeta {
+            id,
+            name: name.map(String::from),
+            created_at,
+            lineage_count: 0,
+            bond_count: 0,
+            size_bytes: 100,
+            version: String::new(),
+            checksums: None,
+        }
+    }
+
+    /// One automatic snapshot per hour for `hours`, newest first
+    fn hourly(hours: u64) -> Vec<SnapshotMeta> {
+        (0..hours)
+            .rev()
+            .map(|h| meta(h + 1, None, h * 3600))
+            .collect()
+    }
+
+    #[test]
+    fn test_keep_last() {
+        let policy = RetentionPolicy {
+            keep_last: 3,
+            keep_daily: 0,
+            keep_weekly: 0,
+            max_total_bytes: None,
+        };
+        let mut delete = policy.plan(&hourly(5));
+        delete.sort();
+        assert_eq!(delete, vec![1, 2]);
+
+        assert!(RetentionPolicy::keep_all().plan(&hourly(50)).is_empty());
+    }
+
+    #[test]
+    fn test_keep_daily_and_weekly() {
+        let policy = RetentionPolicy {
+            keep_last: 1,
+            keep_daily: 2,
+            keep_weekly: 3,
+            max_total_bytes: None,
+        };
+        // 15 days of hourly snapshots
+        let snapshots = hourly(15 * 24);
+        let delete = policy.plan(&snapshots);
+        let kept: Vec<&SnapshotMeta> = snapshots
+            .iter()
+            .filter(|m| !delete.contains(&m.id))
+            .collect();
+
+        // Newest overall, newest of yesterday, newest of the week before last
+        let ids: Vec<u64> = kept.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![360, 336, 168]);
+    }
+
+    #[test]
+    fn test_named_and_newest_survive_budget() {
+        let policy = RetentionPolicy {
+            keep_last: 10,
+            keep_daily: 0,
+            keep_weekly: 0,
+            max_total_bytes: Some(250),
+        };
+        let snapshots = vec![
+            meta(5, Some("pre-shutdown"), 50),
+            meta(4, None, 40),
+            meta(3, Some("release"), 30),
+            meta(2, Some("auto-2"), 20),
+            meta(1, None, 10),
+        ];
+        let mut delete = policy.plan(&snapshots);
+        delete.sort();
+        // 500 bytes -> 200: oldest automatic ones dropped, "release" and newest kept
+        assert_eq!(delete, vec![1, 2, 4]);
+    }
+}
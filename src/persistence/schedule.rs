@@ -0,0 +1,104 @@
+//! Snapshot Schedule - When to checkpoint automatically
+//!
+//! Driven by `LineageFlags::DIRTY`: nothing is written while every
+//! lineage matches the last snapshot. Otherwise a snapshot is due once
+//! the interval has passed or enough lineages are dirty, whichever
+//! comes first.
+//!
+//! Automatic snapshots are deltas on top of the previous one; once a
+//! chain reaches `max_delta_chain` deltas it is compacted into a full base.
+
+use std::time::Duration;
+
+use super::snapshot::AUTO_PREFIX;
+
+/// When automatic snapshots are taken
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotSchedule {
+    /// Maximum time between snapshots of a dirty state (None = never)
+    pub interval: Option<Duration>,
+    /// Dirty lineages that trigger an early snapshot (None = never)
+    pub dirty_threshold: Option<usize>,
+    /// Deltas allowed before compaction (0 = always full)
+    pub max_delta_chain: usize,
+}
+
+impl Default for SnapshotSchedule {
+    fn default() -> Self {
+        Self {
+            interval: Some(Duration::from_secs(300)),
+            dirty_threshold: Some(10_000),
+            max_delta_chain: 10,
+        }
+    }
+}
+
+impl SnapshotSchedule {
+    /// Never snapshot automatically
+    pub fn disabled() -> Self {
+        Self {
+            interval: None,
+            dirty_threshold: None,
+            max_delta_chain: 0,
+        }
+    }
+
+    /// Whether any trigger is configured
+    pub fn is_enabled(&self) -> bool {
+        self.interval.is_some() || self.dirty_threshold.is_some()
+    }
+
+    /// Whether a snapshot is due
+    pub fn is_due(&self, since_last: Duration, dirty: usize) -> bool {
+        if dirty == 0 {
+            return false;
+        }
+        self.interval.is_some_and(|interval| since_last >= interval)
+            || self.dirty_threshold.is_some_and(|n| dirty >= n)
+    }
+
+    /// Whether automatic snapshots may be deltas
+    pub fn uses_deltas(&self) -> bool {
+        self.max_delta_chain > 0
+    }
+
+    /// Whether a chain of `depth` deltas should be folded into a base
+    pub fn needs_compaction(&self, depth: usize) -> bool {
+        self.uses_deltas() && depth >= self.max_delta_chain
+    }
+
+    /// Name for an automatic snapshot taken at `now_secs`
+    pub fn snapshot_name(now_secs: u64) -> String {
+        format!("{}{}", AUTO_PREFIX, now_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_triggers() {
+        let schedule = SnapshotSchedule {
+            interval: Some(Duration::from_secs(60)),
+            dirty_threshold: Some(100),
+            max_delta_chain: 3,
+        };
+
+        // Clean state never snapshots
+        assert!(!schedule.is_due(Duration::from_secs(3600), 0));
+        // Interval elapsed
+        assert!(!schedule.is_due(Duration::from_secs(30), 1));
+        assert!(schedule.is_due(Duration::from_secs(60), 1));
+        // Dirty threshold reached early
+        assert!(schedule.is_due(Duration::from_secs(1), 100));
+        // Chains are folded once they reach the limit
+        assert!(!schedule.needs_compaction(2));
+        assert!(schedule.needs_compaction(3));
+
+        let disabled = SnapshotSchedule::disabled();
+        assert!(!disabled.is_enabled());
+        assert!(!disabled.is_due(Duration::from_secs(3600), 1_000_000));
+        assert!(!disabled.uses_deltas());
+    }
+}
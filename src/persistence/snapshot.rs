@@ -2,8 +2,21 @@
 //!
 //! Defines the format for persisted MindFry state.
 
+use std::collections::HashSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use serde::{Deserialize, Serialize};
 
+use crate::arena::{Engram, Lineage, LineageFlags, LineageId, PsycheArena, StrataArena};
+use crate::graph::{Bond, BondGraph};
+use crate::setun::Cortex;
+
+/// Name of the snapshot taken during graceful shutdown
+pub const PRE_SHUTDOWN: &str = "pre-shutdown";
+
+/// Name prefix reserved for scheduled snapshots
+pub const AUTO_PREFIX: &str = "auto-";
+
 /// Metadata for a snapshot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SnapshotMeta {
@@ -21,6 +34,178 @@ pub struct SnapshotMeta {
     pub size_bytes: u64,
     /// MindFry version that created this snapshot
     pub version: String,
+    /// CRC32 of each data section (None = written before checksums)
+    pub checksums: Option<SectionChecksums>,
+}
+
+/// Meta layout before section checksums existed
+#[derive(Deserialize)]
+struct SnapshotMetaV1 {
+    id: u64,
+    name: Option<String>,
+    created_at: u64,
+    lineage_count: u32,
+    bond_count: u32,
+    size_bytes: u64,
+    version: String,
+}
+
+impl From<SnapshotMetaV1> for SnapshotMeta {
+    fn from(v1: SnapshotMetaV1) -> Self {
+        Self {
+            id: v1.id,
+            name: v1.name,
+            created_at: v1.created_at,
+            lineage_count: v1.lineage_count,
+            bond_count: v1.bond_count,
+            size_bytes: v1.size_bytes,
+            version: v1.version,
+            checksums: None,
+        }
+    }
+}
+
+/// Snapshot layout before section checksums existed
+#[derive(Deserialize)]
+struct SnapshotV1 {
+    meta: SnapshotMetaV1,
+    psyche_data: Vec<u8>,
+    strata_data: Vec<u8>,
+    bond_data: Vec<u8>,
+    cortex_data: Option<Vec<u8>>,
+    physics_config: PhysicsSnapshot,
+}
+
+/// Decode a `snapshot_meta` entry in either layout
+///
+/// The current layout is tried first: a legacy entry ends before the
+/// checksum field, so it can never be misread as current.
+pub(crate) fn decode_meta(bytes: &[u8]) -> bincode::Result<SnapshotMeta> {
+    bincode::deserialize::<SnapshotMeta>(bytes)
+        .or_else(|_| bincode::deserialize::<SnapshotMetaV1>(bytes).map(Into::into))
+}
+
+/// Decode a snapshot blob whose layout is known from its meta entry
+pub(crate) fn decode_snapshot(bytes: &[u8], legacy: bool) -> bincode::Result<Snapshot> {
+    if !legacy {
+        return bincode::deserialize(bytes);
+    }
+    let v1: SnapshotV1 = bincode::deserialize(bytes)?;
+    Ok(Snapshot {
+        meta: v1.meta.into(),
+        psyche_data: v1.psyche_data,
+        strata_data: v1.strata_data,
+        bond_data: v1.bond_data,
+        cortex_data: v1.cortex_data,
+        physics_config: v1.physics_config,
+    })
+}
+
+/// CRC32 of each snapshot data section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionChecksums {
+    /// Serialized lineages
+    pub psyche: u32,
+    /// Compressed engrams
+    pub strata: u32,
+    /// Serialized bonds
+    pub bonds: u32,
+    /// None when the snapshot has no Cortex
+    pub cortex: Option<u32>,
+}
+
+impl SectionChecksums {
+    /// Checksum the serialized sections
+    pub fn compute(psyche: &[u8], strata: &[u8], bonds: &[u8], cortex: Option<&[u8]>) -> Self {
+        Self {
+            psyche: crc32fast::hash(psyche),
+            strata: crc32fast::hash(strata),
+            bonds: crc32fast::hash(bonds),
+            cortex: cortex.map(crc32fast::hash),
+        }
+    }
+}
+
+/// A data section of a snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotSection {
+    /// Lineages (`psyche_data`)
+    Psyche,
+    /// Engrams (`strata_data`)
+    Strata,
+    /// Bonds (`bond_data`)
+    Bonds,
+    /// Cortex state (`cortex_data`)
+    Cortex,
+}
+
+impl std::fmt::Display for SnapshotSection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Psyche => "psyche",
+            Self::Strata => "strata",
+            Self::Bonds => "bonds",
+            Self::Cortex => "cortex",
+        })
+    }
+}
+
+/// Integrity of a stored snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotHealth {
+    /// Every section matches its checksum
+    Intact,
+    /// Readable, but written before checksums existed
+    Unverified,
+    /// These sections fail their checksum
+    Damaged(Vec<SnapshotSection>),
+    /// The blob is missing or cannot be decoded
+    Unreadable(String),
+    /// Intact itself, but this ancestor in its delta chain is not
+    BrokenChain(u64),
+}
+
+impl SnapshotHealth {
+    /// Whether the snapshot can be restored
+    pub fn is_restorable(&self) -> bool {
+        matches!(self, Self::Intact | Self::Unverified)
+    }
+}
+
+impl std::fmt::Display for SnapshotHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Intact => f.write_str("intact"),
+            Self::Unverified => f.write_str("unverified (no checksums)"),
+            Self::Damaged(sections) => {
+                let names: Vec<String> = sections.iter().map(|s| s.to_string()).collect();
+                write!(f, "damaged ({})", names.join(", "))
+            }
+            Self::Unreadable(reason) => write!(f, "unreadable ({})", reason),
+            Self::BrokenChain(ancestor) => write!(f, "broken chain (ancestor {})", ancestor),
+        }
+    }
+}
+
+/// Integrity report for one stored snapshot
+#[derive(Debug, Clone)]
+pub struct VerifyReport {
+    /// Snapshot ID
+    pub id: u64,
+    /// Snapshot name, if any
+    pub name: Option<String>,
+    /// Verdict
+    pub health: SnapshotHealth,
+}
+
+impl SnapshotMeta {
+    /// Whether retention may prune this snapshot (unnamed or system-named)
+    pub fn is_automatic(&self) -> bool {
+        match self.name.as_deref() {
+            None => true,
+            Some(name) => name == PRE_SHUTDOWN || name.starts_with(AUTO_PREFIX),
+        }
+    }
 }
 
 /// A complete snapshot of MindFry state
@@ -53,9 +238,209 @@ pub struct PhysicsSnapshot {
     pub is_frozen: bool,
 }
 
+/// Point-in-time copy of everything a snapshot persists
+///
+/// Capturing only copies live data, so it is cheap enough to do under
+/// the database lock; serialization and the disk write happen later.
+///
+/// A *delta* checkpoint holds only the slots flagged `DIRTY` since its
+/// parent: their lineages (or a tombstone if freed), engram windows and
+/// every bond touching them. Restoring a delta resolves its chain down
+/// to a full base.
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    /// Snapshot ID this checkpoint will be written under
+    pub(crate) id: u64,
+    /// Snapshot this delta applies on top of (None = full)
+    pub(crate) parent: Option<u64>,
+    /// History depth of the captured strata
+    pub(crate) strata_depth: usize,
+    /// Active lineages with their slot IDs
+    pub(crate) lineages: Vec<(u32, Lineage)>,
+    /// Slots freed since the parent (delta only)
+    pub(crate) freed: Vec<u32>,
+    /// Non-empty engrams with their global strata index
+    pub(crate) engrams: Vec<(u32, Engram)>,
+    /// Active bonds
+    pub(crate) bonds: Vec<Bond>,
+    /// String keys of the captured lineages
+    pub(crate) keys: Vec<(u32, String)>,
+    /// Cortex state
+    pub(crate) cortex: Option<Cortex>,
+}
+
+impl Checkpoint {
+    /// Copy the persisted parts of the given arenas
+    pub fn capture(
+        psyche: &PsycheArena,
+        strata: &StrataArena,
+        bonds: &BondGraph,
+        cortex: Option<&Cortex>,
+    ) -> Self {
+        Self {
+            id: new_snapshot_id(None),
+            parent: None,
+            strata_depth: strata.depth(),
+            lineages: psyche.iter().map(|(id, l)| (id.0, *l)).collect(),
+            freed: Vec::new(),
+            engrams: strata.to_sparse(),
+            bonds: bonds.iter().map(|(_, b)| *b).collect(),
+            keys: psyche
+                .keys()
+                .map(|(id, key)| (id.0, key.to_string()))
+                .collect(),
+            cortex: cortex.cloned(),
+        }
+    }
+
+    /// Copy only the slots flagged `DIRTY` since snapshot `parent`
+    pub fn capture_delta(
+        parent: u64,
+        psyche: &PsycheArena,
+        strata: &StrataArena,
+        bonds: &BondGraph,
+        cortex: Option<&Cortex>,
+    ) -> Self {
+        let mut lineages = Vec::new();
+        let mut freed = Vec::new();
+        let mut engrams = Vec::new();
+        let mut keys = Vec::new();
+        let mut bond_ids = HashSet::new();
+        let mut delta_bonds = Vec::new();
+
+        for (i, lineage) in psyche.as_slice().iter().enumerate() {
+            if !lineage.flags.contains(LineageFlags::DIRTY) {
+                continue;
+            }
+            let id = LineageId(i as u32);
+            if lineage.is_active() {
+                lineages.push((id.0, *lineage));
+                if let Some(key) = psyche.key_of(id) {
+                    keys.push((id.0, key.to_string()));
+                }
+            } else {
+                freed.push(id.0);
+            }
+            engrams.extend(strata.window_sparse(id));
+            for &bond_id in bonds.neighbors(id) {
+                if let Some(bond) = bonds.get(bond_id) {
+                    if bond_ids.insert(bond_id) {
+                        delta_bonds.push(*bond);
+                    }
+                }
+            }
+        }
+
+        Self {
+            id: new_snapshot_id(Some(parent)),
+            parent: Some(parent),
+            strata_depth: strata.depth(),
+            lineages,
+            freed,
+            engrams,
+            bonds: delta_bonds,
+            keys,
+            cortex: cortex.cloned(),
+        }
+    }
+
+    /// Snapshot ID this checkpoint will be written under
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Parent snapshot of a delta (None = full)
+    pub fn parent(&self) -> Option<u64> {
+        self.parent
+    }
+
+    /// Whether this is a delta checkpoint
+    pub fn is_delta(&self) -> bool {
+        self.parent.is_some()
+    }
+
+    /// Number of lineages captured
+    pub fn lineage_count(&self) -> usize {
+        self.lineages.len()
+    }
+
+    /// Number of bonds captured
+    pub fn bond_count(&self) -> usize {
+        self.bonds.len()
+    }
+}
+
+/// Link from a delta snapshot to the snapshot it applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainLink {
+    /// Parent snapshot ID
+    pub parent: u64,
+    /// Strata depth the delta's engram windows were captured with
+    pub strata_depth: u32,
+}
+
+/// Fully resolved contents of a snapshot (deltas applied to their base)
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotContents {
+    /// Active lineages with their slot IDs
+    pub lineages: Vec<(u32, Lineage)>,
+    /// Non-empty engrams with their global strata index
+    pub engrams: Vec<(u32, Engram)>,
+    /// Active bonds
+    pub bonds: Vec<Bond>,
+    /// String keys by slot ID (empty for snapshots written before key tables)
+    pub keys: Vec<(u32, String)>,
+}
+
+/// Lineage payload of a delta snapshot
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct DeltaPsyche {
+    /// Modified or created lineages
+    pub lineages: Vec<(u32, Lineage)>,
+    /// Freed slots
+    pub freed: Vec<u32>,
+}
+
+/// Snapshot IDs are wall-clock nanoseconds, kept strictly after the parent
+fn new_snapshot_id(parent: Option<u64>) -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    match parent {
+        Some(parent) => now.max(parent + 1),
+        None => now,
+    }
+}
+
 impl Snapshot {
     /// Calculate total size
     pub fn total_size(&self) -> usize {
         self.psyche_data.len() + self.strata_data.len() + self.bond_data.len()
     }
+
+    /// Checksums of the sections as currently stored
+    pub fn checksums(&self) -> SectionChecksums {
+        SectionChecksums::compute(
+            &self.psyche_data,
+            &self.strata_data,
+            &self.bond_data,
+            self.cortex_data.as_deref(),
+        )
+    }
+
+    /// Sections that do not match `expected`
+    pub fn damaged_sections(&self, expected: &SectionChecksums) -> Vec<SnapshotSection> {
+        let actual = self.checksums();
+        [
+            (SnapshotSection::Psyche, actual.psyche == expected.psyche),
+            (SnapshotSection::Strata, actual.strata == expected.strata),
+            (SnapshotSection::Bonds, actual.bonds == expected.bonds),
+            (SnapshotSection::Cortex, actual.cortex == expected.cortex),
+        ]
+        .into_iter()
+        .filter(|(_, ok)| !ok)
+        .map(|(section, _)| section)
+        .collect()
+    }
 }
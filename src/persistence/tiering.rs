@@ -0,0 +1,243 @@
+//! Cold Tier - Lineages evicted from the Psyche Arena
+//!
+//! Low-energy, long-idle lineages are moved out of RAM into the
+//! `evicted_lineages` tree, keyed by lineage key. A record carries the
+//! lineage as it was last seen, its engram window and its bonds (addressed
+//! by partner key), so an access can fault it back into any free slot.
+//!
+//! Nothing decays while evicted: `last_access` travels with the lineage
+//! and its bonds, so the gap is accounted for lazily on the next read.
+
+use serde::{Deserialize, Serialize};
+use sled::Tree;
+
+use super::AkashicError;
+use crate::arena::{Engram, Lineage};
+use crate::graph::Bond;
+
+/// Result type for cold tier operations
+pub type Result<T> = std::result::Result<T, AkashicError>;
+
+/// Which lineages the maintenance tick moves to the cold tier
+#[derive(Debug, Clone, Copy)]
+pub struct EvictionPolicy {
+    /// Only lineages at or below this decayed energy are evicted
+    pub max_energy: f32,
+    /// Only lineages idle at least this long are evicted
+    pub min_idle_secs: u64,
+    /// Upper bound on evictions per maintenance tick
+    pub max_per_tick: usize,
+}
+
+impl Default for EvictionPolicy {
+    fn default() -> Self {
+        Self {
+            max_energy: 0.05,
+            min_idle_secs: 3600,
+            max_per_tick: 1024,
+        }
+    }
+}
+
+/// A bond of an evicted lineage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvictedBond {
+    /// Key of the lineage on the other end
+    pub partner: String,
+    /// Whether the evicted lineage is the bond's source
+    pub outgoing: bool,
+    /// Bond state (endpoints are reassigned on fault-in)
+    pub bond: Bond,
+}
+
+/// Everything needed to bring a lineage back into the arenas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvictedLineage {
+    /// Lineage state; `head_index` is an offset into `engrams`' window
+    pub lineage: Lineage,
+    /// Engram window as (offset, engram); `prev_index` is an offset too
+    pub engrams: Vec<(u32, Engram)>,
+    /// Bonds to keyed lineages
+    pub bonds: Vec<EvictedBond>,
+}
+
+impl EvictedLineage {
+    /// Capture a lineage whose engram window starts at global index `base`
+    pub fn new(
+        mut lineage: Lineage,
+        window: Vec<(u32, Engram)>,
+        base: u32,
+        bonds: Vec<EvictedBond>,
+    ) -> Self {
+        let offset = |index: u32| {
+            if index == u32::MAX {
+                u32::MAX
+            } else {
+                index - base
+            }
+        };
+        lineage.head_index = offset(lineage.head_index);
+        let engrams = window
+            .into_iter()
+            .map(|(index, mut engram)| {
+                engram.prev_index = offset(engram.prev_index);
+                (offset(index), engram)
+            })
+            .collect();
+        Self {
+            lineage,
+            engrams,
+            bonds,
+        }
+    }
+
+    /// Lineage and engrams rebased onto a window starting at `base`
+    pub fn rebase(&self, base: u32) -> (Lineage, Vec<(u32, Engram)>) {
+        let global = |offset: u32| {
+            if offset == u32::MAX {
+                u32::MAX
+            } else {
+                base + offset
+            }
+        };
+        let mut lineage = self.lineage;
+        lineage.head_index = global(lineage.head_index);
+        let engrams = self
+            .engrams
+            .iter()
+            .map(|&(offset, mut engram)| {
+                engram.prev_index = global(engram.prev_index);
+                (global(offset), engram)
+            })
+            .collect();
+        (lineage, engrams)
+    }
+}
+
+/// Cold Tier - Evicted lineages by key
+pub struct ColdTier {
+    /// The sled tree storing key -> record
+    tree: Tree,
+}
+
+impl ColdTier {
+    /// Create a cold tier from a sled tree
+    pub fn new(tree: Tree) -> Self {
+        Self { tree }
+    }
+
+    /// Store (or replace) an evicted lineage
+    pub fn put(&self, key: &str, record: &EvictedLineage) -> Result<()> {
+        self.tree
+            .insert(key.as_bytes(), bincode::serialize(record)?)?;
+        Ok(())
+    }
+
+    /// Read an evicted lineage
+    pub fn get(&self, key: &str) -> Result<Option<EvictedLineage>> {
+        match self.tree.get(key.as_bytes())? {
+            Some(bytes) => Ok(Some(bincode::deserialize(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Drop an evicted lineage
+    pub fn remove(&self, key: &str) -> Result<bool> {
+        Ok(self.tree.remove(key.as_bytes())?.is_some())
+    }
+
+    /// Check if a key is evicted
+    pub fn contains(&self, key: &str) -> Result<bool> {
+        Ok(self.tree.contains_key(key.as_bytes())?)
+    }
+
+    /// Hand a bond over to an evicted lineage
+    ///
+    /// Returns false if `key` is not evicted (the bond is dropped).
+    pub fn attach_bond(&self, key: &str, bond: EvictedBond) -> Result<bool> {
+        let Some(mut record) = self.get(key)? else {
+            return Ok(false);
+        };
+        record.bonds.push(bond);
+        self.put(key, &record)?;
+        Ok(true)
+    }
+
+    /// Keys of all evicted lineages
+    pub fn keys(&self) -> impl Iterator<Item = Result<String>> + '_ {
+        self.tree.iter().keys().map(|key| {
+            let key = key?;
+            Ok(String::from_utf8_lossy(&key).to_string())
+        })
+    }
+
+    /// Drop every evicted lineage
+    pub fn clear(&self) -> Result<()> {
+        self.tree.clear()?;
+        Ok(())
+    }
+
+    /// Get the number of evicted lineages
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// Check if nothing is evicted
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_evicted_lineage_rebases_engrams() {
+        let mut lineage = Lineage::new(0.2);
+        lineage.head_index = 129;
+        let window = vec![
+            (128, Engram::new(1, 0.5)),
+            (
+                129,
+                Engram {
+                    prev_index: 128,
+                    ..Engram::new(2, 0.5)
+                },
+            ),
+        ];
+        let record = EvictedLineage::new(lineage, window, 128, Vec::new());
+        assert_eq!(record.lineage.head_index, 1);
+
+        let (lineage, engrams) = record.rebase(64);
+        assert_eq!(lineage.head_index, 65);
+        assert_eq!(engrams[0].0, 64);
+        assert_eq!(engrams[0].1.prev_index, u32::MAX);
+        assert_eq!(engrams[1].1.prev_index, 64);
+    }
+
+    #[test]
+    fn test_cold_tier_attach_bond() {
+        let dir = tempdir().unwrap();
+        let db = sled::open(dir.path()).unwrap();
+        let tier = ColdTier::new(db.open_tree("test_cold").unwrap());
+
+        let record = EvictedLineage::new(Lineage::new(0.1), Vec::new(), 0, Vec::new());
+        tier.put("cold", &record).unwrap();
+        assert!(tier.contains("cold").unwrap());
+        assert_eq!(tier.len(), 1);
+
+        let bond = EvictedBond {
+            partner: "warm".into(),
+            outgoing: true,
+            bond: Bond::default(),
+        };
+        assert!(tier.attach_bond("cold", bond.clone()).unwrap());
+        assert!(!tier.attach_bond("missing", bond).unwrap());
+        assert_eq!(tier.get("cold").unwrap().unwrap().bonds.len(), 1);
+
+        assert!(tier.remove("cold").unwrap());
+        assert!(tier.is_empty());
+    }
+}
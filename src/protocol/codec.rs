@@ -6,7 +6,13 @@
 
 use std::io;
 
-use super::{Event, LineageInfo, OpCode, Request, Response, ResponseData};
+use super::{
+    CascadeEntry, ErrorCode, Event, ForensicInfo, HealthInfo, LineageInfo, LineageResult,
+    LineageStatus, NeighborInfo, OpCode, Request, Response, ResponseData, SnapshotInfo, StatsInfo,
+    SuppressorInfo,
+};
+use crate::dynamics::CascadeMode;
+use crate::stability::{ExhaustionLevel, HealthStatus, RecoveryState, TunerStats, WarmupState};
 
 /// MFBP protocol errors
 #[derive(Debug)]
@@ -21,6 +27,8 @@ pub enum MfbpError {
     InvalidUtf8,
     /// Frame too large
     FrameTooLarge,
+    /// Unknown discriminant for an enum field
+    InvalidValue(u8),
 }
 
 impl From<io::Error> for MfbpError {
@@ -37,6 +45,7 @@ impl std::fmt::Display for MfbpError {
             Self::PayloadTooShort => write!(f, "Payload too short"),
             Self::InvalidUtf8 => write!(f, "Invalid UTF-8 in payload"),
             Self::FrameTooLarge => write!(f, "Frame exceeds maximum size"),
+            Self::InvalidValue(v) => write!(f, "Invalid enum value: 0x{:02X}", v),
         }
     }
 }
@@ -75,11 +84,34 @@ impl MfbpCodec {
                 Self::write_string(&mut payload, id);
                 payload.push(*flags);
             }
-            Request::LineageForget { id }
-            | Request::LineageTouch { id }
-            | Request::BondNeighbors { id } => {
+            Request::LineageForget { id, flags } => {
+                Self::write_string(&mut payload, id);
+                payload.push(*flags);
+            }
+            Request::Cascade {
+                id,
+                strength,
+                max_depth,
+                min_bond_strength,
+                ..
+            } => {
+                Self::write_string(&mut payload, id);
+                payload.extend_from_slice(&strength.to_le_bytes());
+                payload.push(*max_depth);
+                payload.extend_from_slice(&min_bond_strength.to_le_bytes());
+            }
+            Request::LineageProtect { id, level, flags } => {
+                Self::write_string(&mut payload, id);
+                payload.push(*level);
+                payload.push(*flags);
+            }
+            Request::LineageTouch { id } | Request::LineageUnlock { id } => {
                 Self::write_string(&mut payload, id);
             }
+            Request::BondNeighbors { id, flags } => {
+                Self::write_string(&mut payload, id);
+                payload.push(*flags);
+            }
             Request::LineageStimulate { id, delta, flags } => {
                 Self::write_string(&mut payload, id);
                 payload.extend_from_slice(&delta.to_le_bytes());
@@ -109,24 +141,42 @@ impl MfbpCodec {
                 Self::write_string(&mut payload, source);
                 Self::write_string(&mut payload, target);
             }
-            Request::QueryConscious { min_energy } => {
+            Request::QueryConscious { min_energy, flags } => {
                 payload.extend_from_slice(&min_energy.to_le_bytes());
+                payload.push(*flags);
             }
-            Request::QueryTopK { k } => {
+            Request::QueryTopK { k, flags } => {
                 payload.extend_from_slice(&k.to_le_bytes());
+                payload.push(*flags);
             }
-            Request::QueryTrauma { min_rigidity } => {
+            Request::QueryTrauma {
+                min_rigidity,
+                flags,
+            } => {
                 payload.extend_from_slice(&min_rigidity.to_le_bytes());
+                payload.push(*flags);
             }
-            Request::QueryPattern { pattern } => {
+            Request::QueryPattern { pattern, flags } => {
                 Self::write_string(&mut payload, pattern);
+                payload.push(*flags);
             }
-            Request::Ping | Request::Stats | Request::Unsubscribe => {
+            Request::Ping
+            | Request::Stats
+            | Request::Health
+            | Request::SnapshotList
+            | Request::Export
+            | Request::Unsubscribe => {
                 // No payload
             }
+            Request::SnapshotDelete { id } => {
+                payload.extend_from_slice(&id.to_le_bytes());
+            }
             Request::Snapshot { name } | Request::Restore { name } => {
                 Self::write_string(&mut payload, name);
             }
+            Request::Import { ndjson } => {
+                Self::write_long_string(&mut payload, ndjson);
+            }
             Request::Freeze { frozen } => {
                 payload.push(if *frozen { 1 } else { 0 });
             }
@@ -150,8 +200,9 @@ impl MfbpCodec {
         let mut payload = Vec::new();
         let opcode = match response {
             Response::Ok(_) => OpCode::ResponseOk,
-            Response::Error { .. } => OpCode::ResponseError,
+            Response::Error { .. } | Response::Rejected { .. } => OpCode::ResponseError,
             Response::Event(_) => OpCode::ResponseEvent,
+            Response::GoingAway { .. } => OpCode::ResponseGoingAway,
         };
 
         match response {
@@ -162,9 +213,23 @@ impl MfbpCodec {
                 payload.push(*code as u8);
                 Self::write_string(&mut payload, message);
             }
+            Response::Rejected {
+                code,
+                message,
+                level,
+                retry_after_ms,
+            } => {
+                payload.push(*code as u8);
+                Self::write_string(&mut payload, message);
+                payload.push(*level as u8);
+                payload.extend_from_slice(&retry_after_ms.to_le_bytes());
+            }
             Response::Event(event) => {
                 Self::encode_event(&mut payload, event);
             }
+            Response::GoingAway { drain_deadline_ms } => {
+                payload.extend_from_slice(&drain_deadline_ms.to_le_bytes());
+            }
         }
 
         Self::wrap_frame(opcode, &payload)
@@ -211,11 +276,84 @@ impl MfbpCodec {
                 buf.extend_from_slice(&stats.total_energy.to_le_bytes());
                 buf.push(if stats.is_frozen { 1 } else { 0 });
                 buf.extend_from_slice(&stats.uptime_secs.to_le_bytes());
+                buf.extend_from_slice(&stats.tuner.mean.to_le_bytes());
+                buf.extend_from_slice(&stats.tuner.std_dev.to_le_bytes());
+                buf.extend_from_slice(&(stats.tuner.sample_count as u32).to_le_bytes());
+                buf.push(if stats.tuner.is_warmed_up { 1 } else { 0 });
+                buf.extend_from_slice(&(stats.evicted_count as u32).to_le_bytes());
+                for count in stats.tier_counts {
+                    buf.extend_from_slice(&(count as u32).to_le_bytes());
+                }
+                buf.extend_from_slice(&stats.protection_violations.to_le_bytes());
             }
             ResponseData::SnapshotCreated { name } => {
                 buf.push(0x06);
                 Self::write_string(buf, name);
             }
+            ResponseData::Health(health) => {
+                buf.push(0x07);
+                buf.push(health.status as u8);
+                buf.extend_from_slice(&health.health_energy.to_le_bytes());
+                buf.push(health.warmup as u8);
+                buf.push(health.exhaustion as u8);
+                buf.push(health.recovery as u8);
+                buf.push(health.flags());
+            }
+            ResponseData::Snapshots(list) => {
+                buf.push(0x08);
+                buf.extend_from_slice(&(list.len() as u32).to_le_bytes());
+                for snapshot in list {
+                    buf.extend_from_slice(&snapshot.id.to_le_bytes());
+                    Self::write_string(buf, &snapshot.name);
+                    buf.extend_from_slice(&snapshot.created_at.to_le_bytes());
+                    buf.extend_from_slice(&snapshot.lineage_count.to_le_bytes());
+                    buf.extend_from_slice(&snapshot.bond_count.to_le_bytes());
+                    buf.extend_from_slice(&snapshot.size_bytes.to_le_bytes());
+                    buf.extend_from_slice(&snapshot.parent.unwrap_or(0).to_le_bytes());
+                }
+            }
+            ResponseData::Export(ndjson) => {
+                buf.push(0x09);
+                Self::write_long_string(buf, ndjson);
+            }
+            ResponseData::Imported {
+                lineages,
+                bonds,
+                engrams,
+            } => {
+                buf.push(0x0A);
+                buf.extend_from_slice(&lineages.to_le_bytes());
+                buf.extend_from_slice(&bonds.to_le_bytes());
+                buf.extend_from_slice(&engrams.to_le_bytes());
+            }
+            ResponseData::Cascade(list) => {
+                buf.push(0x0B);
+                buf.extend_from_slice(&(list.len() as u32).to_le_bytes());
+                for entry in list {
+                    Self::write_string(buf, &entry.id);
+                    buf.push(entry.depth);
+                    buf.extend_from_slice(&entry.signal.to_le_bytes());
+                    buf.extend_from_slice(&entry.energy.to_le_bytes());
+                }
+            }
+            ResponseData::Forensic(list) => {
+                buf.push(0x0C);
+                buf.extend_from_slice(&(list.len() as u32).to_le_bytes());
+                for entry in list {
+                    buf.push(entry.status as u8);
+                    Self::encode_lineage_info(buf, &entry.info);
+                    buf.extend_from_slice(&entry.flags.to_le_bytes());
+                    buf.extend_from_slice(&entry.head_index.to_le_bytes());
+                    buf.extend_from_slice(&entry.raw_energy.to_le_bytes());
+                    buf.extend_from_slice(&entry.pressure.to_le_bytes());
+                    buf.extend_from_slice(&(entry.suppressors.len() as u32).to_le_bytes());
+                    for suppressor in &entry.suppressors {
+                        Self::write_string(buf, &suppressor.id);
+                        buf.extend_from_slice(&suppressor.bond_strength.to_le_bytes());
+                        buf.extend_from_slice(&suppressor.energy.to_le_bytes());
+                    }
+                }
+            }
         }
     }
 
@@ -295,6 +433,13 @@ impl MfbpCodec {
         buf.extend_from_slice(bytes);
     }
 
+    /// Like `write_string`, with a u32 length for bulk text (export/import)
+    fn write_long_string(buf: &mut Vec<u8>, s: &str) {
+        let bytes = s.as_bytes();
+        buf.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        buf.extend_from_slice(bytes);
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // DECODING
     // ═══════════════════════════════════════════════════════════════
@@ -352,12 +497,29 @@ impl MfbpCodec {
             }
             OpCode::LineageForget => {
                 let id = Self::read_string(payload, &mut cursor)?;
-                Request::LineageForget { id }
+                // Optional flags (backward compat: default = 0)
+                let flags = if cursor < payload.len() {
+                    payload[cursor]
+                } else {
+                    0
+                };
+                Request::LineageForget { id, flags }
+            }
+            OpCode::LineageProtect => {
+                let id = Self::read_string(payload, &mut cursor)?;
+                let level = Self::read_u8(payload, &mut cursor)?;
+                // Optional flags (backward compat: default = 0)
+                let flags = payload.get(cursor).copied().unwrap_or(0);
+                Request::LineageProtect { id, level, flags }
             }
             OpCode::LineageTouch => {
                 let id = Self::read_string(payload, &mut cursor)?;
                 Request::LineageTouch { id }
             }
+            OpCode::LineageUnlock => {
+                let id = Self::read_string(payload, &mut cursor)?;
+                Request::LineageUnlock { id }
+            }
             OpCode::BondConnect => {
                 let source = Self::read_string(payload, &mut cursor)?;
                 let target = Self::read_string(payload, &mut cursor)?;
@@ -392,23 +554,51 @@ impl MfbpCodec {
             }
             OpCode::BondNeighbors => {
                 let id = Self::read_string(payload, &mut cursor)?;
-                Request::BondNeighbors { id }
+                // Optional flags (backward compat: default = 0)
+                let flags = payload.get(cursor).copied().unwrap_or(0);
+                Request::BondNeighbors { id, flags }
             }
+            OpCode::CascadeRecall
+            | OpCode::CascadeForget
+            | OpCode::CascadeLock
+            | OpCode::CascadeUnlock => {
+                let mode = match opcode {
+                    OpCode::CascadeRecall => CascadeMode::Recall,
+                    OpCode::CascadeForget => CascadeMode::Forget,
+                    OpCode::CascadeLock => CascadeMode::Lock,
+                    _ => CascadeMode::Unlock,
+                };
+                Request::Cascade {
+                    id: Self::read_string(payload, &mut cursor)?,
+                    mode,
+                    strength: Self::read_f32(payload, &mut cursor)?,
+                    max_depth: Self::read_u8(payload, &mut cursor)?,
+                    min_bond_strength: Self::read_f32(payload, &mut cursor)?,
+                }
+            }
+            // Query flags are optional (backward compat: default = 0)
             OpCode::QueryConscious => {
                 let min_energy = Self::read_f32(payload, &mut cursor)?;
-                Request::QueryConscious { min_energy }
+                let flags = payload.get(cursor).copied().unwrap_or(0);
+                Request::QueryConscious { min_energy, flags }
             }
             OpCode::QueryTopK => {
                 let k = Self::read_u32(payload, &mut cursor)?;
-                Request::QueryTopK { k }
+                let flags = payload.get(cursor).copied().unwrap_or(0);
+                Request::QueryTopK { k, flags }
             }
             OpCode::QueryTrauma => {
                 let min_rigidity = Self::read_f32(payload, &mut cursor)?;
-                Request::QueryTrauma { min_rigidity }
+                let flags = payload.get(cursor).copied().unwrap_or(0);
+                Request::QueryTrauma {
+                    min_rigidity,
+                    flags,
+                }
             }
             OpCode::QueryPattern => {
                 let pattern = Self::read_string(payload, &mut cursor)?;
-                Request::QueryPattern { pattern }
+                let flags = payload.get(cursor).copied().unwrap_or(0);
+                Request::QueryPattern { pattern, flags }
             }
             OpCode::SysPing => Request::Ping,
             OpCode::SysStats => Request::Stats,
@@ -437,6 +627,15 @@ impl MfbpCodec {
                 let events_mask = Self::read_u32(payload, &mut cursor)?;
                 Request::Subscribe { events_mask }
             }
+            OpCode::SysHealth => Request::Health,
+            OpCode::SysSnapshotList => Request::SnapshotList,
+            OpCode::SysSnapshotDelete => Request::SnapshotDelete {
+                id: Self::read_u64(payload, &mut cursor)?,
+            },
+            OpCode::SysExport => Request::Export,
+            OpCode::SysImport => Request::Import {
+                ndjson: Self::read_long_string(payload, &mut cursor)?,
+            },
             OpCode::StreamUnsubscribe => Request::Unsubscribe,
             _ => return Err(MfbpError::InvalidOpCode(opcode_byte)),
         };
@@ -444,6 +643,283 @@ impl MfbpCodec {
         Ok(request)
     }
 
+    /// Decode a response from bytes
+    pub fn decode_response(frame: &[u8]) -> Result<Response, MfbpError> {
+        if frame.len() < 5 {
+            return Err(MfbpError::PayloadTooShort);
+        }
+
+        let len = u32::from_le_bytes([frame[0], frame[1], frame[2], frame[3]]) as usize;
+        if len > MAX_FRAME_SIZE {
+            return Err(MfbpError::FrameTooLarge);
+        }
+
+        let opcode_byte = frame[4];
+        let opcode = OpCode::from_byte(opcode_byte).ok_or(MfbpError::InvalidOpCode(opcode_byte))?;
+
+        let payload = &frame[5..];
+        let mut cursor = 0;
+
+        let response = match opcode {
+            OpCode::ResponseOk => Response::Ok(Self::decode_response_data(payload, &mut cursor)?),
+            OpCode::ResponseError => {
+                let code = ErrorCode::from_byte(Self::read_u8(payload, &mut cursor)?);
+                let message = Self::read_string(payload, &mut cursor)?;
+                if code.is_rejection() {
+                    let level = Self::read_exhaustion(payload, &mut cursor)?;
+                    let retry_after_ms = Self::read_u32(payload, &mut cursor)?;
+                    Response::Rejected {
+                        code,
+                        message,
+                        level,
+                        retry_after_ms,
+                    }
+                } else {
+                    Response::Error { code, message }
+                }
+            }
+            OpCode::ResponseEvent => Response::Event(Self::decode_event(payload, &mut cursor)?),
+            OpCode::ResponseGoingAway => Response::GoingAway {
+                drain_deadline_ms: Self::read_u32(payload, &mut cursor)?,
+            },
+            _ => return Err(MfbpError::InvalidOpCode(opcode_byte)),
+        };
+
+        Ok(response)
+    }
+
+    fn decode_response_data(buf: &[u8], cursor: &mut usize) -> Result<ResponseData, MfbpError> {
+        let tag = Self::read_u8(buf, cursor)?;
+        let data = match tag {
+            0x00 => ResponseData::Ack,
+            0x01 => ResponseData::Pong,
+            0x02 => {
+                let status = Self::read_lineage_status(buf, cursor)?;
+                // Info is present only when the encoder had one to send
+                let info = if *cursor < buf.len() {
+                    Some(Self::decode_lineage_info(buf, cursor)?)
+                } else {
+                    None
+                };
+                ResponseData::LineageResult(LineageResult { status, info })
+            }
+            0x03 => {
+                let count = Self::read_u32(buf, cursor)? as usize;
+                let mut list = Vec::with_capacity(count.min(1024));
+                for _ in 0..count {
+                    list.push(Self::decode_lineage_info(buf, cursor)?);
+                }
+                ResponseData::Lineages(list)
+            }
+            0x04 => {
+                let count = Self::read_u32(buf, cursor)? as usize;
+                let mut list = Vec::with_capacity(count.min(1024));
+                for _ in 0..count {
+                    list.push(NeighborInfo {
+                        id: Self::read_string(buf, cursor)?,
+                        bond_strength: Self::read_f32(buf, cursor)?,
+                        is_learned: Self::read_u8(buf, cursor)? != 0,
+                    });
+                }
+                ResponseData::Neighbors(list)
+            }
+            0x05 => ResponseData::Stats(StatsInfo {
+                lineage_count: Self::read_u32(buf, cursor)? as usize,
+                bond_count: Self::read_u32(buf, cursor)? as usize,
+                conscious_count: Self::read_u32(buf, cursor)? as usize,
+                total_energy: Self::read_f32(buf, cursor)?,
+                is_frozen: Self::read_u8(buf, cursor)? != 0,
+                uptime_secs: Self::read_u64(buf, cursor)?,
+                tuner: TunerStats {
+                    mean: Self::read_f32(buf, cursor)?,
+                    std_dev: Self::read_f32(buf, cursor)?,
+                    sample_count: Self::read_u32(buf, cursor)? as usize,
+                    is_warmed_up: Self::read_u8(buf, cursor)? != 0,
+                },
+                evicted_count: Self::read_u32(buf, cursor)? as usize,
+                tier_counts: [
+                    Self::read_u32(buf, cursor)? as usize,
+                    Self::read_u32(buf, cursor)? as usize,
+                    Self::read_u32(buf, cursor)? as usize,
+                    Self::read_u32(buf, cursor)? as usize,
+                ],
+                protection_violations: Self::read_u64(buf, cursor)?,
+            }),
+            0x06 => ResponseData::SnapshotCreated {
+                name: Self::read_string(buf, cursor)?,
+            },
+            0x07 => {
+                let status = match Self::read_u8(buf, cursor)? {
+                    0 => HealthStatus::Healthy,
+                    1 => HealthStatus::Degraded,
+                    2 => HealthStatus::Unhealthy,
+                    v => return Err(MfbpError::InvalidValue(v)),
+                };
+                let health_energy = Self::read_f32(buf, cursor)?;
+                let warmup = match Self::read_u8(buf, cursor)? {
+                    v @ 0..=3 => WarmupState::from(v),
+                    v => return Err(MfbpError::InvalidValue(v)),
+                };
+                let exhaustion = Self::read_exhaustion(buf, cursor)?;
+                let recovery = match Self::read_u8(buf, cursor)? {
+                    0 => RecoveryState::Normal,
+                    1 => RecoveryState::Shock,
+                    2 => RecoveryState::Coma,
+                    v => return Err(MfbpError::InvalidValue(v)),
+                };
+                let flags = Self::read_u8(buf, cursor)?;
+                ResponseData::Health(HealthInfo {
+                    status,
+                    health_energy,
+                    warmup,
+                    exhaustion,
+                    recovery,
+                    store_attached: flags & HealthInfo::STORE_ATTACHED != 0,
+                    store_reachable: flags & HealthInfo::STORE_REACHABLE != 0,
+                    live: flags & HealthInfo::LIVE != 0,
+                    ready: flags & HealthInfo::READY != 0,
+                })
+            }
+            0x08 => {
+                let count = Self::read_u32(buf, cursor)? as usize;
+                let mut list = Vec::with_capacity(count.min(1024));
+                for _ in 0..count {
+                    list.push(SnapshotInfo {
+                        id: Self::read_u64(buf, cursor)?,
+                        name: Self::read_string(buf, cursor)?,
+                        created_at: Self::read_u64(buf, cursor)?,
+                        lineage_count: Self::read_u32(buf, cursor)?,
+                        bond_count: Self::read_u32(buf, cursor)?,
+                        size_bytes: Self::read_u64(buf, cursor)?,
+                        parent: Some(Self::read_u64(buf, cursor)?).filter(|&p| p != 0),
+                    });
+                }
+                ResponseData::Snapshots(list)
+            }
+            0x09 => ResponseData::Export(Self::read_long_string(buf, cursor)?),
+            0x0A => ResponseData::Imported {
+                lineages: Self::read_u32(buf, cursor)?,
+                bonds: Self::read_u32(buf, cursor)?,
+                engrams: Self::read_u32(buf, cursor)?,
+            },
+            0x0B => {
+                let count = Self::read_u32(buf, cursor)? as usize;
+                let mut list = Vec::with_capacity(count.min(1024));
+                for _ in 0..count {
+                    list.push(CascadeEntry {
+                        id: Self::read_string(buf, cursor)?,
+                        depth: Self::read_u8(buf, cursor)?,
+                        signal: Self::read_f32(buf, cursor)?,
+                        energy: Self::read_f32(buf, cursor)?,
+                    });
+                }
+                ResponseData::Cascade(list)
+            }
+            0x0C => {
+                let count = Self::read_u32(buf, cursor)? as usize;
+                let mut list = Vec::with_capacity(count.min(1024));
+                for _ in 0..count {
+                    let status = Self::read_lineage_status(buf, cursor)?;
+                    let info = Self::decode_lineage_info(buf, cursor)?;
+                    let flags = Self::read_u32(buf, cursor)?;
+                    let head_index = Self::read_u32(buf, cursor)?;
+                    let raw_energy = Self::read_f32(buf, cursor)?;
+                    let pressure = Self::read_f32(buf, cursor)?;
+                    let suppressor_count = Self::read_u32(buf, cursor)? as usize;
+                    let mut suppressors = Vec::with_capacity(suppressor_count.min(1024));
+                    for _ in 0..suppressor_count {
+                        suppressors.push(SuppressorInfo {
+                            id: Self::read_string(buf, cursor)?,
+                            bond_strength: Self::read_f32(buf, cursor)?,
+                            energy: Self::read_f32(buf, cursor)?,
+                        });
+                    }
+                    list.push(ForensicInfo {
+                        status,
+                        info,
+                        flags,
+                        head_index,
+                        raw_energy,
+                        pressure,
+                        suppressors,
+                    });
+                }
+                ResponseData::Forensic(list)
+            }
+            v => return Err(MfbpError::InvalidValue(v)),
+        };
+        Ok(data)
+    }
+
+    fn read_lineage_status(buf: &[u8], cursor: &mut usize) -> Result<LineageStatus, MfbpError> {
+        Ok(match Self::read_u8(buf, cursor)? {
+            0 => LineageStatus::Found,
+            1 => LineageStatus::NotFound,
+            2 => LineageStatus::Repressed,
+            3 => LineageStatus::Dormant,
+            4 => LineageStatus::Frozen,
+            v => return Err(MfbpError::InvalidValue(v)),
+        })
+    }
+
+    fn decode_lineage_info(buf: &[u8], cursor: &mut usize) -> Result<LineageInfo, MfbpError> {
+        Ok(LineageInfo {
+            id: Self::read_string(buf, cursor)?,
+            energy: Self::read_f32(buf, cursor)?,
+            threshold: Self::read_f32(buf, cursor)?,
+            decay_rate: Self::read_f32(buf, cursor)?,
+            rigidity: Self::read_f32(buf, cursor)?,
+            is_conscious: Self::read_u8(buf, cursor)? != 0,
+            last_access_ms: Self::read_u64(buf, cursor)?,
+        })
+    }
+
+    fn decode_event(buf: &[u8], cursor: &mut usize) -> Result<Event, MfbpError> {
+        let tag = Self::read_u8(buf, cursor)?;
+        let event = match tag {
+            0x01 => Event::LineageCreated {
+                id: Self::read_string(buf, cursor)?,
+                energy: Self::read_f32(buf, cursor)?,
+            },
+            0x02 => Event::LineageStimulated {
+                id: Self::read_string(buf, cursor)?,
+                new_energy: Self::read_f32(buf, cursor)?,
+                delta: Self::read_f32(buf, cursor)?,
+            },
+            0x03 => Event::LineageForgotten {
+                id: Self::read_string(buf, cursor)?,
+            },
+            0x04 => Event::BondCreated {
+                source: Self::read_string(buf, cursor)?,
+                target: Self::read_string(buf, cursor)?,
+                strength: Self::read_f32(buf, cursor)?,
+            },
+            0x05 => Event::BondSevered {
+                source: Self::read_string(buf, cursor)?,
+                target: Self::read_string(buf, cursor)?,
+            },
+            0x06 => Event::DecayTick {
+                processed: Self::read_u32(buf, cursor)? as usize,
+                dead_count: Self::read_u32(buf, cursor)? as usize,
+            },
+            0x07 => Event::SnapshotCreated {
+                name: Self::read_string(buf, cursor)?,
+            },
+            v => return Err(MfbpError::InvalidValue(v)),
+        };
+        Ok(event)
+    }
+
+    fn read_exhaustion(buf: &[u8], cursor: &mut usize) -> Result<ExhaustionLevel, MfbpError> {
+        match Self::read_u8(buf, cursor)? {
+            0 => Ok(ExhaustionLevel::Normal),
+            1 => Ok(ExhaustionLevel::Elevated),
+            2 => Ok(ExhaustionLevel::Exhausted),
+            3 => Ok(ExhaustionLevel::Emergency),
+            v => Err(MfbpError::InvalidValue(v)),
+        }
+    }
+
     // Helper read functions
     fn read_string(buf: &[u8], cursor: &mut usize) -> Result<String, MfbpError> {
         if *cursor + 2 > buf.len() {
@@ -462,6 +938,18 @@ impl MfbpCodec {
         Ok(s)
     }
 
+    fn read_long_string(buf: &[u8], cursor: &mut usize) -> Result<String, MfbpError> {
+        let len = Self::read_u32(buf, cursor)? as usize;
+        if *cursor + len > buf.len() {
+            return Err(MfbpError::PayloadTooShort);
+        }
+        let s = std::str::from_utf8(&buf[*cursor..*cursor + len])
+            .map_err(|_| MfbpError::InvalidUtf8)?
+            .to_string();
+        *cursor += len;
+        Ok(s)
+    }
+
     fn read_f32(buf: &[u8], cursor: &mut usize) -> Result<f32, MfbpError> {
         if *cursor + 4 > buf.len() {
             return Err(MfbpError::PayloadTooShort);
@@ -490,6 +978,16 @@ impl MfbpCodec {
         Ok(v)
     }
 
+    fn read_u64(buf: &[u8], cursor: &mut usize) -> Result<u64, MfbpError> {
+        if *cursor + 8 > buf.len() {
+            return Err(MfbpError::PayloadTooShort);
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&buf[*cursor..*cursor + 8]);
+        *cursor += 8;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
     fn read_u8(buf: &[u8], cursor: &mut usize) -> Result<u8, MfbpError> {
         if *cursor >= buf.len() {
             return Err(MfbpError::PayloadTooShort);
@@ -569,4 +1067,256 @@ mod tests {
             _ => panic!("Expected BondConnect"),
         }
     }
+
+    #[test]
+    fn test_encode_health_response() {
+        use crate::protocol::HealthInfo;
+        use crate::stability::{ExhaustionLevel, HealthStatus, RecoveryState, WarmupState};
+
+        let request = Request::Health;
+        let encoded = MfbpCodec::encode_request(&request);
+        assert!(matches!(
+            MfbpCodec::decode_request(&encoded).unwrap(),
+            Request::Health
+        ));
+
+        let response = Response::Ok(ResponseData::Health(HealthInfo {
+            status: HealthStatus::Degraded,
+            health_energy: 0.5,
+            warmup: WarmupState::Ready,
+            exhaustion: ExhaustionLevel::Elevated,
+            recovery: RecoveryState::Coma,
+            store_attached: true,
+            store_reachable: true,
+            live: true,
+            ready: false,
+        }));
+        let frame = MfbpCodec::encode_response(&response);
+
+        // [len:4][opcode][tag][status][energy:4][warmup][exhaustion][recovery][flags]
+        assert_eq!(frame.len(), 15);
+        assert_eq!(frame[5], 0x07);
+        assert_eq!(frame[6], HealthStatus::Degraded as u8);
+        assert_eq!(&frame[11..15], &[2, 1, 2, 0b1101]);
+    }
+
+    #[test]
+    fn test_encode_decode_rejected() {
+        use crate::protocol::ErrorCode;
+        use crate::stability::ExhaustionLevel;
+
+        let response = Response::Rejected {
+            code: ErrorCode::ReadOnlyMode,
+            message: "reads only".into(),
+            level: ExhaustionLevel::Exhausted,
+            retry_after_ms: 1500,
+        };
+        let frame = MfbpCodec::encode_response(&response);
+
+        match MfbpCodec::decode_response(&frame).unwrap() {
+            Response::Rejected {
+                code,
+                message,
+                level,
+                retry_after_ms,
+            } => {
+                assert_eq!(code, ErrorCode::ReadOnlyMode);
+                assert_eq!(message, "reads only");
+                assert_eq!(level, ExhaustionLevel::Exhausted);
+                assert_eq!(retry_after_ms, 1500);
+            }
+            other => panic!("Expected Rejected, got {:?}", other),
+        }
+
+        // Plain errors carry no hint
+        let frame = MfbpCodec::encode_response(&Response::Error {
+            code: ErrorCode::LineageNotFound,
+            message: "nope".into(),
+        });
+        assert!(matches!(
+            MfbpCodec::decode_response(&frame).unwrap(),
+            Response::Error {
+                code: ErrorCode::LineageNotFound,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_encode_decode_going_away() {
+        let frame = MfbpCodec::encode_response(&Response::GoingAway {
+            drain_deadline_ms: 5000,
+        });
+        assert_eq!(frame[4], OpCode::ResponseGoingAway as u8);
+        assert!(matches!(
+            MfbpCodec::decode_response(&frame).unwrap(),
+            Response::GoingAway {
+                drain_deadline_ms: 5000
+            }
+        ));
+    }
+
+    #[test]
+    fn test_encode_decode_snapshot_admin() {
+        let frame = MfbpCodec::encode_request(&Request::SnapshotDelete { id: 42 });
+        assert_eq!(frame[4], OpCode::SysSnapshotDelete as u8);
+        assert!(matches!(
+            MfbpCodec::decode_request(&frame).unwrap(),
+            Request::SnapshotDelete { id: 42 }
+        ));
+
+        let frame = MfbpCodec::encode_response(&Response::Ok(ResponseData::Snapshots(vec![
+            SnapshotInfo {
+                id: 7,
+                name: "pre-shutdown".into(),
+                created_at: 1_700_000_000,
+                lineage_count: 12,
+                bond_count: 3,
+                size_bytes: 4096,
+                parent: None,
+            },
+            SnapshotInfo {
+                id: 9,
+                name: "auto-9".into(),
+                created_at: 1_700_000_060,
+                lineage_count: 2,
+                bond_count: 0,
+                size_bytes: 128,
+                parent: Some(7),
+            },
+        ])));
+        match MfbpCodec::decode_response(&frame).unwrap() {
+            Response::Ok(ResponseData::Snapshots(list)) => {
+                assert_eq!(list.len(), 2);
+                assert_eq!(list[0].id, 7);
+                assert_eq!(list[0].name, "pre-shutdown");
+                assert_eq!(list[0].created_at, 1_700_000_000);
+                assert_eq!(list[0].lineage_count, 12);
+                assert_eq!(list[0].bond_count, 3);
+                assert_eq!(list[0].size_bytes, 4096);
+                assert_eq!(list[0].parent, None);
+                assert_eq!(list[1].parent, Some(7));
+            }
+            other => panic!("Expected Snapshots, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_encode_decode_export_import() {
+        // Larger than a u16-prefixed string can carry
+        let ndjson = "{\"type\":\"bond\"}\n".repeat(5000);
+
+        let frame = MfbpCodec::encode_request(&Request::Import {
+            ndjson: ndjson.clone(),
+        });
+        assert_eq!(frame[4], OpCode::SysImport as u8);
+        match MfbpCodec::decode_request(&frame).unwrap() {
+            Request::Import { ndjson: decoded } => assert_eq!(decoded, ndjson),
+            other => panic!("Expected Import, got {:?}", other),
+        }
+
+        let frame = MfbpCodec::encode_response(&Response::Ok(ResponseData::Export(ndjson.clone())));
+        match MfbpCodec::decode_response(&frame).unwrap() {
+            Response::Ok(ResponseData::Export(decoded)) => assert_eq!(decoded, ndjson),
+            other => panic!("Expected Export, got {:?}", other),
+        }
+
+        let frame = MfbpCodec::encode_response(&Response::Ok(ResponseData::Imported {
+            lineages: 3,
+            bonds: 2,
+            engrams: 1,
+        }));
+        assert!(matches!(
+            MfbpCodec::decode_response(&frame).unwrap(),
+            Response::Ok(ResponseData::Imported {
+                lineages: 3,
+                bonds: 2,
+                engrams: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_encode_decode_cascade() {
+        let frame = MfbpCodec::encode_request(&Request::Cascade {
+            id: "root".into(),
+            mode: CascadeMode::Lock,
+            strength: 0.75,
+            max_depth: 2,
+            min_bond_strength: 0.3,
+        });
+        assert_eq!(frame[4], OpCode::CascadeLock as u8);
+        match MfbpCodec::decode_request(&frame).unwrap() {
+            Request::Cascade {
+                id,
+                mode,
+                strength,
+                max_depth,
+                min_bond_strength,
+            } => {
+                assert_eq!(id, "root");
+                assert_eq!(mode, CascadeMode::Lock);
+                assert_eq!((strength, max_depth, min_bond_strength), (0.75, 2, 0.3));
+            }
+            other => panic!("Expected Cascade, got {:?}", other),
+        }
+
+        let frame =
+            MfbpCodec::encode_response(&Response::Ok(ResponseData::Cascade(vec![CascadeEntry {
+                id: "root".into(),
+                depth: 0,
+                signal: 0.75,
+                energy: 0.9,
+            }])));
+        match MfbpCodec::decode_response(&frame).unwrap() {
+            Response::Ok(ResponseData::Cascade(list)) => {
+                assert_eq!(list.len(), 1);
+                assert_eq!((list[0].id.as_str(), list[0].depth), ("root", 0));
+            }
+            other => panic!("Expected Cascade, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_encode_decode_forensic() {
+        let frame = MfbpCodec::encode_request(&Request::QueryTopK { k: 5, flags: 0x07 });
+        assert!(matches!(
+            MfbpCodec::decode_request(&frame).unwrap(),
+            Request::QueryTopK { k: 5, flags: 0x07 }
+        ));
+
+        let info = LineageInfo {
+            id: "shy".into(),
+            energy: 0.3,
+            threshold: 0.5,
+            decay_rate: 0.0,
+            rigidity: 0.0,
+            is_conscious: false,
+            last_access_ms: 42,
+        };
+        let frame =
+            MfbpCodec::encode_response(&Response::Ok(ResponseData::Forensic(vec![ForensicInfo {
+                status: LineageStatus::Repressed,
+                info,
+                flags: 0x21,
+                head_index: 7,
+                raw_energy: 0.35,
+                pressure: 0.36,
+                suppressors: vec![SuppressorInfo {
+                    id: "bully".into(),
+                    bond_strength: 0.8,
+                    energy: 0.9,
+                }],
+            }])));
+        match MfbpCodec::decode_response(&frame).unwrap() {
+            Response::Ok(ResponseData::Forensic(list)) => {
+                assert_eq!(list.len(), 1);
+                assert_eq!(list[0].status, LineageStatus::Repressed);
+                assert_eq!((list[0].flags, list[0].head_index), (0x21, 7));
+                assert_eq!(list[0].info.id, "shy");
+                assert_eq!(list[0].suppressors[0].id, "bully");
+            }
+            other => panic!("Expected Forensic, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,141 @@
+//! Connection Drain - Graceful shutdown coordination
+//!
+//! Shared between the accept loops and every connection task:
+//!
+//! 1. [`ShutdownCoordinator::drain`] flips the coordinator into draining mode
+//! 2. Accept loops stop accepting, idle connections send `GoingAway` and close
+//! 3. The coordinator waits (up to a deadline) for in-flight requests
+//! 4. The caller snapshots under a quiesced lock
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{watch, Notify};
+
+/// Coordinates connection drain across transports
+///
+/// Cheap to clone; all clones share the same state.
+#[derive(Clone)]
+pub struct ShutdownCoordinator {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    draining: watch::Sender<bool>,
+    in_flight: AtomicUsize,
+    idle: Notify,
+}
+
+impl ShutdownCoordinator {
+    /// Create a coordinator in the running state
+    pub fn new() -> Self {
+        let (draining, _) = watch::channel(false);
+        Self {
+            inner: Arc::new(Inner {
+                draining,
+                in_flight: AtomicUsize::new(0),
+                idle: Notify::new(),
+            }),
+        }
+    }
+
+    /// Whether drain has started
+    pub fn is_draining(&self) -> bool {
+        *self.inner.draining.borrow()
+    }
+
+    /// Resolve once drain has started (immediately if it already has)
+    pub async fn draining(&self) {
+        let mut rx = self.inner.draining.subscribe();
+        // The sender lives in `inner`, so this only fails if it was dropped
+        let _ = rx.wait_for(|draining| *draining).await;
+    }
+
+    /// Mark a request as in flight until the guard is dropped
+    pub fn begin_request(&self) -> InFlight {
+        self.inner.in_flight.fetch_add(1, Ordering::AcqRel);
+        InFlight {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+
+    /// Number of requests currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.inner.in_flight.load(Ordering::Acquire)
+    }
+
+    /// Start draining and wait for in-flight requests
+    ///
+    /// Returns `true` if every request finished before `deadline`.
+    pub async fn drain(&self, deadline: Duration) -> bool {
+        self.inner.draining.send_replace(true);
+
+        let settled = async {
+            loop {
+                let idle = self.inner.idle.notified();
+                if self.in_flight() == 0 {
+                    return;
+                }
+                idle.await;
+            }
+        };
+        tokio::time::timeout(deadline, settled).await.is_ok()
+    }
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// RAII marker for an in-flight request
+pub struct InFlight {
+    inner: Arc<Inner>,
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        if self.inner.in_flight.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.inner.idle.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drain_waits_for_in_flight() {
+        let coordinator = ShutdownCoordinator::new();
+        assert!(!coordinator.is_draining());
+
+        let guard = coordinator.begin_request();
+        assert_eq!(coordinator.in_flight(), 1);
+
+        let waiter = coordinator.clone();
+        let notified = tokio::spawn(async move { waiter.draining().await });
+
+        let release = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(guard);
+        });
+
+        assert!(coordinator.drain(Duration::from_secs(5)).await);
+        assert!(coordinator.is_draining());
+        assert_eq!(coordinator.in_flight(), 0);
+        notified.await.unwrap();
+        release.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_drain_deadline() {
+        let coordinator = ShutdownCoordinator::new();
+        let _stuck = coordinator.begin_request();
+
+        assert!(!coordinator.drain(Duration::from_millis(20)).await);
+        assert_eq!(coordinator.in_flight(), 1);
+    }
+}
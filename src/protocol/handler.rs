@@ -2,18 +2,22 @@
 //!
 //! Executes requests against the MindFry database.
 
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use crate::arena::Lineage;
+use crate::arena::{Lineage, LineageId, Protection, Temperature};
 use crate::graph::Bond;
-use crate::stability::WarmupTracker;
+use crate::metrics::Metrics;
+use crate::persistence::AkashicStore;
+use crate::stability::{HealthStatus, RecoveryState, WarmupTracker};
 use crate::MindFry;
 
 use super::message::*;
-use super::Request;
+use super::{PhysicsParam, Request};
+
+/// Antagonist pressure per lineage, computed once per list read
+type Pressures = HashMap<LineageId, f32>;
 
 /// Command handler for MFBP requests
 pub struct CommandHandler {
@@ -24,10 +28,19 @@ pub struct CommandHandler {
     /// Is decay frozen?
     is_frozen: bool,
     /// Exhaustion monitor for backpressure
-    #[allow(dead_code)] // Reserved for operation cost tracking
     exhaustion: crate::stability::ExhaustionMonitor,
+    /// Propagation depth reached by the request in flight
+    propagation_depth: usize,
+    /// Maintenance tick interval (scales retry hints)
+    tick_interval: Duration,
     /// Warmup tracker for progressive availability
     warmup: WarmupTracker,
+    /// Shared metrics registry
+    metrics: Arc<Metrics>,
+    /// Trauma detected at startup (reported by health probes)
+    recovery: RecoveryState,
+    /// Store whose key index answers negative lookups during warmup
+    store: Option<Arc<AkashicStore>>,
 }
 
 impl CommandHandler {
@@ -38,7 +51,12 @@ impl CommandHandler {
             start_time: Instant::now(),
             is_frozen: false,
             exhaustion: crate::stability::ExhaustionMonitor::default(),
+            propagation_depth: 0,
+            tick_interval: Duration::from_secs(1),
             warmup: WarmupTracker::new(),
+            metrics: Arc::new(Metrics::new()),
+            recovery: RecoveryState::Normal,
+            store: None,
         }
     }
 
@@ -49,62 +67,255 @@ impl CommandHandler {
             start_time: Instant::now(),
             is_frozen: false,
             exhaustion: crate::stability::ExhaustionMonitor::default(),
+            propagation_depth: 0,
+            tick_interval: Duration::from_secs(1),
             warmup,
+            metrics: Arc::new(Metrics::new()),
+            recovery: RecoveryState::Normal,
+            store: None,
+        }
+    }
+
+    /// Record into a shared metrics registry
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Report the startup recovery state in health probes
+    pub fn with_recovery(mut self, recovery: RecoveryState) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    /// Interval at which the server restores `_system.state`
+    pub fn with_tick_interval(mut self, interval: Duration) -> Self {
+        self.tick_interval = interval;
+        self
+    }
+
+    /// Answer negative lookups from the store's lineage index while warming up
+    ///
+    /// Only keys the index does not know are answered (`NotFound`); indexed
+    /// keys still get `WarmingUp` until the hot set is in. The index is read
+    /// without the database lock, which resurrection holds until the arenas
+    /// are loaded.
+    pub fn with_store(mut self, store: Arc<AkashicStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Build a liveness/readiness report
+    ///
+    /// Live while the health pulse keeps `_system.health` above
+    /// `Unhealthy`. Ready when also warmed up, not in `Emergency`,
+    /// and the store (if any) accepts writes.
+    pub fn health(&self) -> HealthInfo {
+        use crate::stability::lineages;
+
+        let (health_energy, exhaustion, store) = {
+            let db = self.db.read().unwrap();
+            let health_energy = db.get_system_energy(lineages::HEALTH).unwrap_or(1.0);
+            (health_energy, db.exhaustion_level(), db.store.clone())
+        };
+        let status = HealthStatus::from_energy(health_energy);
+        // Probed outside the db lock: it may hit the disk
+        let store_attached = store.is_some();
+        let store_reachable = store.is_some_and(|store| store.probe_throttled());
+
+        let live = status != HealthStatus::Unhealthy;
+        let ready = live
+            && self.warmup.is_ready()
+            && exhaustion.allows_operations()
+            && (store_reachable || !store_attached);
+
+        HealthInfo {
+            status,
+            health_energy,
+            warmup: self.warmup.state(),
+            exhaustion,
+            recovery: self.recovery,
+            store_attached,
+            store_reachable,
+            live,
+            ready,
         }
     }
 
     /// Handle a request and return a response
     pub fn handle(&mut self, request: Request) -> Response {
+        let opcode = request.opcode();
+        let start = Instant::now();
+        let response = match self.admit(&request) {
+            Some(rejection) if rejection.error_code() == Some(ErrorCode::WarmingUp) => {
+                self.indexed_lookup(&request).unwrap_or(rejection)
+            }
+            Some(rejection) => rejection,
+            None => {
+                // Probes are free so they never perturb what they observe
+                let charged = !matches!(request, Request::Health);
+                let is_write = request.is_write();
+
+                self.propagation_depth = 0;
+                for key in Self::named_keys(&request) {
+                    self.fault_in(key);
+                }
+                let response = self.execute(request);
+                if charged {
+                    self.charge(is_write);
+                }
+                response
+            }
+        };
+
+        self.metrics
+            .record_request(opcode, start.elapsed(), response.error_code());
+
+        response
+    }
+
+    /// Lineages a request addresses by key
+    fn named_keys(request: &Request) -> Vec<&str> {
+        match request {
+            Request::LineageCreate { id, .. }
+            | Request::LineageGet { id, .. }
+            | Request::LineageStimulate { id, .. }
+            | Request::LineageForget { id, .. }
+            | Request::LineageTouch { id }
+            | Request::LineageUnlock { id }
+            | Request::LineageProtect { id, .. }
+            | Request::Cascade { id, .. }
+            | Request::BondNeighbors { id, .. } => vec![id],
+            Request::BondConnect { source, target, .. }
+            | Request::BondReinforce { source, target, .. }
+            | Request::BondSever { source, target } => vec![source, target],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Bring a lineage missing from the arena back before the request runs
+    ///
+    /// Covers lineages still streaming in during resurrection and lineages
+    /// evicted to the cold tier.
+    fn fault_in(&self, key: &str) {
+        let missing = {
+            let db = self.db.read().unwrap();
+            (db.is_resurrecting() || db.evicted_count() > 0)
+                && db.psyche.lookup(self.hash_key(key)).is_none()
+        };
+        if missing {
+            self.db.write().unwrap().fault_in(key);
+        }
+    }
+
+    /// Answer a `LineageGet` for a key the index does not know
+    ///
+    /// Negative lookups only. Indexed keys still wait for the arena: the
+    /// index holds slot IDs, not lineage state, and until the hot set is in
+    /// the snapshot holding that state is still being decoded.
+    fn indexed_lookup(&self, request: &Request) -> Option<Response> {
+        let Request::LineageGet { id, .. } = request else {
+            return None;
+        };
+        let store = self.store.as_ref()?;
+        // Evicted lineages are not indexed but still exist
+        if store.cold_tier().contains(id).unwrap_or(true) {
+            return None;
+        }
+        match store.indexer().get(id) {
+            Ok(None) => Some(Self::missing_result()),
+            Ok(Some(_)) => None,
+            Err(e) => {
+                tracing::warn!("Index lookup for '{}' failed: {}", id, e);
+                None
+            }
+        }
+    }
+
+    /// Queue the cost of the request just executed against `_system.state`
+    fn charge(&self, is_write: bool) {
+        let db = self.db.read().unwrap();
+        let mut cost = self
+            .exhaustion
+            .calculate_cost(is_write, self.propagation_depth);
+        // Post-crash resistance makes writes dearer, throttling the write rate
+        if is_write {
+            cost *= 1.0 + db.resistance();
+        }
+        db.charge(cost);
+    }
+
+    /// Admission control: returns a rejection if the request must not run
+    fn admit(&self, request: &Request) -> Option<Response> {
         // ═══════════════════════════════════════════════════════════════
         // WARMUP CHECK (Progressive Availability)
         // ═══════════════════════════════════════════════════════════════
-        // Allow Ping and Stats during warmup (always accessible)
-        let is_warmup_exempt = matches!(request, Request::Ping | Request::Stats);
-
-        if !is_warmup_exempt && !self.warmup.is_ready() {
-            return Response::Error {
+        // Allow Ping, Stats and Health during warmup (always accessible)
+        let is_warmup_exempt = matches!(request, Request::Ping | Request::Stats | Request::Health);
+        // Once the hot set is in, single-lineage reads are served too
+        let is_partial_read = self.warmup.is_partial()
+            && matches!(
+                request,
+                Request::LineageGet { .. } | Request::BondNeighbors { .. }
+            );
+
+        if !is_warmup_exempt && !is_partial_read && !self.warmup.is_ready() {
+            return Some(Response::Error {
                 code: ErrorCode::WarmingUp,
                 message: "Server warming up - cognitively unavailable".into(),
-            };
+            });
         }
 
         // ═══════════════════════════════════════════════════════════════
         // EXHAUSTION CHECK (Backpressure)
         // ═══════════════════════════════════════════════════════════════
-        let exhaustion_level = {
+        // Thresholds are learned by the shared ExhaustionTuner
+        let (level, energy, thresholds) = {
             let db = self.db.read().unwrap();
-            let energy = db
-                .get_system_energy(crate::stability::lineages::STATE)
-                .unwrap_or(1.0);
-            crate::stability::ExhaustionLevel::from_energy(energy)
+            let energy = db.state_energy();
+            (db.exhaustion_level(), energy, db.exhaustion_thresholds())
         };
 
-        // Check if request is a write operation
-        let is_write = matches!(
-            request,
-            Request::LineageCreate { .. }
-                | Request::LineageStimulate { .. }
-                | Request::LineageForget { .. }
-                | Request::BondConnect { .. }
-                | Request::BondReinforce { .. }
-                | Request::BondSever { .. }
-        );
+        // Reserved namespace is owned by the stability layer
+        if request.is_write() && writes_reserved(request) {
+            return Some(Response::Rejected {
+                code: ErrorCode::Unauthorized,
+                message: "The _system.* namespace is reserved".into(),
+                level,
+                retry_after_ms: 0,
+            });
+        }
 
         // Backpressure based on exhaustion level
-        if !exhaustion_level.allows_operations() {
-            return Response::Error {
-                code: ErrorCode::Internal,
+        // Health probes must answer even in Emergency
+        if !level.allows_operations() && !matches!(request, Request::Health) {
+            return Some(Response::Rejected {
+                code: ErrorCode::Exhausted,
                 message: "System exhausted - try again later".into(),
-            };
+                level,
+                retry_after_ms: self.retry_after_ms(energy, thresholds.exhausted),
+            });
         }
 
-        if is_write && !exhaustion_level.allows_writes() {
-            return Response::Error {
-                code: ErrorCode::Internal,
+        if request.is_write() && !level.allows_writes() {
+            return Some(Response::Rejected {
+                code: ErrorCode::ReadOnlyMode,
                 message: "System exhausted - reads only".into(),
-            };
+                level,
+                retry_after_ms: self.retry_after_ms(energy, thresholds.elevated),
+            });
         }
 
+        None
+    }
+
+    /// Suggested backoff until `_system.state` recovers above `target`
+    fn retry_after_ms(&self, energy: f32, target: f32) -> u32 {
+        let ticks = self.exhaustion.recovery_ticks(energy, target).max(1) as u128;
+        (ticks * self.tick_interval.as_millis()).min(u32::MAX as u128) as u32
+    }
+
+    fn execute(&mut self, request: Request) -> Response {
         match request {
             // ═══════════════════════════════════════════════════════════════
             // LINEAGE OPERATIONS
@@ -125,129 +336,80 @@ impl CommandHandler {
                     };
                 }
 
-                let lineage = Lineage::with_config(energy, threshold, decay_rate);
-                db.psyche.alloc_with_key(key, lineage);
+                let now = db.clock().now_nanos();
+                let lineage = Lineage::with_config_at(energy, threshold, decay_rate, now);
+                let lineage_id = db.psyche.alloc_named(&id, lineage);
+                db.sync_index_insert(&id, lineage_id);
 
                 Response::Ok(ResponseData::Ack)
             }
 
             Request::LineageGet { id, flags } => {
-                use crate::protocol::QueryFlags;
-
                 let query_flags = QueryFlags::from_bits_truncate(flags);
-                let _bypass = query_flags.contains(QueryFlags::BYPASS_FILTERS);
-                let _include_repressed = query_flags.contains(QueryFlags::INCLUDE_REPRESSED);
-                let no_side_effects = query_flags.contains(QueryFlags::NO_SIDE_EFFECTS);
+                let key = self.hash_key(&id);
 
                 // Use read or write lock based on side effects
-                if no_side_effects {
+                if query_flags.contains(QueryFlags::NO_SIDE_EFFECTS) {
                     let db = self.db.read().unwrap();
-                    let key = self.hash_key(&id);
-
-                    match db.psyche.lookup(key) {
-                        Some(lineage_id) => match db.psyche.get(lineage_id) {
-                            Some(lineage) => {
-                                // TODO: Check antagonism suppression here
-                                use crate::protocol::{LineageResult, LineageStatus};
-                                Response::Ok(ResponseData::LineageResult(LineageResult {
-                                    status: LineageStatus::Found,
-                                    info: Some(LineageInfo {
-                                        id,
-                                        energy: lineage.current_energy(),
-                                        threshold: lineage.threshold,
-                                        decay_rate: lineage.decay_rate,
-                                        rigidity: lineage.rigidity,
-                                        is_conscious: lineage.is_conscious(),
-                                        last_access_ms: lineage.last_access / 1_000_000,
-                                    }),
-                                }))
-                            }
-                            None => {
-                                use crate::protocol::{LineageResult, LineageStatus};
-                                Response::Ok(ResponseData::LineageResult(LineageResult {
-                                    status: LineageStatus::NotFound,
-                                    info: None,
-                                }))
-                            }
-                        },
-                        None => {
-                            use crate::protocol::{LineageResult, LineageStatus};
-                            Response::Ok(ResponseData::LineageResult(LineageResult {
-                                status: LineageStatus::NotFound,
-                                info: None,
-                            }))
-                        }
-                    }
+                    Self::lineage_result(&db, key, id, query_flags)
                 } else {
-                    // Observer effect: stimulate on read
-                    let mut db = self.db.write().unwrap();
-                    let key = self.hash_key(&id);
+                    let mut guard = self.db.write().unwrap();
+                    let db = &mut *guard;
+                    let now = db.clock().now_nanos();
+                    let visible = db.psyche.lookup(key).filter(|&lineage_id| {
+                        db.psyche.get(lineage_id).is_some_and(|lineage| {
+                            let status =
+                                Self::read_status(db, lineage_id, lineage, query_flags, now);
+                            Self::reveals(status, query_flags)
+                        })
+                    });
 
-                    match db.psyche.lookup(key) {
-                        Some(lineage_id) => match db.psyche.get_mut(lineage_id) {
-                            Some(lineage) => {
-                                // Observer effect: reading strengthens memory
-                                lineage.stimulate(0.01);
-
-                                use crate::protocol::{LineageResult, LineageStatus};
-                                Response::Ok(ResponseData::LineageResult(LineageResult {
-                                    status: LineageStatus::Found,
-                                    info: Some(LineageInfo {
-                                        id,
-                                        energy: lineage.current_energy(),
-                                        threshold: lineage.threshold,
-                                        decay_rate: lineage.decay_rate,
-                                        rigidity: lineage.rigidity,
-                                        is_conscious: lineage.is_conscious(),
-                                        last_access_ms: lineage.last_access / 1_000_000,
-                                    }),
-                                }))
-                            }
-                            None => {
-                                use crate::protocol::{LineageResult, LineageStatus};
-                                Response::Ok(ResponseData::LineageResult(LineageResult {
-                                    status: LineageStatus::NotFound,
-                                    info: None,
-                                }))
-                            }
-                        },
-                        None => {
-                            use crate::protocol::{LineageResult, LineageStatus};
-                            Response::Ok(ResponseData::LineageResult(LineageResult {
-                                status: LineageStatus::NotFound,
-                                info: None,
-                            }))
+                    // Observer effect: reading strengthens memory
+                    // (hidden lineages stay untouched, protected ones are only read)
+                    let delta = db.observer_delta();
+                    if let Some(lineage) = visible.and_then(|id| db.psyche.get_mut(id)) {
+                        if lineage.protection().allows_modification(false) {
+                            lineage.stimulate_at(delta, now);
                         }
                     }
+                    Self::lineage_result(db, key, id, query_flags)
                 }
             }
 
             Request::LineageStimulate { id, delta, flags } => {
-                use crate::dynamics::SynapseEngine;
+                use crate::dynamics::{SynapseConfig, SynapseEngine};
                 use crate::protocol::StimulateFlags;
 
                 let stim_flags = StimulateFlags::from_bits_truncate(flags);
                 let no_propagate = stim_flags.contains(StimulateFlags::NO_PROPAGATE);
+                let overridden = stim_flags.contains(StimulateFlags::OVERRIDE_PROTECTION);
 
                 let mut db = self.db.write().unwrap();
                 let key = self.hash_key(&id);
+                let now = db.clock().now_nanos();
 
                 match db.psyche.lookup(key) {
                     Some(lineage_id) => {
                         // Phase 1: Stimulate the target
-                        let found = match db.psyche.get_mut(lineage_id) {
+                        let protection = match db.psyche.get_mut(lineage_id) {
                             Some(lineage) => {
-                                lineage.stimulate(delta);
-                                true
+                                let protection = lineage.protection();
+                                if protection.allows_modification(overridden) {
+                                    lineage.stimulate_at(delta, now);
+                                }
+                                protection
+                            }
+                            None => {
+                                return Response::Error {
+                                    code: ErrorCode::LineageNotFound,
+                                    message: format!("Lineage '{}' not found", id),
+                                };
                             }
-                            None => false,
                         };
 
-                        if !found {
-                            return Response::Error {
-                                code: ErrorCode::LineageNotFound,
-                                message: format!("Lineage '{}' not found", id),
-                            };
+                        if !protection.allows_modification(overridden) {
+                            db.record_protection_violation(&id, "stimulate");
+                            return Self::protected_error(&id, protection);
                         }
 
                         // Phase 2: Propagate (after first borrow ends)
@@ -257,19 +419,28 @@ impl CommandHandler {
                                 id,
                                 delta
                             );
-                            // Use a temporary synapse engine
-                            let synapse = SynapseEngine::new();
+                            // Use a temporary synapse engine, depth-limited by resistance
+                            let synapse = SynapseEngine::with_config(SynapseConfig {
+                                max_depth: db.propagation_depth(),
+                                ..db.synapse.config().clone()
+                            });
                             // SAFETY: bonds is read-only during propagate, psyche is mutated.
                             // We use raw pointers to bypass Rust's borrow checker limitation
                             // with RwLockWriteGuard which doesn't allow partial borrows.
                             let bonds_ptr = &db.bonds as *const _;
-                            let affected = synapse.propagate(
+                            let stats = synapse.propagate_traced_at(
                                 &mut db.psyche,
                                 unsafe { &*bonds_ptr },
                                 lineage_id,
                                 delta,
+                                now,
+                            );
+                            self.propagation_depth = stats.depth;
+                            tracing::debug!(
+                                "[Propagate] Affected {} nodes (depth {})",
+                                stats.affected,
+                                stats.depth
                             );
-                            tracing::debug!("[Propagate] Affected {} nodes", affected);
                         }
 
                         Response::Ok(ResponseData::Ack)
@@ -281,13 +452,29 @@ impl CommandHandler {
                 }
             }
 
-            Request::LineageForget { id } => {
+            Request::LineageForget { id, flags } => {
+                use crate::protocol::ForgetFlags;
+
+                let forget_flags = ForgetFlags::from_bits_truncate(flags);
                 let mut db = self.db.write().unwrap();
                 let key = self.hash_key(&id);
 
                 match db.psyche.lookup(key) {
                     Some(lineage_id) => {
+                        let protection = db
+                            .psyche
+                            .get(lineage_id)
+                            .map_or(Protection::Normal, |l| l.protection());
+                        if !protection.allows_deletion(
+                            forget_flags.contains(ForgetFlags::CONFIRM),
+                            forget_flags.contains(ForgetFlags::OVERRIDE_PROTECTION),
+                        ) {
+                            db.record_protection_violation(&id, "forget");
+                            return Self::protected_error(&id, protection);
+                        }
+
                         if db.psyche.free(lineage_id) {
+                            db.sync_index_remove(&id);
                             Response::Ok(ResponseData::Ack)
                         } else {
                             Response::Error {
@@ -308,9 +495,16 @@ impl CommandHandler {
                 let key = self.hash_key(&id);
 
                 match db.psyche.lookup(key) {
-                    Some(lineage_id) => match db.psyche.get_mut(lineage_id) {
-                        Some(lineage) => {
-                            lineage.touch();
+                    Some(lineage_id) => match db.psyche.get(lineage_id).map(|l| l.protection()) {
+                        Some(protection) if !protection.allows_modification(false) => {
+                            db.record_protection_violation(&id, "touch");
+                            Self::protected_error(&id, protection)
+                        }
+                        Some(_) => {
+                            let now = db.clock().now_nanos();
+                            if let Some(lineage) = db.psyche.get_mut(lineage_id) {
+                                lineage.touch_at(now);
+                            }
                             Response::Ok(ResponseData::Ack)
                         }
                         None => Response::Error {
@@ -325,6 +519,62 @@ impl CommandHandler {
                 }
             }
 
+            Request::LineageUnlock { id } => {
+                let mut db = self.db.write().unwrap();
+                let key = self.hash_key(&id);
+                let now = db.clock().now_nanos();
+
+                match db.psyche.lookup(key).and_then(|lid| db.psyche.get_mut(lid)) {
+                    Some(lineage) if !lineage.protection().allows_modification(false) => {
+                        let protection = lineage.protection();
+                        db.record_protection_violation(&id, "unlock");
+                        Self::protected_error(&id, protection)
+                    }
+                    Some(lineage) => {
+                        if lineage.is_frozen() {
+                            lineage.set_temperature_at(Temperature::Warm, now);
+                        }
+                        Response::Ok(ResponseData::Ack)
+                    }
+                    None => Response::Error {
+                        code: ErrorCode::LineageNotFound,
+                        message: format!("Lineage '{}' not found", id),
+                    },
+                }
+            }
+
+            Request::LineageProtect { id, level, flags } => {
+                use crate::protocol::ProtectFlags;
+
+                let overridden = ProtectFlags::from_bits_truncate(flags)
+                    .contains(ProtectFlags::OVERRIDE_PROTECTION);
+                let Some(level) = Protection::from_byte(level) else {
+                    return Response::Error {
+                        code: ErrorCode::MalformedPayload,
+                        message: format!("Invalid protection level {}", level),
+                    };
+                };
+                let mut db = self.db.write().unwrap();
+                let key = self.hash_key(&id);
+                let now = db.clock().now_nanos();
+
+                match db.psyche.lookup(key).and_then(|lid| db.psyche.get_mut(lid)) {
+                    Some(lineage) if !lineage.protection().allows_change(level, overridden) => {
+                        let protection = lineage.protection();
+                        db.record_protection_violation(&id, "lower");
+                        Self::protected_error(&id, protection)
+                    }
+                    Some(lineage) => {
+                        lineage.set_protection_at(level, now);
+                        Response::Ok(ResponseData::Ack)
+                    }
+                    None => Response::Error {
+                        code: ErrorCode::LineageNotFound,
+                        message: format!("Lineage '{}' not found", id),
+                    },
+                }
+            }
+
             // ═══════════════════════════════════════════════════════════════
             // BOND OPERATIONS
             // ═══════════════════════════════════════════════════════════════
@@ -360,7 +610,15 @@ impl CommandHandler {
                     }
                 };
 
-                let mut bond = Bond::new(src_id, tgt_id, strength);
+                if let Some(refused) = Self::guard_endpoints(
+                    &mut db,
+                    [(&source, src_id), (&target, tgt_id)],
+                    "connect",
+                ) {
+                    return refused;
+                }
+
+                let mut bond = Bond::new_at(src_id, tgt_id, strength, db.clock().now_nanos());
                 // Apply polarity
                 bond.polarity = match polarity {
                     1 => Trit::True,    // Synergy
@@ -370,7 +628,11 @@ impl CommandHandler {
                 };
 
                 match db.bonds.connect(bond) {
-                    Some(_) => Response::Ok(ResponseData::Ack),
+                    Some(_) => {
+                        db.psyche.mark_dirty(src_id);
+                        db.psyche.mark_dirty(tgt_id);
+                        Response::Ok(ResponseData::Ack)
+                    }
                     None => Response::Error {
                         code: ErrorCode::Internal,
                         message: "Failed to create bond".into(),
@@ -407,10 +669,21 @@ impl CommandHandler {
                     }
                 };
 
+                if let Some(refused) = Self::guard_endpoints(
+                    &mut db,
+                    [(&source, src_id), (&target, tgt_id)],
+                    "reinforce a bond of",
+                ) {
+                    return refused;
+                }
+
+                let now = db.clock().now_nanos();
                 match db.bonds.find_bond(src_id, tgt_id) {
                     Some(bond_id) => {
                         if let Some(bond) = db.bonds.get_mut(bond_id) {
-                            bond.reinforce(delta);
+                            bond.reinforce_at(delta, now);
+                            db.psyche.mark_dirty(src_id);
+                            db.psyche.mark_dirty(tgt_id);
                             Response::Ok(ResponseData::Ack)
                         } else {
                             Response::Error {
@@ -451,9 +724,19 @@ impl CommandHandler {
                     }
                 };
 
+                if let Some(refused) = Self::guard_endpoints(
+                    &mut db,
+                    [(&source, src_id), (&target, tgt_id)],
+                    "sever a bond of",
+                ) {
+                    return refused;
+                }
+
                 match db.bonds.find_bond(src_id, tgt_id) {
                     Some(bond_id) => {
                         db.bonds.disconnect(bond_id);
+                        db.psyche.mark_dirty(src_id);
+                        db.psyche.mark_dirty(tgt_id);
                         Response::Ok(ResponseData::Ack)
                     }
                     None => Response::Error {
@@ -463,134 +746,203 @@ impl CommandHandler {
                 }
             }
 
-            Request::BondNeighbors { id } => {
+            Request::BondNeighbors { id, flags } => {
+                let query_flags = QueryFlags::from_bits_truncate(flags);
                 let db = self.db.read().unwrap();
                 let key = self.hash_key(&id);
+                let now = db.clock().now_nanos();
 
-                match db.psyche.lookup(key) {
-                    Some(lineage_id) => {
-                        let neighbors: Vec<NeighborInfo> = db
-                            .bonds
-                            .neighbors_with_strength(lineage_id)
-                            .map(|(neighbor_id, strength)| {
-                                // TODO: Reverse lookup ID to string
-                                // For now, use numeric ID as string
-                                NeighborInfo {
-                                    id: format!("lineage_{}", neighbor_id.0),
-                                    bond_strength: strength,
-                                    is_learned: false, // TODO: Track this
-                                }
-                            })
-                            .collect();
+                let Some((lineage_id, lineage)) = db
+                    .psyche
+                    .lookup(key)
+                    .and_then(|lineage_id| db.psyche.get(lineage_id).map(|l| (lineage_id, l)))
+                else {
+                    return Response::Error {
+                        code: ErrorCode::LineageNotFound,
+                        message: format!("Lineage '{}' not found", id),
+                    };
+                };
 
-                        Response::Ok(ResponseData::Neighbors(neighbors))
-                    }
-                    None => Response::Error {
+                // The same gate as LineageGet: a hidden lineage has no neighbourhood
+                let status = Self::read_status(&db, lineage_id, lineage, query_flags, now);
+                if !Self::reveals(status, query_flags) {
+                    return match status {
+                        LineageStatus::Frozen => Self::frozen_result(),
+                        _ => Self::missing_result(),
+                    };
+                }
+
+                let pressures = Self::query_pressures(&db, query_flags, now);
+                let neighbors: Vec<NeighborInfo> = db
+                    .bonds
+                    .neighbors_with_strength(lineage_id)
+                    .filter(|&(neighbor_id, _)| {
+                        db.psyche.get(neighbor_id).is_some_and(|l| {
+                            let status =
+                                Self::listed_status(&pressures, neighbor_id, l, query_flags, now);
+                            Self::reveals(status, query_flags)
+                        })
+                    })
+                    .map(|(neighbor_id, strength)| NeighborInfo {
+                        id: Self::display_key(&db, neighbor_id),
+                        bond_strength: strength,
+                        is_learned: false, // TODO: Track this
+                    })
+                    .collect();
+
+                Response::Ok(ResponseData::Neighbors(neighbors))
+            }
+
+            Request::Cascade {
+                id,
+                mode,
+                strength,
+                max_depth,
+                min_bond_strength,
+            } => {
+                use crate::dynamics::{CascadeBounds, SynapseConfig, SynapseEngine};
+
+                if !strength.is_finite() || strength < 0.0 || !min_bond_strength.is_finite() {
+                    return Response::Error {
+                        code: ErrorCode::MalformedPayload,
+                        message: "Cascade bounds must be finite and non-negative".into(),
+                    };
+                }
+
+                let mut guard = self.db.write().unwrap();
+                let db = &mut *guard;
+                let Some(source) = db.psyche.lookup(self.hash_key(&id)) else {
+                    return Response::Error {
                         code: ErrorCode::LineageNotFound,
                         message: format!("Lineage '{}' not found", id),
+                    };
+                };
+
+                // Depth-limited by resistance, like propagation
+                let synapse = SynapseEngine::with_config(SynapseConfig {
+                    max_depth: db.propagation_depth(),
+                    ..db.synapse.config().clone()
+                });
+                let now = db.clock().now_nanos();
+                let hits = synapse.cascade(
+                    &mut db.psyche,
+                    &db.bonds,
+                    source,
+                    mode,
+                    CascadeBounds {
+                        strength,
+                        max_depth: max_depth as usize,
+                        min_bond_strength,
                     },
-                }
+                    now,
+                );
+                self.propagation_depth = hits.iter().map(|h| h.depth).max().unwrap_or(0);
+                tracing::debug!("[Cascade] {:?} from '{}' changed {}", mode, id, hits.len());
+
+                let entries = hits
+                    .iter()
+                    .map(|hit| CascadeEntry {
+                        id: Self::display_key(db, hit.id),
+                        depth: hit.depth as u8,
+                        signal: hit.signal,
+                        energy: db
+                            .psyche
+                            .get(hit.id)
+                            .map_or(0.0, |l| l.current_energy_at(now)),
+                    })
+                    .collect();
+                Response::Ok(ResponseData::Cascade(entries))
             }
 
             // ═══════════════════════════════════════════════════════════════
             // QUERY OPERATIONS
             // ═══════════════════════════════════════════════════════════════
-            Request::QueryConscious { min_energy } => {
+            Request::QueryConscious { min_energy, flags } => {
                 use crate::setun::Trit;
 
-                let db = self.db.read().unwrap();
-
-                // Use Cortex for ternary consciousness evaluation
-                // Lucid (+1) or Dreaming (0) count as "aware"
-                let lineages: Vec<LineageInfo> = db
-                    .psyche
-                    .iter()
-                    .filter(|(_, l)| {
-                        // First check energy threshold
-                        if l.current_energy() < min_energy {
-                            return false;
-                        }
+                let query_flags = QueryFlags::from_bits_truncate(flags);
+                let bypass = query_flags.contains(QueryFlags::BYPASS_FILTERS);
+
+                self.list_query(query_flags, |db, pressures, now| {
+                    db.psyche
+                        .iter()
+                        .filter_map(|(id, l)| {
+                            // First check energy threshold
+                            let energy = l.current_energy_at(now);
+                            if energy < min_energy {
+                                return None;
+                            }
 
-                        // Use Cortex to evaluate consciousness state
-                        let state = db
-                            .cortex
-                            .consciousness_state(l.current_energy() as f64, l.threshold as f64);
+                            // Use Cortex for ternary consciousness evaluation
+                            // (BYPASS_FILTERS ignores the mood and takes raw energy)
+                            let lucid = if bypass {
+                                energy >= l.threshold
+                            } else {
+                                let state = db
+                                    .cortex
+                                    .consciousness_state(energy as f64, l.threshold as f64);
+                                // Accept Lucid (+1) and Dreaming (0), reject Dormant (-1)
+                                if state == Trit::False {
+                                    return None;
+                                }
+                                state == Trit::True
+                            };
 
-                        // Accept Lucid (+1) and Dreaming (0), reject Dormant (-1)
-                        state != Trit::False
-                    })
-                    .map(|(id, l)| {
-                        // Calculate ternary state for response
-                        let state = db
-                            .cortex
-                            .consciousness_state(l.current_energy() as f64, l.threshold as f64);
-
-                        LineageInfo {
-                            id: format!("lineage_{}", id.0),
-                            energy: l.current_energy(),
-                            threshold: l.threshold,
-                            decay_rate: l.decay_rate,
-                            rigidity: l.rigidity,
+                            let status = Self::listed_status(pressures, id, l, query_flags, now);
+                            if !Self::reveals(status, query_flags) {
+                                return None;
+                            }
+                            let mut info = Self::lineage_info(Self::display_key(db, id), l, now);
                             // True if Lucid (+1), false if Dreaming (0)
-                            is_conscious: state == Trit::True,
-                            last_access_ms: l.last_access / 1_000_000,
-                        }
-                    })
-                    .collect();
-
-                Response::Ok(ResponseData::Lineages(lineages))
+                            info.is_conscious = lucid;
+                            Some((id, info))
+                        })
+                        .collect()
+                })
             }
 
-            Request::QueryTopK { k } => {
-                let db = self.db.read().unwrap();
-                let mut lineages: Vec<_> = db
-                    .psyche
-                    .iter()
-                    .map(|(id, l)| (id, l.current_energy()))
-                    .collect();
+            Request::QueryTopK { k, flags } => {
+                let query_flags = QueryFlags::from_bits_truncate(flags);
 
-                lineages.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
-
-                let top_k: Vec<LineageInfo> = lineages
-                    .into_iter()
-                    .take(k as usize)
-                    .filter_map(|(id, _)| {
-                        db.psyche.get(id).map(|l| LineageInfo {
-                            id: format!("lineage_{}", id.0),
-                            energy: l.current_energy(),
-                            threshold: l.threshold,
-                            decay_rate: l.decay_rate,
-                            rigidity: l.rigidity,
-                            is_conscious: l.is_conscious(),
-                            last_access_ms: l.last_access / 1_000_000,
+                self.list_query(query_flags, |db, pressures, now| {
+                    let mut found: Vec<_> = db
+                        .psyche
+                        .iter()
+                        .filter(|&(id, l)| {
+                            let status = Self::listed_status(pressures, id, l, query_flags, now);
+                            Self::reveals(status, query_flags)
                         })
-                    })
-                    .collect();
+                        .map(|(id, l)| (id, Self::lineage_info(Self::display_key(db, id), l, now)))
+                        .collect();
 
-                Response::Ok(ResponseData::Lineages(top_k))
+                    found.sort_by(|a, b| b.1.energy.partial_cmp(&a.1.energy).unwrap());
+                    found.truncate(k as usize);
+                    found
+                })
             }
 
-            Request::QueryTrauma { min_rigidity } => {
-                let db = self.db.read().unwrap();
-                let traumatized: Vec<LineageInfo> = db
-                    .psyche
-                    .iter()
-                    .filter(|(_, l)| l.rigidity >= min_rigidity)
-                    .map(|(id, l)| LineageInfo {
-                        id: format!("lineage_{}", id.0),
-                        energy: l.current_energy(),
-                        threshold: l.threshold,
-                        decay_rate: l.decay_rate,
-                        rigidity: l.rigidity,
-                        is_conscious: l.is_conscious(),
-                        last_access_ms: l.last_access / 1_000_000,
-                    })
-                    .collect();
+            Request::QueryTrauma {
+                min_rigidity,
+                flags,
+            } => {
+                let query_flags = QueryFlags::from_bits_truncate(flags);
 
-                Response::Ok(ResponseData::Lineages(traumatized))
+                self.list_query(query_flags, |db, pressures, now| {
+                    db.psyche
+                        .iter()
+                        .filter(|&(id, l)| {
+                            l.rigidity >= min_rigidity
+                                && Self::reveals(
+                                    Self::listed_status(pressures, id, l, query_flags, now),
+                                    query_flags,
+                                )
+                        })
+                        .map(|(id, l)| (id, Self::lineage_info(Self::display_key(db, id), l, now)))
+                        .collect()
+                })
             }
 
-            Request::QueryPattern { pattern: _pattern } => {
+            Request::QueryPattern { .. } => {
                 // TODO: Implement pattern matching
                 Response::Error {
                     code: ErrorCode::Internal,
@@ -605,13 +957,16 @@ impl CommandHandler {
 
             Request::Stats => {
                 let db = self.db.read().unwrap();
+                let now = db.clock().now_nanos();
+                let mut tier_counts = [0usize; 4];
                 let stats = db
                     .psyche
                     .iter()
                     .fold((0usize, 0f32), |(conscious, energy), (_, l)| {
+                        tier_counts[l.temperature() as usize] += 1;
                         (
                             conscious + if l.is_conscious() { 1 } else { 0 },
-                            energy + l.current_energy(),
+                            energy + l.current_energy_at(now),
                         )
                     });
 
@@ -622,27 +977,31 @@ impl CommandHandler {
                     total_energy: stats.1,
                     is_frozen: self.is_frozen,
                     uptime_secs: self.start_time.elapsed().as_secs(),
+                    tuner: db.exhaustion_tuner.get_stats(),
+                    evicted_count: db.evicted_count(),
+                    tier_counts,
+                    protection_violations: db.protection_violations(),
                 }))
             }
 
             Request::Snapshot { name } => {
-                let db = self.db.read().unwrap();
+                // Capture under the lock, serialize after releasing it
+                let captured = {
+                    let mut db = self.db.write().unwrap();
+                    db.store.clone().map(|store| (store, db.checkpoint()))
+                };
 
                 // Check if store is attached
-                if let Some(ref store) = db.store {
+                if let Some((store, checkpoint)) = captured {
                     use crate::persistence::snapshot::PhysicsSnapshot;
 
                     let physics = PhysicsSnapshot::default();
+                    let start = Instant::now();
 
-                    match store.take_snapshot(
-                        Some(&name),
-                        &db.psyche,
-                        &db.strata,
-                        &db.bonds,
-                        Some(&db.cortex),
-                        physics,
-                    ) {
+                    match store.write_checkpoint(Some(&name), &checkpoint, physics) {
                         Ok(meta) => {
+                            self.metrics
+                                .record_snapshot(start.elapsed(), meta.size_bytes);
                             tracing::info!(
                                 "📸 Snapshot '{}' saved ({} lineages, {} bonds)",
                                 name,
@@ -652,6 +1011,9 @@ impl CommandHandler {
                             Response::Ok(ResponseData::SnapshotCreated { name })
                         }
                         Err(e) => {
+                            // The checkpoint was never written: don't chain deltas on it
+                            self.db.write().unwrap().invalidate_checkpoint_chain();
+                            self.metrics.record_snapshot_failure();
                             tracing::error!("Failed to save snapshot: {}", e);
                             Response::Error {
                                 code: ErrorCode::Internal,
@@ -683,6 +1045,14 @@ impl CommandHandler {
                                     db.psyche = psyche;
                                     db.strata = strata;
                                     db.bonds = bonds;
+                                    // The old chain no longer describes this state
+                                    db.invalidate_checkpoint_chain();
+                                    db.rebuild_index();
+                                    db.reconcile_cold_tier();
+                                    // A restored state may be snapshotted and pruned again
+                                    if let Some(ref store) = db.store {
+                                        store.release();
+                                    }
 
                                     // Restore Cortex if available
                                     if let Some(ref cortex_data) = snapshot.cortex_data {
@@ -721,18 +1091,140 @@ impl CommandHandler {
                 }
             }
 
+            Request::SnapshotList => {
+                let db = self.db.read().unwrap();
+
+                match db
+                    .store
+                    .as_ref()
+                    .map(|store| (store, store.list_snapshots()))
+                {
+                    Some((store, Ok(metas))) => Response::Ok(ResponseData::Snapshots(
+                        metas
+                            .into_iter()
+                            .map(|meta| SnapshotInfo {
+                                parent: store.parent_of(meta.id).ok().flatten().map(|l| l.parent),
+                                id: meta.id,
+                                name: meta.name.unwrap_or_default(),
+                                created_at: meta.created_at,
+                                lineage_count: meta.lineage_count,
+                                bond_count: meta.bond_count,
+                                size_bytes: meta.size_bytes,
+                            })
+                            .collect(),
+                    )),
+                    Some((_, Err(e))) => Response::Error {
+                        code: ErrorCode::Internal,
+                        message: format!("Snapshot list failed: {}", e),
+                    },
+                    // No store attached - nothing to list
+                    None => Response::Ok(ResponseData::Snapshots(Vec::new())),
+                }
+            }
+
+            Request::SnapshotDelete { id } => {
+                let db = self.db.read().unwrap();
+
+                if let Some(ref store) = db.store {
+                    match store.delete_snapshot(id) {
+                        Ok(true) => {
+                            tracing::info!("🗑️ Snapshot {} deleted", id);
+                            Response::Ok(ResponseData::Ack)
+                        }
+                        Ok(false) => Response::Error {
+                            code: ErrorCode::SnapshotNotFound,
+                            message: format!("Snapshot {} not found", id),
+                        },
+                        Err(e) => Response::Error {
+                            code: ErrorCode::Internal,
+                            message: format!("Snapshot delete failed: {}", e),
+                        },
+                    }
+                } else {
+                    Response::Error {
+                        code: ErrorCode::Internal,
+                        message: "No storage attached".into(),
+                    }
+                }
+            }
+
+            Request::Export => {
+                let db = self.db.read().unwrap();
+                let mut out = Vec::new();
+                match db.export_ndjson(&mut out) {
+                    Ok(summary) => {
+                        tracing::info!(
+                            "📤 Exported {} lineages, {} bonds, {} engrams",
+                            summary.lineages,
+                            summary.bonds,
+                            summary.engrams
+                        );
+                        match String::from_utf8(out) {
+                            Ok(ndjson) => Response::Ok(ResponseData::Export(ndjson)),
+                            Err(e) => Response::Error {
+                                code: ErrorCode::Internal,
+                                message: format!("Export failed: {}", e),
+                            },
+                        }
+                    }
+                    Err(e) => Response::Error {
+                        code: ErrorCode::Internal,
+                        message: format!("Export failed: {}", e),
+                    },
+                }
+            }
+
+            Request::Import { ndjson } => {
+                let mut db = self.db.write().unwrap();
+                match db.import_ndjson(ndjson.as_bytes()) {
+                    Ok(summary) => {
+                        tracing::info!(
+                            "📥 Imported {} lineages, {} bonds, {} engrams",
+                            summary.lineages,
+                            summary.bonds,
+                            summary.engrams
+                        );
+                        Response::Ok(ResponseData::Imported {
+                            lineages: summary.lineages,
+                            bonds: summary.bonds,
+                            engrams: summary.engrams,
+                        })
+                    }
+                    Err(crate::persistence::ImportError::Io(e)) => Response::Error {
+                        code: ErrorCode::Internal,
+                        message: format!("Import failed: {}", e),
+                    },
+                    // Nothing was applied; the payload itself is at fault
+                    Err(e) => Response::Error {
+                        code: ErrorCode::MalformedPayload,
+                        message: format!("Import rejected: {}", e),
+                    },
+                }
+            }
+
             Request::Freeze { frozen } => {
                 self.is_frozen = frozen;
                 Response::Ok(ResponseData::Ack)
             }
 
-            Request::PhysicsTune {
-                param: _param,
-                value: _value,
-            } => {
-                // TODO: Implement physics tuning
-                Response::Ok(ResponseData::Ack)
-            }
+            Request::PhysicsTune { param, value } => match PhysicsParam::from_byte(param) {
+                Some(PhysicsParam::ObserverDelta) => {
+                    if !value.is_finite() || value < 0.0 {
+                        return Response::Error {
+                            code: ErrorCode::MalformedPayload,
+                            message: "Observer delta must be finite and non-negative".into(),
+                        };
+                    }
+                    self.db.write().unwrap().set_observer_delta(value);
+                    Response::Ok(ResponseData::Ack)
+                }
+                // TODO: Implement the remaining physics parameters
+                Some(_) => Response::Ok(ResponseData::Ack),
+                None => Response::Error {
+                    code: ErrorCode::MalformedPayload,
+                    message: format!("Unknown physics parameter 0x{:02X}", param),
+                },
+            },
 
             Request::MoodSet { mood } => {
                 let mut db = self.db.write().unwrap();
@@ -740,6 +1232,8 @@ impl CommandHandler {
                 Response::Ok(ResponseData::Ack)
             }
 
+            Request::Health => Response::Ok(ResponseData::Health(self.health())),
+
             // ═══════════════════════════════════════════════════════════════
             // STREAM OPERATIONS
             // ═══════════════════════════════════════════════════════════════
@@ -757,17 +1251,282 @@ impl CommandHandler {
         }
     }
 
-    /// Hash a string key using DefaultHasher
+    /// Hash a string key the way `PsycheArena` does
     fn hash_key(&self, key: &str) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        key.hash(&mut hasher);
-        hasher.finish()
+        crate::arena::key_hash(key)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Error for a write refused by a lineage's protection level
+    fn protected_error(id: &str, protection: Protection) -> Response {
+        Response::Error {
+            code: ErrorCode::Protected,
+            message: format!("Lineage '{}' is {:?}-protected", id, protection),
+        }
+    }
+
+    /// Refuse a bond write touching a lineage that forbids modification
+    fn guard_endpoints(
+        db: &mut MindFry,
+        endpoints: [(&str, LineageId); 2],
+        action: &str,
+    ) -> Option<Response> {
+        for (key, id) in endpoints {
+            let protection = db
+                .psyche
+                .get(id)
+                .map_or(Protection::Normal, |l| l.protection());
+            if !protection.allows_modification(false) {
+                db.record_protection_violation(key, action);
+                return Some(Self::protected_error(key, protection));
+            }
+        }
+        None
+    }
+
+    /// LineageGet result for a lineage hidden in the Frozen tier
+    fn frozen_result() -> Response {
+        Response::Ok(ResponseData::LineageResult(LineageResult {
+            status: LineageStatus::Frozen,
+            info: None,
+        }))
+    }
+
+    /// LineageGet result for a missing (or repressed and hidden) lineage
+    fn missing_result() -> Response {
+        Response::Ok(ResponseData::LineageResult(LineageResult {
+            status: LineageStatus::NotFound,
+            info: None,
+        }))
+    }
+
+    /// Status a read with `flags` sees: Frozen tier first, then repression
+    /// by conscious antagonists (skipped by BYPASS_FILTERS)
+    fn read_status(
+        db: &MindFry,
+        id: LineageId,
+        lineage: &Lineage,
+        flags: QueryFlags,
+        now: u64,
+    ) -> LineageStatus {
+        Self::status_under(lineage, flags, now, || {
+            db.synapse
+                .suppression(&db.psyche, &db.bonds, id, now)
+                .pressure
+        })
+    }
+
+    /// [`Self::read_status`] against pressures computed once per query
+    fn listed_status(
+        pressures: &Pressures,
+        id: LineageId,
+        lineage: &Lineage,
+        flags: QueryFlags,
+        now: u64,
+    ) -> LineageStatus {
+        Self::status_under(lineage, flags, now, || {
+            pressures.get(&id).copied().unwrap_or(0.0)
+        })
+    }
+
+    /// Status under the antagonist `pressure`, only weighed when it matters
+    fn status_under(
+        lineage: &Lineage,
+        flags: QueryFlags,
+        now: u64,
+        pressure: impl FnOnce() -> f32,
+    ) -> LineageStatus {
+        if lineage.is_frozen() {
+            return LineageStatus::Frozen;
+        }
+        if !flags.contains(QueryFlags::BYPASS_FILTERS)
+            && pressure() > lineage.current_energy_at(now)
+        {
+            return LineageStatus::Repressed;
+        }
+        LineageStatus::Found
+    }
+
+    /// Antagonist pressures for a query over many lineages (none under BYPASS_FILTERS)
+    fn query_pressures(db: &MindFry, flags: QueryFlags, now: u64) -> Pressures {
+        if flags.contains(QueryFlags::BYPASS_FILTERS) {
+            return Pressures::new();
+        }
+        db.synapse.pressures(&db.psyche, &db.bonds, now)
+    }
+
+    /// Whether a read with `flags` returns a lineage in `status`
+    fn reveals(status: LineageStatus, flags: QueryFlags) -> bool {
+        status == LineageStatus::Found || flags.contains(QueryFlags::INCLUDE_REPRESSED)
+    }
+
+    /// Client key of a lineage, or a synthetic `lineage_<slot>` when it has none
+    fn display_key(db: &MindFry, id: LineageId) -> String {
+        db.psyche
+            .key_of(id)
+            .map_or_else(|| format!("lineage_{}", id.0), str::to_string)
+    }
+
+    /// Public view of a lineage as of `now`
+    fn lineage_info(id: String, lineage: &Lineage, now: u64) -> LineageInfo {
+        LineageInfo {
+            id,
+            energy: lineage.current_energy_at(now),
+            threshold: lineage.threshold,
+            decay_rate: lineage.decay_rate,
+            rigidity: lineage.rigidity,
+            is_conscious: lineage.is_conscious(),
+            last_access_ms: lineage.last_access / 1_000_000,
+        }
+    }
+
+    /// Full internal state of a lineage, with the status a plain read sees
+    fn forensic_info(
+        db: &MindFry,
+        id: LineageId,
+        lineage: &Lineage,
+        info: LineageInfo,
+        now: u64,
+    ) -> ForensicInfo {
+        let suppression = db.synapse.suppression(&db.psyche, &db.bonds, id, now);
+        let status = if lineage.is_frozen() {
+            LineageStatus::Frozen
+        } else if suppression.represses(info.energy) {
+            LineageStatus::Repressed
+        } else {
+            LineageStatus::Found
+        };
+        ForensicInfo {
+            status,
+            info,
+            flags: lineage.flags.bits().into(),
+            head_index: lineage.head_index,
+            raw_energy: lineage.energy,
+            pressure: suppression.pressure,
+            suppressors: suppression
+                .suppressors
+                .iter()
+                .map(|s| SuppressorInfo {
+                    id: Self::display_key(db, s.id),
+                    bond_strength: s.strength,
+                    energy: s.energy,
+                })
+                .collect(),
+        }
+    }
+
+    /// LineageGet answer under `flags`
+    fn lineage_result(db: &MindFry, key: u64, id: String, flags: QueryFlags) -> Response {
+        let now = db.clock().now_nanos();
+        let Some((lineage_id, lineage)) = db
+            .psyche
+            .lookup(key)
+            .and_then(|lineage_id| db.psyche.get(lineage_id).map(|l| (lineage_id, l)))
+        else {
+            return Self::missing_result();
+        };
+
+        let status = Self::read_status(db, lineage_id, lineage, flags, now);
+        if !Self::reveals(status, flags) {
+            // Frozen lineages admit they exist, repressed ones stay hidden
+            return match status {
+                LineageStatus::Frozen => Self::frozen_result(),
+                _ => Self::missing_result(),
+            };
+        }
+
+        let info = Self::lineage_info(id, lineage, now);
+        if flags.contains(QueryFlags::FORENSIC) {
+            let forensic = Self::forensic_info(db, lineage_id, lineage, info, now);
+            return Response::Ok(ResponseData::Forensic(vec![forensic]));
+        }
+        Response::Ok(ResponseData::LineageResult(LineageResult {
+            status,
+            info: Some(info),
+        }))
+    }
+
+    /// Run a list query, reinforcing its results under REINFORCE
+    ///
+    /// Reinforcing takes the write lock and adds the observer delta to
+    /// each returned lineage that allows modification; otherwise the
+    /// query runs under the read lock and leaves memory untouched.
+    fn list_query(
+        &self,
+        flags: QueryFlags,
+        select: impl FnOnce(&MindFry, &Pressures, u64) -> Vec<(LineageId, LineageInfo)>,
+    ) -> Response {
+        let reinforce =
+            flags.contains(QueryFlags::REINFORCE) && !flags.contains(QueryFlags::NO_SIDE_EFFECTS);
+        if !reinforce {
+            let db = self.db.read().unwrap();
+            let now = db.clock().now_nanos();
+            let pressures = Self::query_pressures(&db, flags, now);
+            let found = select(&db, &pressures, now);
+            return Self::lineage_list(&db, found, flags, now);
+        }
+
+        let mut guard = self.db.write().unwrap();
+        let db = &mut *guard;
+        let now = db.clock().now_nanos();
+        let pressures = Self::query_pressures(db, flags, now);
+        let mut found = select(db, &pressures, now);
+        let delta = db.observer_delta();
+        for (id, info) in &mut found {
+            if let Some(lineage) = db.psyche.get_mut(*id) {
+                if lineage.protection().allows_modification(false) {
+                    lineage.stimulate_at(delta, now);
+                    info.energy = lineage.current_energy_at(now);
+                }
+            }
+        }
+        Self::lineage_list(db, found, flags, now)
+    }
+
+    /// List query answer: plain infos, or full state under FORENSIC
+    fn lineage_list(
+        db: &MindFry,
+        found: Vec<(LineageId, LineageInfo)>,
+        flags: QueryFlags,
+        now: u64,
+    ) -> Response {
+        if flags.contains(QueryFlags::FORENSIC) {
+            let forensic = found
+                .into_iter()
+                .filter_map(|(id, info)| {
+                    let lineage = db.psyche.get(id)?;
+                    Some(Self::forensic_info(db, id, lineage, info, now))
+                })
+                .collect();
+            return Response::Ok(ResponseData::Forensic(forensic));
+        }
+        Response::Ok(ResponseData::Lineages(
+            found.into_iter().map(|(_, info)| info).collect(),
+        ))
+    }
+}
+
+/// Whether a write names a lineage in the reserved `_system.*` namespace
+fn writes_reserved(request: &Request) -> bool {
+    use crate::stability::lineages::PREFIX;
+
+    match request {
+        Request::LineageCreate { id, .. }
+        | Request::LineageStimulate { id, .. }
+        | Request::LineageForget { id, .. }
+        | Request::LineageProtect { id, .. }
+        | Request::Cascade { id, .. } => id.starts_with(PREFIX),
+        Request::BondConnect { source, target, .. }
+        | Request::BondReinforce { source, target, .. }
+        | Request::BondSever { source, target } => {
+            source.starts_with(PREFIX) || target.starts_with(PREFIX)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     fn setup_handler() -> CommandHandler {
         let db = Arc::new(RwLock::new(MindFry::new()));
@@ -858,4 +1617,1120 @@ mod tests {
             _ => panic!("Expected Stats"),
         }
     }
+
+    #[test]
+    fn test_handle_records_metrics() {
+        let metrics = Arc::new(Metrics::new());
+        let mut handler = setup_handler().with_metrics(metrics.clone());
+
+        handler.handle(Request::Ping);
+        handler.handle(Request::LineageForget {
+            id: "missing".into(),
+            flags: 0,
+        });
+
+        assert_eq!(metrics.request_count(crate::protocol::OpCode::SysPing), 1);
+        assert_eq!(metrics.error_count(ErrorCode::LineageNotFound), 1);
+    }
+
+    #[test]
+    fn test_health_during_warmup() {
+        let db = Arc::new(RwLock::new(MindFry::new()));
+        db.write().unwrap().bootstrap_system_lineages();
+        let warmup = WarmupTracker::new();
+        warmup.begin_resurrection();
+        let mut handler =
+            CommandHandler::with_warmup(db, warmup.clone()).with_recovery(RecoveryState::Shock);
+
+        match handler.handle(Request::Health) {
+            Response::Ok(ResponseData::Health(health)) => {
+                assert_eq!(health.status, HealthStatus::Healthy);
+                assert_eq!(health.recovery, RecoveryState::Shock);
+                assert!(!health.store_attached);
+                assert!(health.live);
+                assert!(!health.ready); // Still resurrecting
+            }
+            other => panic!("Expected Health, got {:?}", other),
+        }
+
+        warmup.mark_ready();
+        assert!(handler.health().ready);
+    }
+
+    #[test]
+    fn test_requests_drain_system_state() {
+        use crate::stability::lineages;
+
+        let mut handler = setup_handler();
+        handler.db.write().unwrap().bootstrap_system_lineages();
+        let state = |h: &CommandHandler| h.db.read().unwrap().state_energy();
+
+        handler.handle(Request::Ping);
+        let after_read = state(&handler);
+        assert!(after_read < 1.0);
+
+        handler.handle(Request::LineageCreate {
+            id: "a".into(),
+            energy: 0.5,
+            threshold: 0.5,
+            decay_rate: 0.001,
+        });
+        let after_write = state(&handler);
+        // Writes cost double
+        assert!(after_read - after_write > (1.0 - after_read) * 1.5);
+
+        // Probes are free
+        handler.handle(Request::Health);
+        assert!((state(&handler) - after_write).abs() < 1e-4);
+
+        // Costs are only queued; the maintenance tick drains the lineage
+        let mut db = handler.db.write().unwrap();
+        assert!(db.get_system_energy(lineages::STATE).unwrap() > 1.0 - 1e-4);
+        db.settle_charges();
+        assert_eq!(db.pending_cost(), 0.0);
+        assert!((db.get_system_energy(lineages::STATE).unwrap() - after_write).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_backpressure_rejections() {
+        use crate::stability::{lineages, ExhaustionLevel};
+
+        let mut handler = setup_handler().with_tick_interval(Duration::from_millis(200));
+        handler.db.write().unwrap().bootstrap_system_lineages();
+
+        // Reserved namespace
+        match handler.handle(Request::LineageForget {
+            id: lineages::HEALTH.into(),
+            flags: 0,
+        }) {
+            Response::Rejected { code, .. } => assert_eq!(code, ErrorCode::Unauthorized),
+            other => panic!("Expected Unauthorized, got {:?}", other),
+        }
+
+        // Exhausted (0.3): reads pass, writes get ReadOnlyMode
+        handler
+            .db
+            .write()
+            .unwrap()
+            .stimulate_system(lineages::STATE, -0.7);
+        assert!(matches!(handler.handle(Request::Ping), Response::Ok(_)));
+        match handler.handle(Request::LineageCreate {
+            id: "a".into(),
+            energy: 0.5,
+            threshold: 0.5,
+            decay_rate: 0.001,
+        }) {
+            Response::Rejected {
+                code,
+                level,
+                retry_after_ms,
+                ..
+            } => {
+                assert_eq!(code, ErrorCode::ReadOnlyMode);
+                assert_eq!(level, ExhaustionLevel::Exhausted);
+                assert_eq!(retry_after_ms, 3 * 200); // 0.3 → >0.4 at 0.05/tick
+            }
+            other => panic!("Expected ReadOnlyMode, got {:?}", other),
+        }
+        // Replacing or dropping state is a write too
+        for request in [
+            Request::Import {
+                ndjson: String::new(),
+            },
+            Request::Restore {
+                name: "latest".into(),
+            },
+            Request::SnapshotDelete { id: 1 },
+        ] {
+            assert_eq!(
+                handler.handle(request).error_code(),
+                Some(ErrorCode::ReadOnlyMode)
+            );
+        }
+
+        // Emergency: everything but probes is refused
+        handler
+            .db
+            .write()
+            .unwrap()
+            .stimulate_system(lineages::STATE, -1.0);
+        match handler.handle(Request::Ping) {
+            Response::Rejected { code, level, .. } => {
+                assert_eq!(code, ErrorCode::Exhausted);
+                assert_eq!(level, ExhaustionLevel::Emergency);
+            }
+            other => panic!("Expected Exhausted, got {:?}", other),
+        }
+        assert!(matches!(handler.handle(Request::Health), Response::Ok(_)));
+    }
+
+    #[test]
+    fn test_snapshot_list_and_delete() {
+        use crate::persistence::{AkashicConfig, AkashicStore};
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = AkashicStore::open(AkashicConfig {
+            path: dir.path().to_string_lossy().into_owned(),
+            ..Default::default()
+        })
+        .unwrap();
+        let db = Arc::new(RwLock::new(MindFry::new().with_store(Arc::new(store))));
+        let mut handler = CommandHandler::new(db);
+
+        handler.handle(Request::Snapshot {
+            name: "checkpoint".into(),
+        });
+        let id = match handler.handle(Request::SnapshotList) {
+            Response::Ok(ResponseData::Snapshots(list)) => {
+                assert_eq!(list.len(), 1);
+                assert_eq!(list[0].name, "checkpoint");
+                list[0].id
+            }
+            other => panic!("Expected Snapshots, got {:?}", other),
+        };
+
+        assert!(matches!(
+            handler.handle(Request::SnapshotDelete { id }),
+            Response::Ok(ResponseData::Ack)
+        ));
+        assert_eq!(
+            handler.handle(Request::SnapshotDelete { id }).error_code(),
+            Some(ErrorCode::SnapshotNotFound)
+        );
+        assert!(matches!(
+            handler.handle(Request::SnapshotList),
+            Response::Ok(ResponseData::Snapshots(list)) if list.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_failed_snapshot_breaks_the_delta_chain() {
+        use crate::persistence::{AkashicConfig, AkashicStore};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().into_owned();
+        {
+            let store = AkashicStore::open(AkashicConfig {
+                path: path.clone(),
+                ..Default::default()
+            })
+            .unwrap();
+            let db = Arc::new(RwLock::new(MindFry::new().with_store(Arc::new(store))));
+            CommandHandler::new(db).handle(Request::Snapshot {
+                name: "base".into(),
+            });
+        }
+
+        // A read-only store refuses the write after the checkpoint is taken
+        let store = AkashicStore::open(AkashicConfig {
+            path,
+            read_only: true,
+            ..Default::default()
+        })
+        .unwrap();
+        let db = Arc::new(RwLock::new(MindFry::new().with_store(Arc::new(store))));
+        let mut handler = CommandHandler::new(Arc::clone(&db));
+        assert_eq!(
+            handler
+                .handle(Request::Snapshot {
+                    name: "lost".into(),
+                })
+                .error_code(),
+            Some(ErrorCode::Internal)
+        );
+        assert_eq!(db.read().unwrap().last_checkpoint(), None);
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let mut handler = setup_handler();
+        for id in ["fire", "smoke"] {
+            handler.handle(Request::LineageCreate {
+                id: id.into(),
+                energy: 0.8,
+                threshold: 0.5,
+                decay_rate: 0.001,
+            });
+        }
+        handler.handle(Request::BondConnect {
+            source: "fire".into(),
+            target: "smoke".into(),
+            strength: 0.7,
+            polarity: 1,
+        });
+
+        let ndjson = match handler.handle(Request::Export) {
+            Response::Ok(ResponseData::Export(ndjson)) => ndjson,
+            other => panic!("Expected Export, got {:?}", other),
+        };
+        assert!(ndjson.contains(r#""key":"fire""#));
+
+        handler.handle(Request::LineageForget {
+            id: "fire".into(),
+            flags: 0,
+        });
+        assert!(matches!(
+            handler.handle(Request::Import { ndjson }),
+            Response::Ok(ResponseData::Imported {
+                lineages: 2,
+                bonds: 1,
+                ..
+            })
+        ));
+        match handler.handle(Request::LineageGet {
+            id: "fire".into(),
+            flags: 0,
+        }) {
+            Response::Ok(ResponseData::LineageResult(result)) => {
+                assert_eq!(result.status, LineageStatus::Found)
+            }
+            other => panic!("Expected LineageResult, got {:?}", other),
+        }
+
+        // A rejected import leaves the state alone
+        let count = handler.db.read().unwrap().psyche.len();
+        assert_eq!(
+            handler
+                .handle(Request::Import {
+                    ndjson: "not json".into()
+                })
+                .error_code(),
+            Some(ErrorCode::MalformedPayload)
+        );
+        assert_eq!(handler.db.read().unwrap().psyche.len(), count);
+    }
+
+    #[test]
+    fn test_index_follows_keys_and_serves_warmup() {
+        use crate::persistence::{AkashicConfig, AkashicStore};
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = Arc::new(
+            AkashicStore::open(AkashicConfig {
+                path: dir.path().to_string_lossy().into_owned(),
+                ..Default::default()
+            })
+            .unwrap(),
+        );
+        let db = Arc::new(RwLock::new(MindFry::new().with_store(Arc::clone(&store))));
+        let mut handler = CommandHandler::new(Arc::clone(&db));
+        let create = |id: &str| Request::LineageCreate {
+            id: id.into(),
+            energy: 0.8,
+            threshold: 0.5,
+            decay_rate: 0.001,
+        };
+
+        handler.handle(create("kept"));
+        handler.handle(create("gone"));
+        handler.handle(Request::LineageForget {
+            id: "gone".into(),
+            flags: 0,
+        });
+        assert!(store.indexer().get("kept").unwrap().is_some());
+        assert!(store.indexer().get("gone").unwrap().is_none());
+
+        // Keys written after the snapshot vanish from the index on resurrect
+        handler.handle(Request::Snapshot {
+            name: "base".into(),
+        });
+        handler.handle(create("late"));
+        assert!(store.indexer().get("late").unwrap().is_some());
+        let mut revived = MindFry::new().with_store(Arc::clone(&store));
+        assert!(revived.resurrect().unwrap());
+        assert!(store.indexer().get("late").unwrap().is_none());
+        assert!(store.indexer().get("kept").unwrap().is_some());
+
+        // While warming up, the index rules out unknown keys
+        let warmup = WarmupTracker::new();
+        warmup.begin_resurrection();
+        let mut handler = CommandHandler::with_warmup(db, warmup).with_store(store);
+        let get = |id: &str| Request::LineageGet {
+            id: id.into(),
+            flags: 0,
+        };
+        assert!(matches!(
+            handler.handle(get("nobody")),
+            Response::Ok(ResponseData::LineageResult(LineageResult {
+                status: LineageStatus::NotFound,
+                ..
+            }))
+        ));
+        assert_eq!(
+            handler.handle(get("kept")).error_code(),
+            Some(ErrorCode::WarmingUp)
+        );
+        assert_eq!(
+            handler.handle(create("nobody")).error_code(),
+            Some(ErrorCode::WarmingUp)
+        );
+    }
+
+    #[test]
+    fn test_partial_resurrection_serves_reads() {
+        use crate::persistence::{AkashicConfig, AkashicStore, Resurrection};
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = Arc::new(
+            AkashicStore::open(AkashicConfig {
+                path: dir.path().to_string_lossy().into_owned(),
+                ..Default::default()
+            })
+            .unwrap(),
+        );
+        let db = Arc::new(RwLock::new(MindFry::new().with_store(Arc::clone(&store))));
+        let mut handler = CommandHandler::new(db);
+        let create = |id: &str, energy: f32| Request::LineageCreate {
+            id: id.into(),
+            energy,
+            threshold: 0.5,
+            decay_rate: 0.001,
+        };
+        handler.handle(create("hot", 0.9));
+        handler.handle(create("warm", 0.6));
+        handler.handle(create("cold", 0.2));
+        handler.handle(create("frozen", 0.1));
+        handler.handle(Request::BondConnect {
+            source: "cold".into(),
+            target: "warm".into(),
+            strength: 0.7,
+            polarity: 1,
+        });
+        handler.handle(Request::Snapshot {
+            name: "base".into(),
+        });
+
+        // Only the hottest lineage is restored up front
+        let mut revived = MindFry::new().with_store(Arc::clone(&store));
+        let staged =
+            Resurrection::stage(&store, revived.strata.depth(), revived.clock().now_nanos())
+                .unwrap()
+                .unwrap();
+        revived.begin_resurrection(staged, 1);
+        assert!(revived.is_resurrecting());
+        assert_eq!(revived.psyche.len(), 1);
+
+        let db = Arc::new(RwLock::new(revived));
+        let warmup = WarmupTracker::new();
+        warmup.mark_partial();
+        let mut handler = CommandHandler::with_warmup(Arc::clone(&db), warmup);
+        assert!(matches!(
+            handler.handle(Request::LineageGet {
+                id: "hot".into(),
+                flags: 0,
+            }),
+            Response::Ok(ResponseData::LineageResult(LineageResult {
+                status: LineageStatus::Found,
+                ..
+            }))
+        ));
+
+        // A cold read faults the lineage in with its whole neighborhood
+        match handler.handle(Request::BondNeighbors {
+            id: "cold".into(),
+            flags: 0,
+        }) {
+            Response::Ok(ResponseData::Neighbors(neighbors)) => {
+                assert_eq!(neighbors.len(), 1);
+            }
+            other => panic!("Expected Neighbors, got {:?}", other),
+        }
+        {
+            let db = db.read().unwrap();
+            assert_eq!(db.psyche.len(), 3);
+            assert!(db.psyche.lookup(crate::arena::key_hash("warm")).is_some());
+        }
+
+        // Writes wait for the full state
+        assert_eq!(
+            handler.handle(create("new", 0.5)).error_code(),
+            Some(ErrorCode::WarmingUp)
+        );
+
+        let mut db = db.write().unwrap();
+        assert!(db.resume_resurrection(16));
+        assert!(!db.is_resurrecting());
+        assert_eq!(db.psyche.len(), 4);
+        assert_eq!(db.bonds.len(), 1);
+        let frozen = store.indexer().get("frozen").unwrap().unwrap();
+        assert_eq!(db.psyche.key_of(frozen), Some("frozen"));
+    }
+
+    #[test]
+    fn test_evicted_lineages_fault_back_in() {
+        use crate::arena::Engram;
+        use crate::persistence::{AkashicConfig, AkashicStore, EvictionPolicy};
+        use crate::MindFryConfig;
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = Arc::new(
+            AkashicStore::open(AkashicConfig {
+                path: dir.path().to_string_lossy().into_owned(),
+                ..Default::default()
+            })
+            .unwrap(),
+        );
+        let config = MindFryConfig {
+            max_lineages: 64,
+            max_bonds: 64,
+            strata_depth: 8,
+            eviction: Some(EvictionPolicy {
+                max_energy: 0.3,
+                min_idle_secs: 0,
+                max_per_tick: 16,
+            }),
+            ..Default::default()
+        };
+        let db = Arc::new(RwLock::new(
+            MindFry::with_config(config).with_store(Arc::clone(&store)),
+        ));
+        let mut handler = CommandHandler::new(Arc::clone(&db));
+        let create = |id: &str, energy: f32| Request::LineageCreate {
+            id: id.into(),
+            energy,
+            threshold: 0.5,
+            decay_rate: 0.001,
+        };
+        let connect = |source: &str, target: &str| Request::BondConnect {
+            source: source.into(),
+            target: target.into(),
+            strength: 0.7,
+            polarity: 1,
+        };
+        handler.handle(create("hot", 0.9));
+        handler.handle(create("idle", 0.1));
+        handler.handle(create("frozen", 0.1));
+        handler.handle(connect("hot", "idle"));
+        handler.handle(connect("idle", "frozen"));
+        {
+            let mut db = db.write().unwrap();
+            let id = db.psyche.lookup(crate::arena::key_hash("idle")).unwrap();
+            let head = db.psyche.get(id).unwrap().head_index;
+            let head = db.strata.record(id, head, Engram::new(1, 0.5));
+            db.psyche.get_mut(id).unwrap().head_index = head;
+        }
+
+        // Maintenance moves both cold lineages out, bonds and all
+        let report = db.write().unwrap().maintenance_tick(false);
+        assert_eq!(report.evicted, 2);
+        {
+            let db = db.read().unwrap();
+            assert_eq!(db.psyche.len(), 1);
+            assert_eq!(db.bonds.len(), 0);
+            assert_eq!(db.evicted_count(), 2);
+        }
+        match handler.handle(Request::Stats) {
+            Response::Ok(ResponseData::Stats(stats)) => assert_eq!(stats.evicted_count, 2),
+            other => panic!("Expected Stats, got {:?}", other),
+        }
+
+        // A read faults it back with its history; the bond to a still
+        // evicted partner waits in the partner's record
+        assert!(matches!(
+            handler.handle(Request::LineageGet {
+                id: "idle".into(),
+                flags: 0,
+            }),
+            Response::Ok(ResponseData::LineageResult(LineageResult {
+                status: LineageStatus::Found,
+                ..
+            }))
+        ));
+        {
+            let db = db.read().unwrap();
+            let id = db.psyche.lookup(crate::arena::key_hash("idle")).unwrap();
+            let head = db.psyche.get(id).unwrap().head_index;
+            assert_eq!(db.strata.history(head).count(), 1);
+            assert_eq!(db.bonds.len(), 1);
+            assert_eq!(db.evicted_count(), 1);
+        }
+
+        match handler.handle(Request::BondNeighbors {
+            id: "frozen".into(),
+            flags: 0,
+        }) {
+            Response::Ok(ResponseData::Neighbors(neighbors)) => assert_eq!(neighbors.len(), 1),
+            other => panic!("Expected Neighbors, got {:?}", other),
+        }
+        assert_eq!(db.read().unwrap().bonds.len(), 2);
+
+        // Cold copies go once a checkpoint holds the faulted-in lineages
+        assert_eq!(store.cold_tier().len(), 2);
+        db.write().unwrap().checkpoint();
+        assert!(store.cold_tier().is_empty());
+        assert_eq!(db.read().unwrap().evicted_count(), 0);
+    }
+
+    #[test]
+    fn test_frozen_lineage_requires_unlock() {
+        use crate::protocol::QueryFlags;
+
+        let mut handler = setup_handler();
+        handler.handle(Request::LineageCreate {
+            id: "ice".into(),
+            energy: 0.8,
+            threshold: 0.5,
+            decay_rate: 0.001,
+        });
+        {
+            let mut db = handler.db.write().unwrap();
+            let now = db.clock().now_nanos();
+            let id = db.psyche.lookup(crate::arena::key_hash("ice")).unwrap();
+            db.psyche
+                .get_mut(id)
+                .unwrap()
+                .set_temperature_at(Temperature::Frozen, now);
+        }
+        let get = |flags: QueryFlags| Request::LineageGet {
+            id: "ice".into(),
+            flags: flags.bits(),
+        };
+        let status = |response: Response| match response {
+            Response::Ok(ResponseData::LineageResult(result)) => result.status,
+            other => panic!("Expected LineageResult, got {:?}", other),
+        };
+
+        // Hidden from plain reads, and not stimulated by them
+        assert_eq!(
+            status(handler.handle(get(QueryFlags::NONE))),
+            LineageStatus::Frozen
+        );
+        assert_eq!(
+            status(handler.handle(get(QueryFlags::INCLUDE_REPRESSED))),
+            LineageStatus::Frozen
+        );
+        match handler.handle(Request::Stats) {
+            Response::Ok(ResponseData::Stats(stats)) => assert_eq!(stats.tier_counts[3], 1),
+            _ => panic!("Expected Stats"),
+        }
+
+        assert!(matches!(
+            handler.handle(Request::LineageUnlock { id: "ice".into() }),
+            Response::Ok(ResponseData::Ack)
+        ));
+        assert_eq!(
+            status(handler.handle(get(QueryFlags::NONE))),
+            LineageStatus::Found
+        );
+        assert!(matches!(
+            handler.handle(Request::LineageUnlock { id: "nope".into() }),
+            Response::Error {
+                code: ErrorCode::LineageNotFound,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_protection_levels_are_enforced() {
+        use crate::protocol::{ForgetFlags, StimulateFlags};
+
+        let mut handler = setup_handler();
+        for id in ["vault", "shield", "pin"] {
+            handler.handle(Request::LineageCreate {
+                id: id.into(),
+                energy: 0.5,
+                threshold: 0.5,
+                decay_rate: 0.001,
+            });
+        }
+        for (id, level) in [("vault", 3), ("shield", 2), ("pin", 1)] {
+            assert!(matches!(
+                handler.handle(Request::LineageProtect {
+                    id: id.into(),
+                    level,
+                    flags: 0,
+                }),
+                Response::Ok(ResponseData::Ack)
+            ));
+        }
+        let code = |response: Response| match response {
+            Response::Ok(_) => None,
+            Response::Error { code, .. } => Some(code),
+            other => panic!("Unexpected {:?}", other),
+        };
+        let stimulate = |id: &str, flags: StimulateFlags| Request::LineageStimulate {
+            id: id.into(),
+            delta: 0.2,
+            flags: (flags | StimulateFlags::NO_PROPAGATE).bits(),
+        };
+        let forget = |id: &str, flags: ForgetFlags| Request::LineageForget {
+            id: id.into(),
+            flags: flags.bits(),
+        };
+
+        // Absolute: nothing gets through, not even with an override
+        let all = ForgetFlags::CONFIRM | ForgetFlags::OVERRIDE_PROTECTION;
+        assert_eq!(
+            code(handler.handle(stimulate("vault", StimulateFlags::OVERRIDE_PROTECTION))),
+            Some(ErrorCode::Protected)
+        );
+        assert_eq!(
+            code(handler.handle(forget("vault", all))),
+            Some(ErrorCode::Protected)
+        );
+
+        // Hardened: only with an override
+        assert_eq!(
+            code(handler.handle(stimulate("shield", StimulateFlags::NONE))),
+            Some(ErrorCode::Protected)
+        );
+        assert_eq!(
+            code(handler.handle(stimulate("shield", StimulateFlags::OVERRIDE_PROTECTION))),
+            None
+        );
+
+        // Pinned: modification allowed, deletion needs confirmation
+        assert_eq!(
+            code(handler.handle(stimulate("pin", StimulateFlags::NONE))),
+            None
+        );
+        assert_eq!(
+            code(handler.handle(forget("pin", ForgetFlags::NONE))),
+            Some(ErrorCode::Protected)
+        );
+        assert_eq!(
+            code(handler.handle(forget("pin", ForgetFlags::CONFIRM))),
+            None
+        );
+
+        {
+            let db = handler.db.read().unwrap();
+            assert_eq!(db.protection_violations(), 4);
+            let vault = db.psyche.lookup(crate::arena::key_hash("vault")).unwrap();
+            assert!((db.psyche.get(vault).unwrap().energy - 0.5).abs() < 1e-3);
+        }
+        assert_eq!(
+            code(handler.handle(Request::LineageProtect {
+                id: "vault".into(),
+                level: 9,
+                flags: 0,
+            })),
+            Some(ErrorCode::MalformedPayload)
+        );
+    }
+
+    #[test]
+    fn test_cascades_return_affected_set() {
+        use crate::dynamics::CascadeMode;
+
+        fn cascade(handler: &mut CommandHandler, mode: CascadeMode, max_depth: u8) -> Vec<String> {
+            match handler.handle(Request::Cascade {
+                id: "root".into(),
+                mode,
+                strength: 1.0,
+                max_depth,
+                min_bond_strength: 0.1,
+            }) {
+                Response::Ok(ResponseData::Cascade(list)) => {
+                    list.into_iter().map(|entry| entry.id).collect()
+                }
+                other => panic!("Expected Cascade, got {:?}", other),
+            }
+        }
+
+        fn status(handler: &mut CommandHandler, id: &str) -> LineageStatus {
+            match handler.handle(Request::LineageGet {
+                id: id.into(),
+                flags: 0,
+            }) {
+                Response::Ok(ResponseData::LineageResult(result)) => result.status,
+                other => panic!("Expected LineageResult, got {:?}", other),
+            }
+        }
+
+        let mut handler = setup_handler();
+        for id in ["root", "near", "far", "rival"] {
+            handler.handle(Request::LineageCreate {
+                id: id.into(),
+                energy: 0.5,
+                threshold: 0.5,
+                decay_rate: 0.001,
+            });
+        }
+        for (source, target, polarity) in [
+            ("root", "near", 1),
+            ("near", "far", 1),
+            ("root", "rival", -1),
+        ] {
+            handler.handle(Request::BondConnect {
+                source: source.into(),
+                target: target.into(),
+                strength: 0.9,
+                polarity,
+            });
+        }
+
+        // Antagonistic bonds bound the cluster; the depth bound stops at "near"
+        assert_eq!(
+            cascade(&mut handler, CascadeMode::Recall, 1),
+            ["root", "near"]
+        );
+
+        assert_eq!(
+            cascade(&mut handler, CascadeMode::Lock, 3),
+            ["root", "near", "far"]
+        );
+        assert_eq!(status(&mut handler, "far"), LineageStatus::Frozen);
+        assert_eq!(status(&mut handler, "rival"), LineageStatus::Found);
+
+        // Frozen lineages are out of reach until the cluster is unlocked
+        assert!(cascade(&mut handler, CascadeMode::Forget, 3).is_empty());
+        assert_eq!(cascade(&mut handler, CascadeMode::Unlock, 3).len(), 3);
+        assert_eq!(status(&mut handler, "far"), LineageStatus::Found);
+    }
+
+    #[test]
+    fn test_repressed_lineage_obeys_query_flags() {
+        let mut handler = setup_handler();
+        for (id, energy) in [("shy", 0.3), ("bully", 0.9)] {
+            handler.handle(Request::LineageCreate {
+                id: id.into(),
+                energy,
+                threshold: 0.5,
+                decay_rate: 0.0,
+            });
+        }
+        // Conscious antagonist: 0.9 × 0.8 × 0.5 = 0.36 outweighs 0.3
+        handler.handle(Request::BondConnect {
+            source: "bully".into(),
+            target: "shy".into(),
+            strength: 0.8,
+            polarity: -1,
+        });
+        let get = |flags: QueryFlags| Request::LineageGet {
+            id: "shy".into(),
+            flags: flags.bits(),
+        };
+        let result = |response: Response| match response {
+            Response::Ok(ResponseData::LineageResult(result)) => result,
+            other => panic!("Expected LineageResult, got {:?}", other),
+        };
+
+        // Hidden from plain reads, revealed or unfiltered on request
+        let hidden = result(handler.handle(get(QueryFlags::NONE)));
+        assert_eq!(hidden.status, LineageStatus::NotFound);
+        let revealed = result(handler.handle(get(QueryFlags::INCLUDE_REPRESSED)));
+        assert_eq!(revealed.status, LineageStatus::Repressed);
+        assert!(revealed.info.is_some());
+        let bypassed = result(handler.handle(get(QueryFlags::BYPASS_FILTERS)));
+        assert_eq!(bypassed.status, LineageStatus::Found);
+
+        match handler.handle(get(QueryFlags::FORENSIC)) {
+            Response::Ok(ResponseData::Forensic(list)) => {
+                assert_eq!(list.len(), 1);
+                assert_eq!(list[0].status, LineageStatus::Repressed);
+                assert!((list[0].pressure - 0.36).abs() < 1e-3);
+                assert_eq!(list[0].suppressors.len(), 1);
+                assert_eq!(list[0].suppressors[0].id, "bully");
+            }
+            other => panic!("Expected Forensic, got {:?}", other),
+        }
+
+        // List queries apply the same flags
+        let topk = |handler: &mut CommandHandler, flags: QueryFlags| match handler.handle(
+            Request::QueryTopK {
+                k: 10,
+                flags: flags.bits(),
+            },
+        ) {
+            Response::Ok(ResponseData::Lineages(list)) => list.len(),
+            Response::Ok(ResponseData::Forensic(list)) => list.len(),
+            other => panic!("Expected Lineages, got {:?}", other),
+        };
+        assert_eq!(topk(&mut handler, QueryFlags::NONE), 1);
+        assert_eq!(topk(&mut handler, QueryFlags::INCLUDE_REPRESSED), 2);
+        assert_eq!(topk(&mut handler, QueryFlags::FORENSIC), 2);
+        match handler.handle(Request::QueryConscious {
+            min_energy: 0.0,
+            flags: QueryFlags::BYPASS_FILTERS.bits(),
+        }) {
+            Response::Ok(ResponseData::Lineages(list)) => assert_eq!(list.len(), 2),
+            other => panic!("Expected Lineages, got {:?}", other),
+        }
+        match handler.handle(Request::QueryTopK { k: 10, flags: 0 }) {
+            Response::Ok(ResponseData::Lineages(list)) => assert_eq!(list[0].id, "bully"),
+            other => panic!("Expected Lineages, got {:?}", other),
+        }
+
+        // So do neighbour lists, which name neighbours by key
+        let neighbors = |handler: &mut CommandHandler, flags: QueryFlags| match handler.handle(
+            Request::BondNeighbors {
+                id: "bully".into(),
+                flags: flags.bits(),
+            },
+        ) {
+            Response::Ok(ResponseData::Neighbors(list)) => {
+                list.into_iter().map(|n| n.id).collect::<Vec<_>>()
+            }
+            other => panic!("Expected Neighbors, got {:?}", other),
+        };
+        assert!(neighbors(&mut handler, QueryFlags::NONE).is_empty());
+        assert_eq!(
+            neighbors(&mut handler, QueryFlags::INCLUDE_REPRESSED),
+            ["shy"]
+        );
+        assert!(matches!(
+            handler.handle(Request::BondNeighbors {
+                id: "shy".into(),
+                flags: 0,
+            }),
+            Response::Ok(ResponseData::LineageResult(LineageResult {
+                status: LineageStatus::NotFound,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_observer_delta_and_reinforcing_queries() {
+        let mut handler = setup_handler();
+        handler.handle(Request::LineageCreate {
+            id: "seen".into(),
+            energy: 0.5,
+            threshold: 0.5,
+            decay_rate: 0.0,
+        });
+        assert!(matches!(
+            handler.handle(Request::PhysicsTune {
+                param: PhysicsParam::ObserverDelta as u8,
+                value: 0.1,
+            }),
+            Response::Ok(ResponseData::Ack)
+        ));
+        let energy = |handler: &mut CommandHandler, flags: QueryFlags| match handler.handle(
+            Request::LineageGet {
+                id: "seen".into(),
+                flags: flags.bits(),
+            },
+        ) {
+            Response::Ok(ResponseData::LineageResult(result)) => result.info.unwrap().energy,
+            other => panic!("Expected LineageResult, got {:?}", other),
+        };
+        let topk = |handler: &mut CommandHandler, flags: QueryFlags| match handler.handle(
+            Request::QueryTopK {
+                k: 1,
+                flags: flags.bits(),
+            },
+        ) {
+            Response::Ok(ResponseData::Lineages(list)) => list[0].energy,
+            other => panic!("Expected Lineages, got {:?}", other),
+        };
+
+        // Reads reinforce by the tuned delta unless told not to
+        assert!((energy(&mut handler, QueryFlags::NONE) - 0.6).abs() < 1e-5);
+        assert!((energy(&mut handler, QueryFlags::NO_SIDE_EFFECTS) - 0.6).abs() < 1e-5);
+
+        // List queries only reinforce on request, and never without side effects
+        assert!((topk(&mut handler, QueryFlags::NONE) - 0.6).abs() < 1e-5);
+        for query in [
+            Request::QueryConscious {
+                min_energy: 0.0,
+                flags: 0,
+            },
+            Request::QueryTrauma {
+                min_rigidity: 0.0,
+                flags: 0,
+            },
+        ] {
+            assert!(matches!(
+                handler.handle(query),
+                Response::Ok(ResponseData::Lineages(list)) if list.len() == 1
+            ));
+        }
+        assert!((energy(&mut handler, QueryFlags::NO_SIDE_EFFECTS) - 0.6).abs() < 1e-5);
+        assert!((topk(&mut handler, QueryFlags::REINFORCE) - 0.7).abs() < 1e-5);
+        let analytics = QueryFlags::REINFORCE | QueryFlags::NO_SIDE_EFFECTS;
+        assert!((topk(&mut handler, analytics) - 0.7).abs() < 1e-5);
+        assert!((energy(&mut handler, QueryFlags::NO_SIDE_EFFECTS) - 0.7).abs() < 1e-5);
+
+        for (param, value) in [(PhysicsParam::ObserverDelta as u8, -1.0), (0x7F, 0.1)] {
+            assert!(matches!(
+                handler.handle(Request::PhysicsTune { param, value }),
+                Response::Error {
+                    code: ErrorCode::MalformedPayload,
+                    ..
+                }
+            ));
+        }
+        assert_eq!(handler.db.read().unwrap().observer_delta(), 0.1);
+    }
+
+    #[test]
+    fn test_protection_downgrades_and_side_writes_are_enforced() {
+        use crate::protocol::ProtectFlags;
+
+        let mut handler = setup_handler();
+        for id in ["vault", "shield", "plain"] {
+            handler.handle(Request::LineageCreate {
+                id: id.into(),
+                energy: 0.5,
+                threshold: 0.5,
+                decay_rate: 0.0,
+            });
+        }
+        let protect = |id: &str, level: Protection, flags: ProtectFlags| Request::LineageProtect {
+            id: id.into(),
+            level: level as u8,
+            flags: flags.bits(),
+        };
+        let code = |response: Response| match response {
+            Response::Ok(_) => None,
+            Response::Error { code, .. } => Some(code),
+            other => panic!("Unexpected {:?}", other),
+        };
+        assert_eq!(
+            code(handler.handle(protect("vault", Protection::Absolute, ProtectFlags::NONE))),
+            None
+        );
+        assert_eq!(
+            code(handler.handle(protect("shield", Protection::Hardened, ProtectFlags::NONE))),
+            None
+        );
+
+        // Hardened lineages refuse touches and bond writes
+        assert_eq!(
+            code(handler.handle(Request::LineageTouch {
+                id: "shield".into()
+            })),
+            Some(ErrorCode::Protected)
+        );
+        assert_eq!(
+            code(handler.handle(Request::LineageUnlock {
+                id: "shield".into()
+            })),
+            Some(ErrorCode::Protected)
+        );
+        assert_eq!(
+            code(handler.handle(Request::BondConnect {
+                source: "plain".into(),
+                target: "shield".into(),
+                strength: 0.5,
+                polarity: -1,
+            })),
+            Some(ErrorCode::Protected)
+        );
+        assert!(handler.db.read().unwrap().bonds.is_empty());
+
+        // Downgrades: Hardened needs an override, Absolute never moves
+        assert_eq!(
+            code(handler.handle(protect("shield", Protection::Normal, ProtectFlags::NONE))),
+            Some(ErrorCode::Protected)
+        );
+        let overridden = ProtectFlags::OVERRIDE_PROTECTION;
+        assert_eq!(
+            code(handler.handle(protect("vault", Protection::Normal, overridden))),
+            Some(ErrorCode::Protected)
+        );
+        assert_eq!(
+            code(handler.handle(protect("shield", Protection::Normal, overridden))),
+            None
+        );
+        assert_eq!(
+            code(handler.handle(Request::LineageTouch {
+                id: "shield".into()
+            })),
+            None
+        );
+        assert_eq!(handler.db.read().unwrap().protection_violations(), 5);
+    }
+
+    #[test]
+    fn test_writes_follow_the_db_clock() {
+        use crate::clock::{Clock, ManualClock, NANOS_PER_SEC};
+        use crate::MindFryConfig;
+
+        let clock = Arc::new(ManualClock::new(1_000 * NANOS_PER_SEC));
+        let db = Arc::new(RwLock::new(MindFry::with_config(MindFryConfig {
+            clock: clock.clone(),
+            ..Default::default()
+        })));
+        let mut handler = CommandHandler::new(Arc::clone(&db));
+        for id in ["a", "b"] {
+            handler.handle(Request::LineageCreate {
+                id: id.into(),
+                energy: 0.5,
+                threshold: 0.5,
+                decay_rate: 0.001,
+            });
+        }
+        handler.handle(Request::BondConnect {
+            source: "a".into(),
+            target: "b".into(),
+            strength: 1.0,
+            polarity: 1,
+        });
+        let last_access = |id: &str| {
+            let db = db.read().unwrap();
+            let lineage_id = db.psyche.lookup(crate::arena::key_hash(id)).unwrap();
+            db.psyche.get(lineage_id).unwrap().last_access
+        };
+
+        clock.advance(Duration::from_secs(10));
+        handler.handle(Request::LineageStimulate {
+            id: "a".into(),
+            delta: 0.1,
+            flags: 0,
+        });
+        assert_eq!(last_access("a"), clock.now_nanos());
+        assert_eq!(last_access("b"), clock.now_nanos()); // Via propagation
+
+        clock.advance(Duration::from_secs(10));
+        handler.handle(Request::LineageTouch { id: "b".into() });
+        assert_eq!(last_access("b"), clock.now_nanos());
+    }
+
+    #[test]
+    fn test_created_state_follows_the_db_clock() {
+        use crate::clock::{ManualClock, NANOS_PER_SEC};
+        use crate::MindFryConfig;
+
+        // Far from wall time, so a global-clock stamp would stand out
+        let start = 1_000 * NANOS_PER_SEC;
+        let clock = Arc::new(ManualClock::new(start));
+        let db = Arc::new(RwLock::new(MindFry::with_config(MindFryConfig {
+            clock: clock.clone(),
+            ..Default::default()
+        })));
+        db.write().unwrap().bootstrap_system_lineages();
+        let mut handler = CommandHandler::new(Arc::clone(&db));
+        for id in ["a", "b"] {
+            handler.handle(Request::LineageCreate {
+                id: id.into(),
+                energy: 0.5,
+                threshold: 0.5,
+                decay_rate: 0.001,
+            });
+        }
+        handler.handle(Request::BondConnect {
+            source: "a".into(),
+            target: "b".into(),
+            strength: 0.5,
+            polarity: 1,
+        });
+        let bond_access = || {
+            let db = db.read().unwrap();
+            let a = db.psyche.lookup(crate::arena::key_hash("a")).unwrap();
+            let b = db.psyche.lookup(crate::arena::key_hash("b")).unwrap();
+            let bond = db.bonds.find_bond(a, b).unwrap();
+            db.bonds.get(bond).unwrap().last_access
+        };
+
+        {
+            let db = db.read().unwrap();
+            for key in ["a", crate::stability::lineages::STATE] {
+                let id = db.psyche.lookup(crate::arena::key_hash(key)).unwrap();
+                assert_eq!(db.psyche.get(id).unwrap().last_access, start);
+            }
+        }
+        assert_eq!(bond_access(), start);
+
+        clock.advance(Duration::from_secs(10));
+        handler.handle(Request::BondReinforce {
+            source: "a".into(),
+            target: "b".into(),
+            delta: 0.1,
+        });
+        assert_eq!(bond_access(), start + 10 * NANOS_PER_SEC);
+    }
 }
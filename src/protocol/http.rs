@@ -0,0 +1,1131 @@
+//! HTTP/JSON Gateway
+//!
+//! A thin HTTP/1.1 front-end for clients that cannot speak MFBP.
+//! Every route is translated into the same `Request` the binary codec
+//! produces and executed by a `CommandHandler`, so both transports share
+//! a single code path. Responses are the serde form of `Response`.
+//!
+//! ## Routes
+//!
+//! ```text
+//! GET    /ping
+//! GET    /stats
+//! GET    /health                      full `HealthInfo` report
+//! GET    /livez                       200 when live, 503 otherwise
+//! GET    /readyz                      200 when ready, 503 otherwise
+//! POST   /lineages                    {"id", "energy", "threshold"?, "decay_rate"?}
+//! GET    /lineages/{id}?flags=N
+//! DELETE /lineages/{id}?flags=N
+//! POST   /lineages/{id}/stimulate     {"delta", "flags"?}
+//! POST   /lineages/{id}/touch
+//! POST   /lineages/{id}/unlock        thaw a Frozen lineage
+//! POST   /lineages/{id}/protect       {"level", "flags"?} 0=Normal 1=Pinned 2=Hardened 3=Absolute
+//! GET    /lineages/{id}/neighbors?flags=N
+//! POST   /lineages/{id}/cascade       {"mode": recall|forget|lock|unlock, "strength"?, "max_depth"?, "min_bond_strength"?}
+//! POST   /bonds                       {"source", "target", "strength", "polarity"?}
+//! POST   /bonds/reinforce             {"source", "target", "delta"}
+//! POST   /bonds/sever                 {"source", "target"}
+//! GET    /query/conscious?min_energy=F&flags=N
+//! GET    /query/topk?k=N&flags=N
+//! GET    /query/trauma?min_rigidity=F&flags=N
+//! GET    /query/pattern?pattern=S&flags=N
+//! GET    /snapshots                   newest first
+//! POST   /snapshots                   {"name"}
+//! DELETE /snapshots/{id}
+//! POST   /snapshots/{name}/restore
+//! GET    /export                      NDJSON dump (application/x-ndjson)
+//! POST   /import                      NDJSON body, replaces the whole state
+//! POST   /command                     raw `Request` JSON
+//! GET    /metrics                     Prometheus text format
+//! ```
+
+use std::io;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use super::codec::MAX_FRAME_SIZE;
+use super::{
+    CommandHandler, ErrorCode, LineageStatus, Request, Response, ResponseData, ShutdownCoordinator,
+};
+use crate::dynamics::{CascadeBounds, CascadeMode};
+use crate::metrics::{Metrics, Transport};
+use crate::persistence::AkashicStore;
+use crate::stability::{RecoveryState, WarmupTracker};
+use crate::MindFry;
+
+/// Maximum size of the request line plus headers (16 KB)
+const MAX_HEAD_SIZE: usize = 16 * 1024;
+
+// ═══════════════════════════════════════════════════════════════
+// HTTP MESSAGES
+// ═══════════════════════════════════════════════════════════════
+
+/// A parsed HTTP request
+#[derive(Debug, Clone, Default)]
+pub struct HttpRequest {
+    /// Request method (upper-case)
+    pub method: String,
+    /// Raw path without query string (still percent-encoded)
+    pub path: String,
+    /// Path segments, split on `/` before percent-decoding (empty ones dropped)
+    pub path_segments: Vec<String>,
+    /// Percent-decoded query parameters in order of appearance
+    pub query: Vec<(String, String)>,
+    /// Header name/value pairs (names lower-cased)
+    pub headers: Vec<(String, String)>,
+    /// Raw request body
+    pub body: Vec<u8>,
+    /// Whether the client asked to keep the connection open
+    pub keep_alive: bool,
+}
+
+impl HttpRequest {
+    /// Get a query parameter by name
+    pub fn query_param(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Get a header by (lower-case) name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Decoded path segments, for matching routes
+    pub fn segments(&self) -> Vec<&str> {
+        self.path_segments.iter().map(String::as_str).collect()
+    }
+}
+
+/// An HTTP response ready to be written
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// Status code
+    pub status: u16,
+    /// Content-Type header value
+    pub content_type: &'static str,
+    /// Extra headers
+    pub headers: Vec<(&'static str, String)>,
+    /// Response body
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Build a JSON response
+    pub fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => Self {
+                status,
+                content_type: "application/json",
+                headers: Vec::new(),
+                body,
+            },
+            Err(e) => Self::text(500, format!("Serialization failed: {}", e)),
+        }
+    }
+
+    /// Build a plain-text response
+    pub fn text(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            headers: Vec::new(),
+            body: body.into().into_bytes(),
+        }
+    }
+
+    /// Add a header
+    pub fn with_header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    /// Encode the response as HTTP/1.1 bytes
+    pub fn to_bytes(&self, keep_alive: bool) -> Vec<u8> {
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: {}\r\n",
+            self.status,
+            reason_phrase(self.status),
+            self.content_type,
+            self.body.len(),
+            if keep_alive { "keep-alive" } else { "close" }
+        );
+        for (name, value) in &self.headers {
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        head.push_str("\r\n");
+        let mut out = Vec::with_capacity(head.len() + self.body.len());
+        out.extend_from_slice(head.as_bytes());
+        out.extend_from_slice(&self.body);
+        out
+    }
+}
+
+/// Errors raised while mapping an HTTP request onto a `Request`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HttpError {
+    /// Malformed body, query or path
+    BadRequest(String),
+    /// No route matches the path
+    NotFound,
+    /// Route exists but not for this method
+    MethodNotAllowed,
+}
+
+impl HttpError {
+    /// HTTP status code for this error
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::BadRequest(_) => 400,
+            Self::NotFound => 404,
+            Self::MethodNotAllowed => 405,
+        }
+    }
+
+    fn into_response(self) -> HttpResponse {
+        let status = self.status();
+        let response = Response::Error {
+            code: match self {
+                Self::BadRequest(_) => ErrorCode::MalformedPayload,
+                Self::NotFound | Self::MethodNotAllowed => ErrorCode::InvalidOpCode,
+            },
+            message: self.to_string(),
+        };
+        HttpResponse::json(status, &response)
+    }
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BadRequest(msg) => write!(f, "Bad request: {}", msg),
+            Self::NotFound => write!(f, "No such route"),
+            Self::MethodNotAllowed => write!(f, "Method not allowed"),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        423 => "Locked",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// ROUTING
+// ═══════════════════════════════════════════════════════════════
+
+#[derive(Deserialize)]
+struct CreateBody {
+    id: String,
+    energy: f32,
+    #[serde(default = "default_threshold")]
+    threshold: f32,
+    #[serde(default = "default_decay_rate")]
+    decay_rate: f32,
+}
+
+#[derive(Deserialize)]
+struct StimulateBody {
+    delta: f32,
+    #[serde(default)]
+    flags: u8,
+}
+
+#[derive(Deserialize)]
+struct ProtectBody {
+    level: u8,
+    #[serde(default)]
+    flags: u8,
+}
+
+#[derive(Deserialize)]
+struct ConnectBody {
+    source: String,
+    target: String,
+    strength: f32,
+    #[serde(default = "default_polarity")]
+    polarity: i8,
+}
+
+#[derive(Deserialize)]
+struct ReinforceBody {
+    source: String,
+    target: String,
+    delta: f32,
+}
+
+#[derive(Deserialize)]
+struct SeverBody {
+    source: String,
+    target: String,
+}
+
+#[derive(Deserialize)]
+struct SnapshotBody {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct CascadeBody {
+    mode: CascadeMode,
+    #[serde(default = "default_cascade_strength")]
+    strength: f32,
+    #[serde(default = "default_cascade_depth")]
+    max_depth: u8,
+    #[serde(default = "default_cascade_min_bond")]
+    min_bond_strength: f32,
+}
+
+fn default_threshold() -> f32 {
+    0.5
+}
+
+fn default_decay_rate() -> f32 {
+    0.001
+}
+
+fn default_polarity() -> i8 {
+    1 // Synergy, same default as the binary codec
+}
+
+fn default_cascade_strength() -> f32 {
+    CascadeBounds::default().strength
+}
+
+fn default_cascade_depth() -> u8 {
+    CascadeBounds::default().max_depth as u8
+}
+
+fn default_cascade_min_bond() -> f32 {
+    CascadeBounds::default().min_bond_strength
+}
+
+fn parse_body<'a, T: Deserialize<'a>>(req: &'a HttpRequest) -> Result<T, HttpError> {
+    serde_json::from_slice(&req.body).map_err(|e| HttpError::BadRequest(e.to_string()))
+}
+
+fn parse_query<T: std::str::FromStr>(
+    req: &HttpRequest,
+    name: &str,
+    default: T,
+) -> Result<T, HttpError> {
+    match req.query_param(name) {
+        Some(raw) => raw
+            .parse()
+            .map_err(|_| HttpError::BadRequest(format!("Invalid value for '{}'", name))),
+        None => Ok(default),
+    }
+}
+
+/// Map an HTTP request onto an MFBP `Request`
+pub fn route(req: &HttpRequest) -> Result<Request, HttpError> {
+    let segments = req.segments();
+    let method = req.method.as_str();
+
+    let request = match (method, segments.as_slice()) {
+        ("GET", ["ping"]) => Request::Ping,
+        ("GET", ["stats"]) => Request::Stats,
+        ("GET", ["health"]) => Request::Health,
+
+        // Lineages
+        ("POST", ["lineages"]) => {
+            let body: CreateBody = parse_body(req)?;
+            Request::LineageCreate {
+                id: body.id,
+                energy: body.energy,
+                threshold: body.threshold,
+                decay_rate: body.decay_rate,
+            }
+        }
+        ("GET", ["lineages", id]) => Request::LineageGet {
+            id: id.to_string(),
+            flags: parse_query(req, "flags", 0u8)?,
+        },
+        ("DELETE", ["lineages", id]) => Request::LineageForget {
+            id: id.to_string(),
+            flags: parse_query(req, "flags", 0u8)?,
+        },
+        ("POST", ["lineages", id, "stimulate"]) => {
+            let body: StimulateBody = parse_body(req)?;
+            Request::LineageStimulate {
+                id: id.to_string(),
+                delta: body.delta,
+                flags: body.flags,
+            }
+        }
+        ("POST", ["lineages", id, "touch"]) => Request::LineageTouch { id: id.to_string() },
+        ("POST", ["lineages", id, "unlock"]) => Request::LineageUnlock { id: id.to_string() },
+        ("POST", ["lineages", id, "protect"]) => {
+            let body: ProtectBody = parse_body(req)?;
+            Request::LineageProtect {
+                id: id.to_string(),
+                level: body.level,
+                flags: body.flags,
+            }
+        }
+        ("POST", ["lineages", id, "cascade"]) => {
+            let body: CascadeBody = parse_body(req)?;
+            Request::Cascade {
+                id: id.to_string(),
+                mode: body.mode,
+                strength: body.strength,
+                max_depth: body.max_depth,
+                min_bond_strength: body.min_bond_strength,
+            }
+        }
+        ("GET", ["lineages", id, "neighbors"]) => Request::BondNeighbors {
+            id: id.to_string(),
+            flags: parse_query(req, "flags", 0u8)?,
+        },
+
+        // Bonds
+        ("POST", ["bonds"]) => {
+            let body: ConnectBody = parse_body(req)?;
+            Request::BondConnect {
+                source: body.source,
+                target: body.target,
+                strength: body.strength,
+                polarity: body.polarity,
+            }
+        }
+        ("POST", ["bonds", "reinforce"]) => {
+            let body: ReinforceBody = parse_body(req)?;
+            Request::BondReinforce {
+                source: body.source,
+                target: body.target,
+                delta: body.delta,
+            }
+        }
+        ("POST", ["bonds", "sever"]) => {
+            let body: SeverBody = parse_body(req)?;
+            Request::BondSever {
+                source: body.source,
+                target: body.target,
+            }
+        }
+
+        // Queries
+        ("GET", ["query", "conscious"]) => Request::QueryConscious {
+            min_energy: parse_query(req, "min_energy", 0.5f32)?,
+            flags: parse_query(req, "flags", 0u8)?,
+        },
+        ("GET", ["query", "topk"]) => Request::QueryTopK {
+            k: parse_query(req, "k", 10u32)?,
+            flags: parse_query(req, "flags", 0u8)?,
+        },
+        ("GET", ["query", "trauma"]) => Request::QueryTrauma {
+            min_rigidity: parse_query(req, "min_rigidity", 0.8f32)?,
+            flags: parse_query(req, "flags", 0u8)?,
+        },
+        ("GET", ["query", "pattern"]) => Request::QueryPattern {
+            pattern: req.query_param("pattern").unwrap_or_default().to_string(),
+            flags: parse_query(req, "flags", 0u8)?,
+        },
+
+        // Snapshots
+        ("GET", ["snapshots"]) => Request::SnapshotList,
+        ("DELETE", ["snapshots", id]) => Request::SnapshotDelete {
+            id: id
+                .parse()
+                .map_err(|_| HttpError::BadRequest(format!("Invalid snapshot id: {}", id)))?,
+        },
+        ("POST", ["snapshots"]) => {
+            let body: SnapshotBody = parse_body(req)?;
+            Request::Snapshot { name: body.name }
+        }
+        ("POST", ["snapshots", name, "restore"]) => Request::Restore {
+            name: name.to_string(),
+        },
+
+        // Export / import
+        ("GET", ["export"]) => Request::Export,
+        ("POST", ["import"]) => Request::Import {
+            ndjson: String::from_utf8(req.body.clone())
+                .map_err(|_| HttpError::BadRequest("Body is not valid UTF-8".into()))?,
+        },
+
+        // Raw escape hatch: any request in its serde form
+        ("POST", ["command"]) => parse_body(req)?,
+
+        // Known paths with the wrong method
+        (_, ["ping"])
+        | (_, ["stats"])
+        | (_, ["health"])
+        | (_, ["livez"])
+        | (_, ["readyz"])
+        | (_, ["command"])
+        | (_, ["export"])
+        | (_, ["import"])
+        | (_, ["lineages", ..])
+        | (_, ["bonds", ..])
+        | (_, ["query", "conscious" | "topk" | "trauma" | "pattern"])
+        | (_, ["snapshots", ..]) => return Err(HttpError::MethodNotAllowed),
+
+        _ => return Err(HttpError::NotFound),
+    };
+
+    Ok(request)
+}
+
+/// HTTP status code for a handler response
+pub fn status_for(response: &Response) -> u16 {
+    match response {
+        Response::Ok(ResponseData::LineageResult(result))
+            if result.status == LineageStatus::NotFound =>
+        {
+            404
+        }
+        Response::Ok(ResponseData::LineageResult(result))
+            if result.status == LineageStatus::Frozen && result.info.is_none() =>
+        {
+            423
+        }
+        Response::Ok(_) | Response::Event(_) => 200,
+        Response::GoingAway { .. } => 503,
+        Response::Error { code, .. } | Response::Rejected { code, .. } => match code {
+            ErrorCode::InvalidOpCode | ErrorCode::MalformedPayload => 400,
+            ErrorCode::LineageNotFound | ErrorCode::BondNotFound | ErrorCode::SnapshotNotFound => {
+                404
+            }
+            ErrorCode::LineageExists | ErrorCode::BondExists => 409,
+            ErrorCode::Protected => 423,
+            ErrorCode::WarmingUp | ErrorCode::Exhausted | ErrorCode::ReadOnlyMode => 503,
+            ErrorCode::Unauthorized => 403,
+            ErrorCode::Unknown | ErrorCode::Internal => 500,
+        },
+    }
+}
+
+/// Route and execute a single HTTP request
+pub fn dispatch(handler: &mut CommandHandler, req: &HttpRequest) -> HttpResponse {
+    // Orchestrator probes: status code carries the verdict
+    if req.method == "GET" {
+        let probe = match req.segments().as_slice() {
+            ["livez"] => Some(false),
+            ["readyz"] => Some(true),
+            _ => None,
+        };
+        if let Some(readiness) = probe {
+            let health = handler.health();
+            let ok = if readiness { health.ready } else { health.live };
+            return HttpResponse::json(if ok { 200 } else { 503 }, &health);
+        }
+    }
+
+    match route(req) {
+        Ok(request) => {
+            let response = handler.handle(request);
+            // Exports are served raw so they can be piped straight to a file
+            let response = match response {
+                Response::Ok(ResponseData::Export(ndjson)) => {
+                    return HttpResponse {
+                        status: 200,
+                        content_type: "application/x-ndjson",
+                        headers: Vec::new(),
+                        body: ndjson.into_bytes(),
+                    }
+                }
+                other => other,
+            };
+            let http = HttpResponse::json(status_for(&response), &response);
+            match response {
+                // Retry-After is whole seconds; never advertise 0 for load shedding
+                Response::Rejected { retry_after_ms, .. } if retry_after_ms > 0 => {
+                    http.with_header("Retry-After", retry_after_ms.div_ceil(1000).to_string())
+                }
+                _ => http,
+            }
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// WIRE PARSING
+// ═══════════════════════════════════════════════════════════════
+
+/// Incremental HTTP/1.1 request reader
+///
+/// Keeps leftover bytes between calls so pipelined requests work.
+pub struct HttpReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+}
+
+impl<R: AsyncRead + Unpin> HttpReader<R> {
+    /// Wrap a byte stream
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Get a mutable reference to the underlying stream
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Read the next request, or `None` on clean EOF
+    pub async fn next_request(&mut self) -> io::Result<Option<HttpRequest>> {
+        // Read until the end of the head
+        let head_end = loop {
+            if let Some(pos) = find_subsequence(&self.buf, b"\r\n\r\n") {
+                break pos;
+            }
+            if self.buf.len() > MAX_HEAD_SIZE {
+                return Err(invalid("Request head too large"));
+            }
+            if !self.fill().await? {
+                return if self.buf.is_empty() {
+                    Ok(None)
+                } else {
+                    Err(io::ErrorKind::UnexpectedEof.into())
+                };
+            }
+        };
+
+        let head = std::str::from_utf8(&self.buf[..head_end])
+            .map_err(|_| invalid("Request head is not UTF-8"))?;
+        let mut request = parse_head(head)?;
+
+        // Chunked bodies are not supported; guessing a length would desync the stream
+        if request.header("transfer-encoding").is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Transfer-Encoding is not supported",
+            ));
+        }
+
+        let content_length = match request.header("content-length") {
+            Some(v) => v
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| invalid("Invalid Content-Length"))?,
+            None => 0,
+        };
+        if content_length > MAX_FRAME_SIZE {
+            return Err(invalid("Body too large"));
+        }
+
+        let body_start = head_end + 4;
+        while self.buf.len() < body_start + content_length {
+            if !self.fill().await? {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+        }
+
+        request.body = self.buf[body_start..body_start + content_length].to_vec();
+        self.buf.drain(..body_start + content_length);
+        Ok(Some(request))
+    }
+
+    async fn fill(&mut self) -> io::Result<bool> {
+        let mut chunk = [0u8; 4096];
+        let n = self.inner.read(&mut chunk).await?;
+        self.buf.extend_from_slice(&chunk[..n]);
+        Ok(n > 0)
+    }
+}
+
+fn parse_head(head: &str) -> io::Result<HttpRequest> {
+    let mut lines = head.split("\r\n");
+    let request_line = lines.next().ok_or_else(|| invalid("Empty request"))?;
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().ok_or_else(|| invalid("Missing method"))?;
+    let target = parts.next().ok_or_else(|| invalid("Missing target"))?;
+    let version = parts.next().unwrap_or("HTTP/1.1");
+
+    let mut headers = Vec::new();
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    let (raw_path, raw_query) = target.split_once('?').unwrap_or((target, ""));
+    // Split before decoding, so an escaped `%2F` stays inside its segment
+    let path_segments = raw_path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(percent_decode)
+        .collect();
+    let query = raw_query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|pair| {
+            let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode_query_component(k), decode_query_component(v))
+        })
+        .collect();
+
+    let connection = headers
+        .iter()
+        .find(|(k, _)| k == "connection")
+        .map(|(_, v)| v.to_ascii_lowercase());
+    let keep_alive = match connection.as_deref() {
+        Some("close") => false,
+        Some("keep-alive") => true,
+        _ => version != "HTTP/1.0",
+    };
+
+    Ok(HttpRequest {
+        method: method.to_ascii_uppercase(),
+        path: raw_path.to_string(),
+        path_segments,
+        query,
+        headers,
+        body: Vec::new(),
+        keep_alive,
+    })
+}
+
+/// Decode a query-string key or value: `+` is a space there, `%2B` a plus
+fn decode_query_component(s: &str) -> String {
+    percent_decode(&s.replace('+', " "))
+}
+
+/// Decode `%XX` escapes in a URL component
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(b) => {
+                        out.push(b);
+                        i += 3;
+                        continue;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn find_subsequence(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+// ═══════════════════════════════════════════════════════════════
+// GATEWAY
+// ═══════════════════════════════════════════════════════════════
+
+/// HTTP/JSON gateway serving `CommandHandler` operations
+#[derive(Clone)]
+pub struct HttpGateway {
+    db: Arc<RwLock<MindFry>>,
+    warmup: WarmupTracker,
+    metrics: Arc<Metrics>,
+    recovery: RecoveryState,
+    tick_interval: Duration,
+    shutdown: ShutdownCoordinator,
+    store: Option<Arc<AkashicStore>>,
+}
+
+impl HttpGateway {
+    /// Create a gateway over a shared database
+    pub fn new(db: Arc<RwLock<MindFry>>, warmup: WarmupTracker) -> Self {
+        Self {
+            db,
+            warmup,
+            metrics: Arc::new(Metrics::new()),
+            recovery: RecoveryState::Normal,
+            tick_interval: Duration::from_secs(1),
+            shutdown: ShutdownCoordinator::new(),
+            store: None,
+        }
+    }
+
+    /// Answer negative lookups from the store's index while warming up
+    ///
+    /// Only unindexed keys get `NotFound` early; indexed ones wait for the hot set.
+    pub fn with_store(mut self, store: Arc<AkashicStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Record into (and serve `/metrics` from) a shared registry
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Report the startup recovery state in health probes
+    pub fn with_recovery(mut self, recovery: RecoveryState) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    /// Interval at which the server restores `_system.state`
+    pub fn with_tick_interval(mut self, interval: Duration) -> Self {
+        self.tick_interval = interval;
+        self
+    }
+
+    /// Stop accepting and close idle connections when `shutdown` drains
+    pub fn with_shutdown(mut self, shutdown: ShutdownCoordinator) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
+    /// Accept connections until the listener fails or drain starts
+    pub async fn serve(self, listener: TcpListener) -> io::Result<()> {
+        loop {
+            let (socket, peer) = tokio::select! {
+                accepted = listener.accept() => accepted?,
+                _ = self.shutdown.draining() => return Ok(()),
+            };
+            tracing::debug!("🌐 HTTP connection from {}", peer);
+
+            let gateway = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = gateway.handle_connection(socket).await {
+                    tracing::debug!("HTTP connection error ({}): {}", peer, e);
+                }
+            });
+        }
+    }
+
+    /// Serve requests on a single connection until it closes
+    pub async fn handle_connection<S>(&self, stream: S) -> io::Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let _connection = self.metrics.connection(Transport::Http);
+        let mut handler = CommandHandler::with_warmup(Arc::clone(&self.db), self.warmup.clone())
+            .with_metrics(Arc::clone(&self.metrics))
+            .with_recovery(self.recovery)
+            .with_tick_interval(self.tick_interval);
+        if let Some(ref store) = self.store {
+            handler = handler.with_store(Arc::clone(store));
+        }
+        let mut reader = HttpReader::new(stream);
+
+        loop {
+            let next = tokio::select! {
+                next = reader.next_request() => next,
+                // Idle keep-alive connections close as soon as drain starts
+                _ = self.shutdown.draining() => return Ok(()),
+            };
+            let request = match next {
+                Ok(Some(request)) => request,
+                Ok(None) => return Ok(()),
+                // The stream can't be resynchronised: answer once and close
+                Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                    let response = HttpResponse::text(400, e.to_string());
+                    reader
+                        .get_mut()
+                        .write_all(&response.to_bytes(false))
+                        .await?;
+                    return Ok(());
+                }
+                Err(e) if e.kind() == io::ErrorKind::Unsupported => {
+                    let response = HttpResponse::text(501, e.to_string());
+                    reader
+                        .get_mut()
+                        .write_all(&response.to_bytes(false))
+                        .await?;
+                    return Ok(());
+                }
+                Err(e) => return Err(e),
+            };
+
+            let _in_flight = self.shutdown.begin_request();
+            let response = if request.path == "/metrics" {
+                self.metrics_response(&request)
+            } else {
+                dispatch(&mut handler, &request)
+            };
+            let keep_alive = request.keep_alive && !self.shutdown.is_draining();
+            let stream = reader.get_mut();
+            stream.write_all(&response.to_bytes(keep_alive)).await?;
+            stream.flush().await?;
+
+            if !keep_alive {
+                return Ok(());
+            }
+        }
+    }
+
+    fn metrics_response(&self, request: &HttpRequest) -> HttpResponse {
+        if request.method != "GET" {
+            return HttpError::MethodNotAllowed.into_response();
+        }
+        let db = self.db.read().unwrap();
+        HttpResponse::text(200, self.metrics.render(&db, self.warmup.state()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, target: &str, body: &str) -> HttpRequest {
+        let mut req = parse_head(&format!("{} {} HTTP/1.1\r\nHost: x", method, target)).unwrap();
+        req.body = body.as_bytes().to_vec();
+        req
+    }
+
+    #[test]
+    fn test_route_lineage_create_defaults() {
+        let req = request("POST", "/lineages", r#"{"id":"fire","energy":0.9}"#);
+        match route(&req).unwrap() {
+            Request::LineageCreate {
+                id,
+                energy,
+                threshold,
+                decay_rate,
+            } => {
+                assert_eq!(id, "fire");
+                assert!((energy - 0.9).abs() < 0.001);
+                assert_eq!(threshold, 0.5);
+                assert_eq!(decay_rate, 0.001);
+            }
+            other => panic!("Expected LineageCreate, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_route_decodes_path_and_query() {
+        let req = request("GET", "/lineages/hot%20fire?flags=4", "");
+        match route(&req).unwrap() {
+            Request::LineageGet { id, flags } => {
+                assert_eq!(id, "hot fire");
+                assert_eq!(flags, 4);
+            }
+            other => panic!("Expected LineageGet, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_route_keeps_plus_and_escaped_slash_in_path_segments() {
+        for (target, expected) in [
+            ("/lineages/c++", "c++"),
+            ("/lineages/a%2Fb", "a/b"),
+            ("/lineages/a%2Fb/neighbors", "a/b"),
+        ] {
+            match route(&request("GET", target, "")).unwrap() {
+                Request::LineageGet { id, .. } | Request::BondNeighbors { id, .. } => {
+                    assert_eq!(id, expected, "{}", target)
+                }
+                other => panic!("Expected a lineage read, got {:?}", other),
+            }
+        }
+
+        // `+` only means a space in query strings
+        let req = request("GET", "/query/topk?k=3&note=a+b%2Bc", "");
+        assert_eq!(req.query_param("note"), Some("a b+c"));
+    }
+
+    #[test]
+    fn test_route_errors() {
+        assert_eq!(
+            route(&request("GET", "/nowhere", "")).unwrap_err(),
+            HttpError::NotFound
+        );
+        assert_eq!(
+            route(&request("PUT", "/lineages/x", "")).unwrap_err(),
+            HttpError::MethodNotAllowed
+        );
+        assert!(matches!(
+            route(&request("POST", "/lineages", "{")).unwrap_err(),
+            HttpError::BadRequest(_)
+        ));
+        assert!(matches!(
+            route(&request("DELETE", "/snapshots/latest", "")).unwrap_err(),
+            HttpError::BadRequest(_)
+        ));
+        assert!(matches!(
+            route(&request("DELETE", "/snapshots/42", "")).unwrap(),
+            Request::SnapshotDelete { id: 42 }
+        ));
+        assert_eq!(
+            route(&request("POST", "/export", "")).unwrap_err(),
+            HttpError::MethodNotAllowed
+        );
+        assert!(matches!(
+            route(&request("GET", "/export", "")).unwrap(),
+            Request::Export
+        ));
+        assert!(matches!(
+            route(&request("POST", "/import", "{}\n")).unwrap(),
+            Request::Import { ndjson } if ndjson == "{}\n"
+        ));
+    }
+
+    #[test]
+    fn test_route_unknown_query_is_not_found() {
+        assert_eq!(
+            route(&request("GET", "/query/bogus", "")).unwrap_err(),
+            HttpError::NotFound
+        );
+        assert_eq!(
+            route(&request("POST", "/query/topk", "")).unwrap_err(),
+            HttpError::MethodNotAllowed
+        );
+    }
+
+    #[test]
+    fn test_route_raw_command() {
+        let req = request("POST", "/command", r#"{"QueryTopK":{"k":3}}"#);
+        assert!(matches!(
+            route(&req).unwrap(),
+            Request::QueryTopK { k: 3, flags: 0 }
+        ));
+    }
+
+    #[test]
+    fn test_probes() {
+        let db = Arc::new(RwLock::new(MindFry::new()));
+        db.write().unwrap().bootstrap_system_lineages();
+        let warmup = WarmupTracker::new();
+        warmup.begin_resurrection();
+        let mut handler = CommandHandler::with_warmup(db, warmup.clone());
+
+        assert_eq!(
+            dispatch(&mut handler, &request("GET", "/livez", "")).status,
+            200
+        );
+        assert_eq!(
+            dispatch(&mut handler, &request("GET", "/readyz", "")).status,
+            503
+        );
+
+        warmup.mark_ready();
+        let ready = dispatch(&mut handler, &request("GET", "/readyz", ""));
+        assert_eq!(ready.status, 200);
+        assert!(String::from_utf8_lossy(&ready.body).contains(r#""warmup":"Ready""#));
+    }
+
+    #[tokio::test]
+    async fn test_gateway_roundtrip() {
+        let db = Arc::new(RwLock::new(MindFry::new()));
+        let gateway = HttpGateway::new(db, WarmupTracker::new());
+        let (client, server) = tokio::io::duplex(64 * 1024);
+
+        let server_task = tokio::spawn(async move { gateway.handle_connection(server).await });
+
+        let body = r#"{"id":"fire","energy":0.9}"#;
+        let raw = format!(
+            "POST /lineages HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}\
+             GET /lineages/fire?flags=4 HTTP/1.1\r\nConnection: close\r\n\r\n",
+            body.len(),
+            body
+        );
+
+        let (mut rd, mut wr) = tokio::io::split(client);
+        wr.write_all(raw.as_bytes()).await.unwrap();
+
+        let mut out = String::new();
+        rd.read_to_string(&mut out).await.unwrap();
+        server_task.await.unwrap().unwrap();
+
+        assert_eq!(out.matches("HTTP/1.1 200 OK").count(), 2);
+        assert!(out.contains(r#""Ok":"Ack""#));
+        assert!(out.contains(r#""id":"fire""#));
+    }
+
+    #[tokio::test]
+    async fn test_gateway_rejects_chunked_bodies_and_closes() {
+        let db = Arc::new(RwLock::new(MindFry::new()));
+        let gateway = HttpGateway::new(db, WarmupTracker::new());
+        let (client, server) = tokio::io::duplex(64 * 1024);
+
+        let server_task = tokio::spawn(async move { gateway.handle_connection(server).await });
+
+        // The chunk must not be parsed as a second request
+        let raw = "POST /command HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n\
+                   16\r\nGET /ping HTTP/1.1\r\n\r\n\r\n0\r\n\r\n";
+        let (mut rd, mut wr) = tokio::io::split(client);
+        wr.write_all(raw.as_bytes()).await.unwrap();
+
+        let mut out = String::new();
+        rd.read_to_string(&mut out).await.unwrap();
+        server_task.await.unwrap().unwrap();
+
+        assert!(out.starts_with("HTTP/1.1 501 Not Implemented"));
+        assert!(out.contains("Connection: close"));
+        assert_eq!(out.matches("HTTP/1.1").count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_gateway_serves_metrics() {
+        let db = Arc::new(RwLock::new(MindFry::new()));
+        let gateway = HttpGateway::new(db, WarmupTracker::new());
+        let (client, server) = tokio::io::duplex(64 * 1024);
+
+        let server_task = tokio::spawn(async move { gateway.handle_connection(server).await });
+
+        let raw = "GET /ping HTTP/1.1\r\n\r\n\
+                   GET /metrics HTTP/1.1\r\nConnection: close\r\n\r\n";
+        let (mut rd, mut wr) = tokio::io::split(client);
+        wr.write_all(raw.as_bytes()).await.unwrap();
+
+        let mut out = String::new();
+        rd.read_to_string(&mut out).await.unwrap();
+        server_task.await.unwrap().unwrap();
+
+        assert!(out.contains("mindfry_requests_total{opcode=\"SysPing\"} 1"));
+        assert!(out.contains("mindfry_active_connections{transport=\"http\"} 1"));
+    }
+
+    #[tokio::test]
+    async fn test_gateway_closes_idle_connection_on_drain() {
+        let db = Arc::new(RwLock::new(MindFry::new()));
+        let shutdown = ShutdownCoordinator::new();
+        let gateway = HttpGateway::new(db, WarmupTracker::new()).with_shutdown(shutdown.clone());
+        let (client, server) = tokio::io::duplex(64 * 1024);
+
+        let server_task = tokio::spawn(async move { gateway.handle_connection(server).await });
+
+        // Keep-alive request, then the connection sits idle
+        let (mut rd, mut wr) = tokio::io::split(client);
+        wr.write_all(b"GET /ping HTTP/1.1\r\n\r\n").await.unwrap();
+        let mut buf = [0u8; 256];
+        let n = rd.read(&mut buf).await.unwrap();
+        assert!(String::from_utf8_lossy(&buf[..n]).contains("200 OK"));
+
+        assert!(shutdown.drain(Duration::from_secs(5)).await);
+        server_task.await.unwrap().unwrap();
+        assert_eq!(rd.read(&mut buf).await.unwrap(), 0);
+    }
+}
@@ -6,6 +6,8 @@ use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
 
 use super::OpCode;
+use crate::dynamics::CascadeMode;
+use crate::stability::{ExhaustionLevel, HealthStatus, RecoveryState, TunerStats, WarmupState};
 
 // ═══════════════════════════════════════════════════════════════
 // QUERY FLAGS (Executive Override)
@@ -19,12 +21,14 @@ bitflags! {
         const NONE = 0x00;
         /// Bypass Cortex mood and Antagonism filters
         const BYPASS_FILTERS = 0x01;
-        /// Return REPRESSED status instead of hiding
+        /// Return repressed and frozen lineages instead of hiding them
         const INCLUDE_REPRESSED = 0x02;
         /// Don't stimulate on read (no observer effect)
         const NO_SIDE_EFFECTS = 0x04;
-        /// All flags combined (forensic/god mode)
+        /// Bypass, reveal and don't touch: full internal state (forensic/god mode)
         const FORENSIC = 0x07;
+        /// List queries reinforce what they return (unless NO_SIDE_EFFECTS)
+        const REINFORCE = 0x08;
     }
 }
 
@@ -36,6 +40,32 @@ bitflags! {
         const NONE = 0x00;
         /// Surgical mode - don't propagate to neighbors
         const NO_PROPAGATE = 0x01;
+        /// Stimulate a Hardened lineage anyway
+        const OVERRIDE_PROTECTION = 0x02;
+    }
+}
+
+bitflags! {
+    /// Forget flags for deleting protected lineages
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct ForgetFlags: u8 {
+        /// Normal behavior - only unprotected lineages are forgotten
+        const NONE = 0x00;
+        /// Confirm deleting a Pinned lineage
+        const CONFIRM = 0x01;
+        /// Delete a Hardened (or Pinned) lineage anyway
+        const OVERRIDE_PROTECTION = 0x02;
+    }
+}
+
+bitflags! {
+    /// Protect flags for lowering a protection level
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct ProtectFlags: u8 {
+        /// Normal behavior - levels may only be raised past Hardened
+        const NONE = 0x00;
+        /// Lower a Hardened lineage anyway
+        const OVERRIDE_PROTECTION = 0x02;
     }
 }
 
@@ -51,6 +81,8 @@ pub enum LineageStatus {
     Repressed = 2,
     /// Lineage is in retention buffer (scheduled for GC)
     Dormant = 3,
+    /// Lineage is in the Frozen tier and must be unlocked first
+    Frozen = 4,
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -58,7 +90,7 @@ pub enum LineageStatus {
 // ═══════════════════════════════════════════════════════════════
 
 /// A request message from client to server
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Request {
     // Lineage
     LineageCreate {
@@ -80,10 +112,24 @@ pub enum Request {
     },
     LineageForget {
         id: String,
+        /// Forget flags (default: unprotected lineages only)
+        flags: u8,
     },
     LineageTouch {
         id: String,
     },
+    /// Thaw a Frozen lineage so plain reads return it again
+    LineageUnlock {
+        id: String,
+    },
+    /// Set a lineage's protection level (`Protection` as u8)
+    LineageProtect {
+        id: String,
+        level: u8,
+        /// Protect flags (default: no override)
+        #[serde(default)]
+        flags: u8,
+    },
 
     // Bond
     BondConnect {
@@ -104,20 +150,42 @@ pub enum Request {
     },
     BondNeighbors {
         id: String,
+        /// Query flags for the lineage and its neighbours (default: NONE)
+        #[serde(default)]
+        flags: u8,
+    },
+    /// Cascade over the bond neighbourhood of a lineage
+    Cascade {
+        id: String,
+        mode: CascadeMode,
+        /// Signal at the source (energy delta for recall/forget)
+        strength: f32,
+        /// Maximum hops from the source
+        max_depth: u8,
+        /// Bonds weaker than this are not followed
+        min_bond_strength: f32,
     },
 
-    // Query
+    // Query (flags: `QueryFlags`, default: NONE)
     QueryConscious {
         min_energy: f32,
+        #[serde(default)]
+        flags: u8,
     },
     QueryTopK {
         k: u32,
+        #[serde(default)]
+        flags: u8,
     },
     QueryTrauma {
         min_rigidity: f32,
+        #[serde(default)]
+        flags: u8,
     },
     QueryPattern {
         pattern: String,
+        #[serde(default)]
+        flags: u8,
     },
 
     // System
@@ -129,6 +197,18 @@ pub enum Request {
     Restore {
         name: String,
     },
+    /// List stored snapshots
+    SnapshotList,
+    /// Delete a snapshot by ID
+    SnapshotDelete {
+        id: u64,
+    },
+    /// Export lineages, bonds, engrams and cortex as NDJSON
+    Export,
+    /// Replace the whole state from an NDJSON export
+    Import {
+        ndjson: String,
+    },
     Freeze {
         frozen: bool,
     },
@@ -140,6 +220,8 @@ pub enum Request {
     MoodSet {
         mood: f32,
     },
+    /// Liveness/readiness report
+    Health,
 
     // Stream
     Subscribe {
@@ -157,10 +239,18 @@ impl Request {
             Self::LineageStimulate { .. } => OpCode::LineageStimulate,
             Self::LineageForget { .. } => OpCode::LineageForget,
             Self::LineageTouch { .. } => OpCode::LineageTouch,
+            Self::LineageUnlock { .. } => OpCode::LineageUnlock,
+            Self::LineageProtect { .. } => OpCode::LineageProtect,
             Self::BondConnect { .. } => OpCode::BondConnect,
             Self::BondReinforce { .. } => OpCode::BondReinforce,
             Self::BondSever { .. } => OpCode::BondSever,
             Self::BondNeighbors { .. } => OpCode::BondNeighbors,
+            Self::Cascade { mode, .. } => match mode {
+                CascadeMode::Recall => OpCode::CascadeRecall,
+                CascadeMode::Forget => OpCode::CascadeForget,
+                CascadeMode::Lock => OpCode::CascadeLock,
+                CascadeMode::Unlock => OpCode::CascadeUnlock,
+            },
             Self::QueryConscious { .. } => OpCode::QueryConscious,
             Self::QueryTopK { .. } => OpCode::QueryTopK,
             Self::QueryTrauma { .. } => OpCode::QueryTrauma,
@@ -169,13 +259,37 @@ impl Request {
             Self::Stats => OpCode::SysStats,
             Self::Snapshot { .. } => OpCode::SysSnapshot,
             Self::Restore { .. } => OpCode::SysRestore,
+            Self::SnapshotList => OpCode::SysSnapshotList,
+            Self::SnapshotDelete { .. } => OpCode::SysSnapshotDelete,
+            Self::Export => OpCode::SysExport,
+            Self::Import { .. } => OpCode::SysImport,
             Self::Freeze { .. } => OpCode::SysFreeze,
             Self::PhysicsTune { .. } => OpCode::PhysicsTune,
             Self::MoodSet { .. } => OpCode::SysMoodSet,
+            Self::Health => OpCode::SysHealth,
             Self::Subscribe { .. } => OpCode::StreamSubscribe,
             Self::Unsubscribe => OpCode::StreamUnsubscribe,
         }
     }
+
+    /// Whether this request mutates lineages, bonds or stored snapshots
+    pub fn is_write(&self) -> bool {
+        matches!(
+            self,
+            Self::LineageCreate { .. }
+                | Self::LineageStimulate { .. }
+                | Self::LineageForget { .. }
+                | Self::LineageUnlock { .. }
+                | Self::LineageProtect { .. }
+                | Self::Cascade { .. }
+                | Self::BondConnect { .. }
+                | Self::BondReinforce { .. }
+                | Self::BondSever { .. }
+                | Self::Restore { .. }
+                | Self::SnapshotDelete { .. }
+                | Self::Import { .. }
+        )
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -191,8 +305,30 @@ pub enum Response {
     /// Error with message
     Error { code: ErrorCode, message: String },
 
+    /// Request refused by admission control, with a backoff hint
+    /// Wire format: error frame + [level:u8, retry_after_ms:u32]
+    Rejected {
+        code: ErrorCode,
+        message: String,
+        level: ExhaustionLevel,
+        retry_after_ms: u32,
+    },
+
     /// Event notification
     Event(Event),
+
+    /// Server is draining connections before shutdown
+    GoingAway { drain_deadline_ms: u32 },
+}
+
+impl Response {
+    /// Error code of an `Error` or `Rejected` response
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::Error { code, .. } | Self::Rejected { code, .. } => Some(*code),
+            Self::Ok(_) | Self::Event(_) | Self::GoingAway { .. } => None,
+        }
+    }
 }
 
 /// Response data variants
@@ -218,6 +354,28 @@ pub enum ResponseData {
 
     /// Snapshot created
     SnapshotCreated { name: String },
+
+    /// Health report
+    Health(HealthInfo),
+
+    /// Stored snapshots (newest first)
+    Snapshots(Vec<SnapshotInfo>),
+
+    /// NDJSON export of the whole state
+    Export(String),
+
+    /// Import completed
+    Imported {
+        lineages: u32,
+        bonds: u32,
+        engrams: u32,
+    },
+
+    /// Lineages changed by a cascade (source first)
+    Cascade(Vec<CascadeEntry>),
+
+    /// Full internal state of queried lineages (FORENSIC flag)
+    Forensic(Vec<ForensicInfo>),
 }
 
 /// Lineage lookup result with status framing
@@ -250,6 +408,66 @@ pub struct NeighborInfo {
     pub is_learned: bool,
 }
 
+/// A lineage changed by a cascade
+/// Wire format: [id:str, depth:u8, signal:f32, energy:f32]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CascadeEntry {
+    pub id: String,
+    /// Hops from the source (0 = the source itself)
+    pub depth: u8,
+    /// Damped signal that arrived
+    pub signal: f32,
+    /// Decayed energy after the cascade
+    pub energy: f32,
+}
+
+/// Full internal state of a lineage
+/// Wire format: [status:u8, info, flags:u32, head_index:u32, raw_energy:f32,
+/// pressure:f32, count:u32, suppressors...]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForensicInfo {
+    /// Status a plain read would see
+    pub status: LineageStatus,
+    /// Public view (decayed energy)
+    pub info: LineageInfo,
+    /// Raw `LineageFlags` bits
+    pub flags: u32,
+    /// Head engram in the strata arena
+    pub head_index: u32,
+    /// Stored energy before lazy decay
+    pub raw_energy: f32,
+    /// Summed inhibition from conscious antagonists
+    pub pressure: f32,
+    /// Antagonists contributing to the pressure
+    pub suppressors: Vec<SuppressorInfo>,
+}
+
+/// A conscious antagonist inhibiting a lineage
+/// Wire format: [id:str, bond_strength:f32, energy:f32]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuppressorInfo {
+    pub id: String,
+    pub bond_strength: f32,
+    /// Decayed energy of the antagonist
+    pub energy: f32,
+}
+
+/// Stored snapshot summary
+/// Wire format: [id:u64, name:str, created_at:u64, lineages:u32, bonds:u32, size:u64, parent:u64]
+/// (`parent` 0 = full snapshot)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    pub id: u64,
+    /// Empty for unnamed snapshots
+    pub name: String,
+    pub created_at: u64,
+    pub lineage_count: u32,
+    pub bond_count: u32,
+    pub size_bytes: u64,
+    /// Base snapshot of a delta (None = full)
+    pub parent: Option<u64>,
+}
+
 /// Database statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StatsInfo {
@@ -259,6 +477,67 @@ pub struct StatsInfo {
     pub total_energy: f32,
     pub is_frozen: bool,
     pub uptime_secs: u64,
+    /// Exhaustion tuner state
+    pub tuner: TunerStats,
+    /// Lineages evicted to the cold tier (`lineage_count` counts resident ones)
+    pub evicted_count: usize,
+    /// Resident lineages per temperature tier: [hot, warm, cold, frozen]
+    pub tier_counts: [usize; 4],
+    /// Writes refused by protection levels since startup
+    pub protection_violations: u64,
+}
+
+/// Health report for liveness/readiness probes
+/// Wire format: [status:u8, energy:f32, warmup:u8, exhaustion:u8, recovery:u8, flags:u8]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthInfo {
+    /// Status derived from `_system.health` energy
+    pub status: HealthStatus,
+    /// Current `_system.health` energy
+    pub health_energy: f32,
+    /// Resurrection progress
+    pub warmup: WarmupState,
+    /// Backpressure level derived from `_system.state`
+    pub exhaustion: ExhaustionLevel,
+    /// Trauma detected at startup
+    pub recovery: RecoveryState,
+    /// Whether a persistence store is attached
+    pub store_attached: bool,
+    /// Whether the attached store accepted a probe write
+    pub store_reachable: bool,
+    /// Process is alive (health pulse is keeping up)
+    pub live: bool,
+    /// Ready to serve traffic
+    pub ready: bool,
+}
+
+impl HealthInfo {
+    /// Flag bit: live
+    pub const LIVE: u8 = 1 << 0;
+    /// Flag bit: ready
+    pub const READY: u8 = 1 << 1;
+    /// Flag bit: store attached
+    pub const STORE_ATTACHED: u8 = 1 << 2;
+    /// Flag bit: store reachable
+    pub const STORE_REACHABLE: u8 = 1 << 3;
+
+    /// Pack boolean fields into the wire flags byte
+    pub fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.live {
+            flags |= Self::LIVE;
+        }
+        if self.ready {
+            flags |= Self::READY;
+        }
+        if self.store_attached {
+            flags |= Self::STORE_ATTACHED;
+        }
+        if self.store_reachable {
+            flags |= Self::STORE_REACHABLE;
+        }
+        flags
+    }
 }
 
 /// Error codes
@@ -277,17 +556,33 @@ pub enum ErrorCode {
     LineageNotFound = 0x10,
     /// Lineage already exists
     LineageExists = 0x11,
+    /// Refused by the lineage's protection level
+    Protected = 0x12,
     /// Bond not found
     BondNotFound = 0x20,
     /// Bond already exists
     BondExists = 0x21,
     /// Snapshot not found
     SnapshotNotFound = 0x30,
+    /// Too exhausted to serve any request (Emergency)
+    Exhausted = 0x40,
+    /// Writes refused while exhausted, reads still served
+    ReadOnlyMode = 0x41,
+    /// Operation not permitted (e.g. writes to reserved `_system.*`)
+    Unauthorized = 0x42,
     /// Internal error
     Internal = 0xFF,
 }
 
 impl ErrorCode {
+    /// Whether responses with this code carry a `Rejected` backoff hint
+    pub fn is_rejection(self) -> bool {
+        matches!(
+            self,
+            Self::Exhausted | Self::ReadOnlyMode | Self::Unauthorized
+        )
+    }
+
     pub fn from_byte(byte: u8) -> Self {
         match byte {
             0x01 => Self::InvalidOpCode,
@@ -295,9 +590,13 @@ impl ErrorCode {
             0x03 => Self::WarmingUp,
             0x10 => Self::LineageNotFound,
             0x11 => Self::LineageExists,
+            0x12 => Self::Protected,
             0x20 => Self::BondNotFound,
             0x21 => Self::BondExists,
             0x30 => Self::SnapshotNotFound,
+            0x40 => Self::Exhausted,
+            0x41 => Self::ReadOnlyMode,
+            0x42 => Self::Unauthorized,
             0xFF => Self::Internal,
             _ => Self::Unknown,
         }
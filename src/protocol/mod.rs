@@ -16,15 +16,24 @@
 //! - `0x30-0x3F`: Query operations
 //! - `0x40-0x4F`: System operations
 //! - `0x50-0x5F`: Stream operations
+//!
+//! ## HTTP Gateway
+//!
+//! [`HttpGateway`] exposes the same operations as JSON over HTTP/1.1
+//! for clients without an MFBP implementation.
 
 #![allow(missing_docs)]
 
 mod codec;
+mod drain;
 mod handler;
+mod http;
 mod message;
 mod opcodes;
 
 pub use codec::{MfbpCodec, MfbpError};
+pub use drain::{InFlight, ShutdownCoordinator};
 pub use handler::CommandHandler;
+pub use http::{HttpError, HttpGateway, HttpReader, HttpRequest, HttpResponse};
 pub use message::*;
 pub use opcodes::*;
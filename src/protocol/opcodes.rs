@@ -22,13 +22,21 @@ pub enum OpCode {
     LineageStimulate = 0x12,
 
     /// Soft-delete a lineage (forget)
-    /// Payload: [id_len: u16, id_bytes: [u8]]
+    /// Payload: [id_len: u16, id_bytes: [u8], flags: u8 (optional)]
     LineageForget = 0x13,
 
     /// Touch lineage (update last access)
     /// Payload: [id_len: u16, id_bytes: [u8]]
     LineageTouch = 0x14,
 
+    /// Unlock a frozen lineage (back to the Warm tier)
+    /// Payload: [id_len: u16, id_bytes: [u8]]
+    LineageUnlock = 0x15,
+
+    /// Set the protection level of a lineage
+    /// Payload: [id_len: u16, id_bytes: [u8], level: u8]
+    LineageProtect = 0x16,
+
     // ═══════════════════════════════════════════════════════════════
     // BOND OPERATIONS (0x20-0x2F)
     // ═══════════════════════════════════════════════════════════════
@@ -48,6 +56,19 @@ pub enum OpCode {
     /// Payload: [id_len: u16, id_bytes: [u8]]
     BondNeighbors = 0x23,
 
+    /// Recall cascade: boost the synergy neighbourhood of a lineage
+    /// Payload: [id_len: u16, id_bytes: [u8], strength: f32, max_depth: u8, min_bond: f32]
+    CascadeRecall = 0x24,
+
+    /// Forget cascade: drain the neighbourhood (same payload)
+    CascadeForget = 0x25,
+
+    /// Lock cascade: freeze the neighbourhood (same payload)
+    CascadeLock = 0x26,
+
+    /// Unlock cascade: thaw frozen lineages in the neighbourhood (same payload)
+    CascadeUnlock = 0x27,
+
     // ═══════════════════════════════════════════════════════════════
     // QUERY OPERATIONS (0x30-0x3F)
     // ═══════════════════════════════════════════════════════════════
@@ -98,6 +119,26 @@ pub enum OpCode {
     /// Payload: [mood: f32] (-1.0 to +1.0)
     SysMoodSet = 0x46,
 
+    /// Health report (liveness/readiness)
+    /// Payload: []
+    SysHealth = 0x47,
+
+    /// List stored snapshots (newest first)
+    /// Payload: []
+    SysSnapshotList = 0x48,
+
+    /// Delete a snapshot by ID
+    /// Payload: [id: u64]
+    SysSnapshotDelete = 0x49,
+
+    /// Export the whole state as versioned NDJSON
+    /// Payload: []
+    SysExport = 0x4A,
+
+    /// Replace the whole state from NDJSON
+    /// Payload: [ndjson: u32 len + bytes]
+    SysImport = 0x4B,
+
     // ═══════════════════════════════════════════════════════════════
     // STREAM OPERATIONS (0x50-0x5F)
     // ═══════════════════════════════════════════════════════════════
@@ -120,6 +161,10 @@ pub enum OpCode {
 
     /// Event notification (from subscription)
     ResponseEvent = 0xF2,
+
+    /// Server is shutting down; no further requests will be served
+    /// Payload: [drain_deadline_ms: u32]
+    ResponseGoingAway = 0xF3,
 }
 
 impl OpCode {
@@ -132,11 +177,17 @@ impl OpCode {
             0x12 => Some(Self::LineageStimulate),
             0x13 => Some(Self::LineageForget),
             0x14 => Some(Self::LineageTouch),
+            0x15 => Some(Self::LineageUnlock),
+            0x16 => Some(Self::LineageProtect),
             // Bond
             0x20 => Some(Self::BondConnect),
             0x21 => Some(Self::BondReinforce),
             0x22 => Some(Self::BondSever),
             0x23 => Some(Self::BondNeighbors),
+            0x24 => Some(Self::CascadeRecall),
+            0x25 => Some(Self::CascadeForget),
+            0x26 => Some(Self::CascadeLock),
+            0x27 => Some(Self::CascadeUnlock),
             // Query
             0x30 => Some(Self::QueryConscious),
             0x31 => Some(Self::QueryTopK),
@@ -150,6 +201,11 @@ impl OpCode {
             0x44 => Some(Self::SysFreeze),
             0x45 => Some(Self::PhysicsTune),
             0x46 => Some(Self::SysMoodSet),
+            0x47 => Some(Self::SysHealth),
+            0x48 => Some(Self::SysSnapshotList),
+            0x49 => Some(Self::SysSnapshotDelete),
+            0x4A => Some(Self::SysExport),
+            0x4B => Some(Self::SysImport),
             // Stream
             0x50 => Some(Self::StreamSubscribe),
             0x51 => Some(Self::StreamUnsubscribe),
@@ -157,6 +213,7 @@ impl OpCode {
             0xF0 => Some(Self::ResponseOk),
             0xF1 => Some(Self::ResponseError),
             0xF2 => Some(Self::ResponseEvent),
+            0xF3 => Some(Self::ResponseGoingAway),
             _ => None,
         }
     }
@@ -208,6 +265,8 @@ pub enum PhysicsParam {
     BondPruneThreshold = 0x03,
     /// Minimum energy threshold
     MinEnergyThreshold = 0x04,
+    /// Energy a reinforcing read adds (observer effect)
+    ObserverDelta = 0x05,
 }
 
 impl PhysicsParam {
@@ -217,6 +276,7 @@ impl PhysicsParam {
             0x02 => Some(Self::TraumaThreshold),
             0x03 => Some(Self::BondPruneThreshold),
             0x04 => Some(Self::MinEnergyThreshold),
+            0x05 => Some(Self::ObserverDelta),
             _ => None,
         }
     }
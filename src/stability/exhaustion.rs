@@ -101,6 +101,17 @@ impl ExhaustionMonitor {
         let depth_multiplier = 1.0 + (propagation_depth as f32 * 0.1);
         base * write_multiplier * depth_multiplier
     }
+
+    /// Recovery ticks needed for energy to climb strictly above `target`
+    ///
+    /// Returns 0 when no recovery is needed or recovery is disabled.
+    pub fn recovery_ticks(&self, energy: f32, target: f32) -> u32 {
+        if energy > target || self.recovery_rate <= 0.0 {
+            return 0;
+        }
+        // Nudge before flooring so an exact multiple of the rate isn't lost to f32 error
+        ((target - energy) / self.recovery_rate + 1e-3).floor() as u32 + 1
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -120,6 +131,28 @@ pub struct TunerConfig {
     pub hard_ceiling: f32,
     /// Ticks before adaptive mode activates
     pub warmup_ticks: usize,
+    /// Floor for σ so a perfectly steady signal does not collapse all
+    /// thresholds onto the mean
+    #[serde(default = "default_min_std_dev")]
+    pub min_std_dev: f32,
+    /// Lowest learned threshold, as a fraction of the static default
+    #[serde(default = "default_min_scale")]
+    pub min_scale: f32,
+    /// Highest learned threshold, as a multiple of the static default
+    #[serde(default = "default_max_scale")]
+    pub max_scale: f32,
+}
+
+fn default_min_std_dev() -> f32 {
+    0.05
+}
+
+fn default_min_scale() -> f32 {
+    0.5
+}
+
+fn default_max_scale() -> f32 {
+    1.5
 }
 
 impl Default for TunerConfig {
@@ -130,6 +163,9 @@ impl Default for TunerConfig {
             min_floor: 0.1,
             hard_ceiling: 0.9,
             warmup_ticks: 50,
+            min_std_dev: default_min_std_dev(),
+            min_scale: default_min_scale(),
+            max_scale: default_max_scale(),
         }
     }
 }
@@ -193,7 +229,7 @@ impl EMAAccumulator {
 }
 
 /// Tuner statistics snapshot
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TunerStats {
     /// Current mean
     pub mean: f32,
@@ -256,13 +292,35 @@ impl ExhaustionTuner {
         }
 
         // Dynamic thresholds based on learned patterns
-        let normal = (stats.mean - self.config.sensitivity * 0.3 * stats.std_dev)
+        let std_dev = stats.std_dev.max(self.config.min_std_dev);
+        let normal = (stats.mean - self.config.sensitivity * 0.3 * std_dev)
             .clamp(self.config.min_floor, self.config.hard_ceiling);
-        let elevated = (stats.mean - self.config.sensitivity * 0.6 * stats.std_dev)
+        let elevated = (stats.mean - self.config.sensitivity * 0.6 * std_dev)
             .clamp(self.config.min_floor, normal);
-        let exhausted = (stats.mean - self.config.sensitivity * stats.std_dev)
-            .clamp(self.config.min_floor, elevated);
+        let exhausted =
+            (stats.mean - self.config.sensitivity * std_dev).clamp(self.config.min_floor, elevated);
+
+        ExhaustionThresholds {
+            normal,
+            elevated,
+            exhausted,
+        }
+    }
 
+    /// Learned thresholds held within `min_scale..=max_scale` of the defaults
+    ///
+    /// Adapts both ways: a workload that runs hot gets stricter levels,
+    /// one that idles low gets looser ones, but neither strays far enough
+    /// to make backpressure fire always or never.
+    pub fn bounded_threshold(&self) -> ExhaustionThresholds {
+        let learned = self.compute_threshold();
+        let defaults = ExhaustionThresholds::default();
+        let band =
+            |t: f32, base: f32| t.clamp(base * self.config.min_scale, base * self.config.max_scale);
+
+        let normal = band(learned.normal, defaults.normal);
+        let elevated = band(learned.elevated, defaults.elevated).min(normal);
+        let exhausted = band(learned.exhausted, defaults.exhausted).min(elevated);
         ExhaustionThresholds {
             normal,
             elevated,
@@ -314,6 +372,14 @@ mod tests {
         assert!(!ExhaustionLevel::Emergency.allows_writes());
     }
 
+    #[test]
+    fn test_recovery_ticks() {
+        let monitor = ExhaustionMonitor::default(); // 0.05 per tick
+        assert_eq!(monitor.recovery_ticks(0.8, 0.7), 0);
+        assert_eq!(monitor.recovery_ticks(0.3, 0.4), 3);
+        assert_eq!(monitor.recovery_ticks(0.1, 0.1), 1);
+    }
+
     #[test]
     fn test_tuner_warmup() {
         let mut tuner = ExhaustionTuner::new(
@@ -356,4 +422,54 @@ mod tests {
         assert!(stats.mean > 0.8);
         assert!(stats.std_dev < 0.1); // Low variance
     }
+
+    #[test]
+    fn test_tuner_std_dev_floor() {
+        let mut tuner = ExhaustionTuner::new(
+            TunerConfig {
+                warmup_ticks: 3,
+                ..Default::default()
+            },
+            0.5,
+        );
+        for _ in 0..3 {
+            tuner.observe(0.6);
+        }
+
+        // Zero observed variance still leaves room between levels
+        let thresholds = tuner.compute_threshold();
+        assert!(thresholds.normal < 0.6);
+        assert!(thresholds.elevated < thresholds.normal);
+        assert!(thresholds.exhausted < thresholds.elevated);
+    }
+
+    #[test]
+    fn test_tuner_bounds_adapt_both_ways() {
+        let defaults = ExhaustionThresholds::default();
+        let learned = |energy: f32| {
+            let mut tuner = ExhaustionTuner::new(
+                TunerConfig {
+                    warmup_ticks: 3,
+                    min_floor: 0.01,
+                    ..Default::default()
+                },
+                0.5,
+            );
+            for _ in 0..3 {
+                tuner.observe(energy);
+            }
+            tuner.bounded_threshold()
+        };
+
+        // Running hot tightens, up to max_scale
+        let hot = learned(1.0);
+        assert!(hot.normal > defaults.normal);
+        assert_eq!(hot.elevated, defaults.elevated * 1.5);
+
+        // Idling low loosens, down to min_scale
+        let low = learned(0.2);
+        assert_eq!(low.normal, defaults.normal * 0.5);
+        assert!(low.elevated < defaults.elevated);
+        assert!(low.exhausted <= low.elevated && low.elevated <= low.normal);
+    }
 }
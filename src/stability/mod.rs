@@ -35,6 +35,8 @@ pub use warmup::{WarmupState, WarmupTracker};
 
 /// System lineage key constants
 pub mod lineages {
+    /// Reserved namespace prefix; clients may not write here
+    pub const PREFIX: &str = "_system.";
     /// Self-diagnostic health lineage
     pub const HEALTH: &str = "_system.health";
     /// Current exhaustion level
@@ -5,6 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::ShutdownTracker;
+use crate::clock::{now_secs, Clock};
+
 /// Coma threshold: 1 hour of downtime
 pub const COMA_THRESHOLD_SECS: u64 = 3600;
 
@@ -70,6 +73,8 @@ pub struct RecoveryAnalyzer {
     last_marker: Option<ShutdownMarker>,
     /// Current startup timestamp
     startup_time: u64,
+    /// Whether the previous shutdown was recorded as traumatic
+    traumatic: bool,
 }
 
 impl RecoveryAnalyzer {
@@ -78,9 +83,25 @@ impl RecoveryAnalyzer {
         Self {
             last_marker,
             startup_time: now_secs(),
+            traumatic: false,
         }
     }
 
+    /// Measure downtime against a specific clock instead of the process clock
+    pub fn with_clock(mut self, clock: &dyn Clock) -> Self {
+        self.startup_time = clock.now_secs();
+        self
+    }
+
+    /// Factor in the recorded shutdown history
+    ///
+    /// A traumatic last shutdown (e.g. a crash caught by the server)
+    /// is treated as shock even if a graceful marker was written.
+    pub fn with_history(mut self, tracker: &ShutdownTracker) -> Self {
+        self.traumatic = tracker.was_traumatic();
+        self
+    }
+
     /// Analyze restart conditions
     pub fn analyze(&self) -> RecoveryState {
         match &self.last_marker {
@@ -89,8 +110,9 @@ impl RecoveryAnalyzer {
                 // Treat as normal (genesis mode)
                 RecoveryState::Normal
             }
-            Some(marker) if !marker.graceful => {
-                // Marker exists but not graceful — shock
+            Some(marker) if !marker.graceful || self.traumatic => {
+                // Marker exists but not graceful, or the last
+                // shutdown was traumatic — shock
                 RecoveryState::Shock
             }
             Some(marker) => {
@@ -121,14 +143,6 @@ pub fn decay_resistance(current: f32, rate: f32) -> f32 {
     (current - rate).max(0.0)
 }
 
-#[inline]
-fn now_secs() -> u64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_secs())
-        .unwrap_or(0)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,6 +182,51 @@ mod tests {
         assert_eq!(analyzer.analyze(), RecoveryState::Shock);
     }
 
+    #[test]
+    fn test_analyzer_traumatic_history() {
+        use crate::stability::ShutdownReason;
+
+        let marker = ShutdownMarker {
+            timestamp: now_secs() - 10,
+            graceful: true,
+            version: "test".into(),
+        };
+        let mut tracker = ShutdownTracker::default();
+        tracker.record(ShutdownReason::Signal { signal: 15 }, now_secs());
+        let analyzer = RecoveryAnalyzer::new(Some(marker.clone())).with_history(&tracker);
+        assert_eq!(analyzer.analyze(), RecoveryState::Normal);
+
+        tracker.record(
+            ShutdownReason::Crash {
+                message: "accept failed".into(),
+            },
+            now_secs(),
+        );
+        let analyzer = RecoveryAnalyzer::new(Some(marker)).with_history(&tracker);
+        assert_eq!(analyzer.analyze(), RecoveryState::Shock);
+    }
+
+    #[test]
+    fn test_analyzer_with_clock() {
+        use crate::clock::{ManualClock, NANOS_PER_SEC};
+        use std::time::Duration;
+
+        let clock = ManualClock::new(1_000 * NANOS_PER_SEC);
+        let marker = ShutdownMarker {
+            timestamp: 1_000,
+            graceful: true,
+            version: "test".into(),
+        };
+
+        let analyzer = RecoveryAnalyzer::new(Some(marker.clone())).with_clock(&clock);
+        assert_eq!(analyzer.analyze(), RecoveryState::Normal);
+
+        clock.advance(Duration::from_secs(COMA_THRESHOLD_SECS + 1));
+        let analyzer = RecoveryAnalyzer::new(Some(marker)).with_clock(&clock);
+        assert_eq!(analyzer.downtime_secs(), COMA_THRESHOLD_SECS + 1);
+        assert_eq!(analyzer.analyze(), RecoveryState::Coma);
+    }
+
     #[test]
     fn test_analyzer_coma() {
         let marker = ShutdownMarker {
@@ -49,6 +49,18 @@ impl ShutdownReason {
         }
     }
 
+    /// Stable numeric code for this shutdown type
+    ///
+    /// Stored as the `source_id` of the shutdown engram.
+    pub fn code(&self) -> u32 {
+        match self {
+            ShutdownReason::Graceful => 0,
+            ShutdownReason::Signal { .. } => 1,
+            ShutdownReason::Crash { .. } => 2,
+            ShutdownReason::Coma { .. } => 3,
+        }
+    }
+
     /// Get description for logging
     pub fn description(&self) -> String {
         match self {
@@ -63,7 +75,10 @@ impl ShutdownReason {
 }
 
 /// Tracks shutdown experiences
-#[derive(Default)]
+///
+/// Persisted across restarts so the next startup can factor the
+/// previous shutdown into its recovery analysis.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ShutdownTracker {
     /// Last shutdown reason (if any)
     pub last_reason: Option<ShutdownReason>,
@@ -2,12 +2,18 @@
 //!
 //! Enables zero startup delay by allowing the server to accept connections
 //! while snapshot loading happens in the background.
+//!
+//! Resurrection restores the hottest lineages first and enters `Partial`,
+//! where reads are served (cold lineages are faulted in on demand) while
+//! the rest streams in. Writes wait for `Ready`.
 
 use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
+
 /// Server warmup state
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum WarmupState {
     /// No snapshot found, server is ready immediately
@@ -16,6 +22,8 @@ pub enum WarmupState {
     Resurrecting = 1,
     /// Fully operational
     Ready = 2,
+    /// Hot set restored, cold lineages still loading (reads only)
+    Partial = 3,
 }
 
 impl From<u8> for WarmupState {
@@ -24,6 +32,7 @@ impl From<u8> for WarmupState {
             0 => Self::Cold,
             1 => Self::Resurrecting,
             2 => Self::Ready,
+            3 => Self::Partial,
             _ => Self::Cold,
         }
     }
@@ -59,6 +68,17 @@ impl WarmupTracker {
             .store(WarmupState::Resurrecting as u8, Ordering::SeqCst);
     }
 
+    /// Check if reads can be served while the cold remainder loads
+    pub fn is_partial(&self) -> bool {
+        self.state() == WarmupState::Partial
+    }
+
+    /// Transition to Partial state (hot set restored)
+    pub fn mark_partial(&self) {
+        self.state
+            .store(WarmupState::Partial as u8, Ordering::SeqCst);
+    }
+
     /// Transition to Ready state
     pub fn mark_ready(&self) {
         self.state.store(WarmupState::Ready as u8, Ordering::SeqCst);
@@ -90,6 +110,11 @@ mod tests {
         assert_eq!(tracker.state(), WarmupState::Resurrecting);
         assert!(!tracker.is_ready());
 
+        tracker.mark_partial();
+        assert_eq!(tracker.state(), WarmupState::Partial);
+        assert!(tracker.is_partial());
+        assert!(!tracker.is_ready());
+
         tracker.mark_ready();
         assert_eq!(tracker.state(), WarmupState::Ready);
         assert!(tracker.is_ready());